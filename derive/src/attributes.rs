@@ -0,0 +1,128 @@
+use syn::{spanned::Spanned, Attribute, Error, LitStr, Result};
+
+pub(crate) struct Container {
+  pub element: LitStr,
+  pub namespace: Option<LitStr>,
+}
+
+pub(crate) enum FieldKind {
+  Attribute(LitStr),
+  Text,
+  Element(LitStr),
+  Child,
+  Skip,
+}
+
+pub(crate) struct Field {
+  pub kind: FieldKind,
+  pub namespace: Option<LitStr>,
+  pub skip_if_none: bool,
+}
+
+impl Container {
+  pub fn parse(attrs: &[Attribute], span: proc_macro2::Span) -> Result<Self> {
+    let mut element = None;
+    let mut namespace = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("xml")) {
+      attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("element") {
+          element = Some(xml_name(meta.value()?.parse()?)?);
+          Ok(())
+        } else if meta.path.is_ident("namespace") {
+          namespace = Some(meta.value()?.parse()?);
+          Ok(())
+        } else {
+          Err(meta.error("unknown xml container attribute"))
+        }
+      })?;
+    }
+
+    let element = element.ok_or_else(|| Error::new(span, "missing #[xml(element = \"...\")]"))?;
+
+    Ok(Self { element, namespace })
+  }
+}
+
+impl Field {
+  pub fn parse(field: &syn::Field) -> Result<Self> {
+    let mut kind = None;
+    let mut namespace: Option<LitStr> = None;
+    let mut skip_if_none = false;
+
+    let mut set_kind = |value: FieldKind, span: proc_macro2::Span| {
+      if kind.replace(value).is_some() {
+        Err(Error::new(span, "conflicting xml field kinds"))
+      } else {
+        Ok(())
+      }
+    };
+
+    for attr in field
+      .attrs
+      .iter()
+      .filter(|attr| attr.path().is_ident("xml"))
+    {
+      attr.parse_nested_meta(|meta| {
+        let span = meta.path.span();
+
+        if meta.path.is_ident("attribute") {
+          let name = if meta.input.peek(syn::Token![=]) {
+            meta.value()?.parse()?
+          } else {
+            let ident = field.ident.as_ref().expect("named field");
+            LitStr::new(ident.to_string().trim_start_matches("r#"), ident.span())
+          };
+
+          set_kind(FieldKind::Attribute(xml_name(name)?), span)
+        } else if meta.path.is_ident("text") {
+          set_kind(FieldKind::Text, span)
+        } else if meta.path.is_ident("element") {
+          set_kind(FieldKind::Element(xml_name(meta.value()?.parse()?)?), span)
+        } else if meta.path.is_ident("skip") {
+          set_kind(FieldKind::Skip, span)
+        } else if meta.path.is_ident("namespace") {
+          namespace = Some(meta.value()?.parse()?);
+          Ok(())
+        } else if meta.path.is_ident("skip_if_none") {
+          skip_if_none = true;
+          Ok(())
+        } else {
+          Err(meta.error("unknown xml field attribute"))
+        }
+      })?;
+    }
+
+    let kind = kind.unwrap_or(FieldKind::Child);
+
+    if let (Some(namespace), FieldKind::Attribute(_) | FieldKind::Text) = (&namespace, &kind) {
+      return Err(Error::new(
+        namespace.span(),
+        "namespace is only supported on child elements, use a prefixed attribute name instead",
+      ));
+    }
+
+    Ok(Self {
+      kind,
+      namespace,
+      skip_if_none,
+    })
+  }
+}
+
+/// Rejects the names which can't be written as XML element or attribute names.
+fn xml_name(name: LitStr) -> Result<LitStr> {
+  let value = name.value();
+  let mut chars = value.chars();
+
+  let is_valid = chars
+    .next()
+    .is_some_and(|c| c.is_alphabetic() || c == '_' || c == ':')
+    && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'));
+
+  if is_valid {
+    Ok(name)
+  } else {
+    Err(Error::new(name.span(), "invalid XML name"))
+  }
+}
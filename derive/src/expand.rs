@@ -0,0 +1,250 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Result, Type};
+
+use crate::attributes::{Container, Field, FieldKind};
+
+pub(crate) fn serialize(input: &DeriveInput) -> Result<TokenStream> {
+  let fields = match &input.data {
+    Data::Struct(data) => match &data.fields {
+      Fields::Named(fields) => fields.named.iter().collect(),
+      Fields::Unit => Vec::new(),
+      Fields::Unnamed(_) => {
+        return Err(Error::new(
+          input.ident.span(),
+          "Serialize can only be derived for structs with named fields",
+        ))
+      }
+    },
+    _ => {
+      return Err(Error::new(
+        input.ident.span(),
+        "Serialize can only be derived for structs",
+      ))
+    }
+  };
+
+  let container = Container::parse(&input.attrs, input.ident.span())?;
+  let mut attributes = Vec::new();
+  let mut children = Vec::new();
+  let mut text = None;
+
+  for field in fields {
+    let options = Field::parse(field)?;
+    let ident = field.ident.as_ref().expect("named field");
+    let access = quote!(self.#ident);
+
+    match &options.kind {
+      FieldKind::Attribute(name) => {
+        let set = quote! {
+          __attributes.set(
+            ::syndication_format::common::AttributeName::unchecked_new(#name),
+            ::syndication_format::common::AttributeValue::new(
+              &::std::string::ToString::to_string(value),
+            ),
+          );
+        };
+        attributes.push(for_value(&access, &options, set));
+      }
+      FieldKind::Text if text.is_some() => {
+        return Err(Error::new(
+          field.span(),
+          "only one field can be the element text",
+        ));
+      }
+      FieldKind::Text => text = Some((access, options)),
+      FieldKind::Element(name) => {
+        let write = quote! {
+          ::syndication_format::serializer::ElementSerializer::serialize(
+            &mut __element,
+            ::syndication_format::serializer::ElementNode::new(
+              #name,
+              None,
+              Some(&::std::string::ToString::to_string(value)),
+            ),
+            __child_namespace,
+          )?;
+        };
+        children.push(child(&access, field, &options, write));
+      }
+      FieldKind::Child => {
+        let write = quote! {
+          ::syndication_format::serializer::ElementSerializer::serialize(
+            &mut __element,
+            value,
+            __child_namespace,
+          )?;
+        };
+        children.push(child(&access, field, &options, write));
+      }
+      FieldKind::Skip => (),
+    }
+  }
+
+  let element = &container.element;
+
+  let namespace = match &container.namespace {
+    Some(uri) => quote! {
+      let _ = namespace;
+      let __prefix = ::syndication_format::serializer::Serializer::namespace_prefix(&serializer, #uri);
+      let __namespace: Option<&str> = match __prefix {
+        Some("") => None,
+        Some(prefix) => Some(prefix),
+        None => {
+          __attributes.set(
+            ::syndication_format::common::AttributeName::unchecked_new("xmlns"),
+            ::syndication_format::common::AttributeValue::new(#uri),
+          );
+          None
+        }
+      };
+    },
+    None => quote!(let __namespace: Option<&str> = namespace;),
+  };
+
+  let body = match text {
+    Some(_) if !children.is_empty() => {
+      return Err(Error::new(
+        input.ident.span(),
+        "element text can't be combined with child elements",
+      ))
+    }
+    Some((access, options)) => {
+      let write = quote! {
+        let __element = ::syndication_format::serializer::Serializer::serialize_element(
+          serializer,
+          #element,
+          __namespace,
+          Some(&__attributes),
+        )?;
+        ::syndication_format::serializer::ElementSerializer::serialize_escaped_str(
+          __element,
+          &::std::string::ToString::to_string(value),
+        )
+      };
+
+      if options.skip_if_none {
+        quote! {
+          match &#access {
+            Some(value) => { #write }
+            None => ::syndication_format::serializer::Serializer::serialize_empty_element(
+              serializer,
+              #element,
+              __namespace,
+              Some(&__attributes),
+            ),
+          }
+        }
+      } else {
+        quote!({
+          let value = &#access;
+          #write
+        })
+      }
+    }
+    None if children.is_empty() => quote! {
+      ::syndication_format::serializer::Serializer::serialize_empty_element(
+        serializer,
+        #element,
+        __namespace,
+        Some(&__attributes),
+      )
+    },
+    None => quote! {
+      let mut __element = ::syndication_format::serializer::Serializer::serialize_element(
+        serializer,
+        #element,
+        __namespace,
+        Some(&__attributes),
+      )?;
+      #(#children)*
+      ::syndication_format::serializer::ElementSerializer::end(__element)
+    },
+  };
+
+  let ident = &input.ident;
+  let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+  Ok(quote! {
+    impl #impl_generics ::syndication_format::serializer::Serialize for #ident #type_generics
+    #where_clause
+    {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), ::syndication_format::error::XmlSerializeError>
+      where
+        S: ::syndication_format::serializer::Serializer,
+      {
+        #[allow(unused_mut)]
+        let mut __attributes = ::syndication_format::common::AttributeMap::new();
+        #namespace
+        #(#attributes)*
+        #body
+      }
+    }
+  })
+}
+
+/// Writes a child field, once for each item of `Vec` fields.
+fn child(
+  access: &TokenStream,
+  field: &syn::Field,
+  options: &Field,
+  write: TokenStream,
+) -> TokenStream {
+  let namespace = match &options.namespace {
+    Some(uri) => quote! {
+      let __child_prefix = ::syndication_format::serializer::ElementSerializer::namespace_prefix(
+        &__element,
+        #uri,
+      )
+      .ok_or(::syndication_format::error::XmlSerializeError::InvalidNamespace)?;
+      let __child_namespace: Option<&str> = Some(__child_prefix).filter(|prefix| !prefix.is_empty());
+    },
+    None => quote!(let __child_namespace: Option<&str> = __namespace;),
+  };
+
+  let write = if is_vec(&field.ty) && !options.skip_if_none {
+    quote! {
+      for value in &#access {
+        #write
+      }
+    }
+  } else {
+    for_value(access, options, write)
+  };
+
+  quote!({
+    #namespace
+    #write
+  })
+}
+
+/// Binds the field to `value`, skips `None` values of `skip_if_none` fields.
+fn for_value(access: &TokenStream, options: &Field, tokens: TokenStream) -> TokenStream {
+  if options.skip_if_none {
+    quote! {
+      if let Some(value) = &#access {
+        #tokens
+      }
+    }
+  } else {
+    quote!({
+      let value = &#access;
+      #tokens
+    })
+  }
+}
+
+fn is_vec(ty: &Type) -> bool {
+  match ty {
+    Type::Path(path) if path.qself.is_none() => path
+      .path
+      .segments
+      .last()
+      .is_some_and(|segment| segment.ident == "Vec"),
+    _ => false,
+  }
+}
@@ -0,0 +1,37 @@
+//! Derive macro for the `syndication_format::serializer::Serialize` trait, enabled with the
+//! `derive` feature of `syndication_format`.
+
+mod attributes;
+mod expand;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Implements `Serialize` for structs with named fields, unit structs are written as empty
+/// elements.
+///
+/// Container attributes:
+/// - `#[xml(element = "name")]` element name, required.
+/// - `#[xml(namespace = "uri")]` writes the element with the prefix bound to `uri`, or declares
+///   `uri` as the default namespace of the element when there's no binding. Without it, the
+///   element uses the namespace prefix given by the caller.
+///
+/// Field attributes:
+/// - `#[xml(attribute)]` or `#[xml(attribute = "name")]` writes a `Display` value as attribute.
+/// - `#[xml(text)]` writes a `Display` value as escaped element text. It can't be combined with
+///   child elements.
+/// - `#[xml(element = "name")]` writes a `Display` value as a child element with escaped text.
+/// - Fields without a kind are child elements and must implement `Serialize`. `Vec` fields write
+///   an element for each item.
+/// - `#[xml(namespace = "uri")]` writes child elements with the prefix bound to `uri`. Unbound
+///   namespaces fail with `XmlSerializeError::InvalidNamespace`.
+/// - `#[xml(skip_if_none)]` for `Option` fields, nothing is written for `None`.
+/// - `#[xml(skip)]` ignores the field.
+#[proc_macro_derive(Serialize, attributes(xml))]
+pub fn derive_serialize(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  expand::serialize(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
@@ -1,12 +1,16 @@
-use crate::{
-  common::{AttributeMap, AttributeName, AttributeValue, TimeStamp, XmlText},
-  error::XmlSerializeError,
-  serializer::{ElementSerializer, Serialize},
-};
+use crate::common::{AttributeMap, AttributeName, AttributeValue, TimeStamp, XmlText};
 
-use super::{Author, Category, Content, Contributor, Id, Link, Rights, Summary, Title, Updated};
+use super::{
+  Author, Category, Content, Contributor, Id, Link, Published, Rights, Source, Summary, Title,
+  Updated,
+};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Entry<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub authors: Vec<Author<'a>>,
   pub categories: Vec<Category<'a>>,
@@ -14,56 +18,81 @@ pub struct Entry<'a> {
   pub contributors: Vec<Contributor<'a>>,
   pub id: Id<'a>,
   pub links: Vec<Link<'a>>,
+  pub published: Option<Published>,
+  pub source: Option<Source<'a>>,
   pub summary: Option<Summary<'a>>,
   pub title: Title<'a>,
   pub updated: Updated,
   pub rights: Option<Rights<'a>>,
 }
 
-impl Serialize for Entry<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let mut entry = serializer.serialize_element("entry", namespace, Some(&self.attributes))?;
+/// Serializes [Entry] and [super::owned::Entry].
+macro_rules! impl_serialize_for_entry {
+  ($type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        use $crate::serializer::ElementSerializer;
 
-    for author in self.authors.iter() {
-      entry.serialize(author, namespace)?;
-    }
+        let mut entry = serializer.serialize_element("entry", namespace, Some(&self.attributes))?;
 
-    for contributor in self.contributors.iter() {
-      entry.serialize(contributor, namespace)?;
-    }
+        for author in self.authors.iter() {
+          entry.serialize(author, namespace)?;
+        }
 
-    for category in self.categories.iter() {
-      entry.serialize(category, namespace)?;
-    }
+        for contributor in self.contributors.iter() {
+          entry.serialize(contributor, namespace)?;
+        }
 
-    for link in self.links.iter() {
-      entry.serialize(link, namespace)?;
-    }
+        for category in self.categories.iter() {
+          entry.serialize(category, namespace)?;
+        }
 
-    if let Some(content) = self.content.as_ref() {
-      entry.serialize(content, namespace)?;
-    }
+        for link in self.links.iter() {
+          entry.serialize(link, namespace)?;
+        }
 
-    if let Some(summary) = self.summary.as_ref() {
-      entry.serialize(summary, namespace)?;
-    }
+        if let Some(content) = self.content.as_ref() {
+          entry.serialize(content, namespace)?;
+        }
 
-    if let Some(rights) = self.rights.as_ref() {
-      entry.serialize(rights, namespace)?;
-    }
+        if let Some(summary) = self.summary.as_ref() {
+          entry.serialize(summary, namespace)?;
+        }
 
-    entry.serialize(&self.title, namespace)?;
-    entry.serialize(&self.id, namespace)?;
-    entry.serialize(&self.updated, namespace)?;
+        if let Some(rights) = self.rights.as_ref() {
+          entry.serialize(rights, namespace)?;
+        }
 
-    entry.end()?;
-    Ok(())
-  }
+        if let Some(source) = self.source.as_ref() {
+          entry.serialize(source, namespace)?;
+        }
+
+        entry.serialize(&self.title, namespace)?;
+        entry.serialize(&self.id, namespace)?;
+        entry.serialize(&self.updated, namespace)?;
+
+        if let Some(published) = self.published.as_ref() {
+          entry.serialize(published, namespace)?;
+        }
+
+        entry.end()?;
+        Ok(())
+      }
+    }
+  };
 }
 
+pub(crate) use impl_serialize_for_entry;
+
+impl_serialize_for_entry!(Entry<'_>);
+
 impl<'a> Entry<'a> {
   pub fn new(id: &'a str, title: XmlText<'a>, updated: TimeStamp) -> Self {
     Self {
@@ -76,7 +105,9 @@ impl<'a> Entry<'a> {
         value: id,
       },
       links: Vec::default(),
+      published: None,
       rights: None,
+      source: None,
       content: None,
       summary: None,
       title: Title {
@@ -1,17 +1,21 @@
-use crate::{
-  common::{AttributeMap, AttributeName, AttributeValue, TimeStamp, XmlText},
-  error::XmlSerializeError,
-  serializer::{ElementSerializer, Serialize},
-  utils::xml_name_token::is_valid_name,
-};
+use crate::common::{AttributeMap, AttributeName, AttributeValue, TimeStamp, XmlText};
 
 use super::{
   Author, Category, Contributor, Entry, Generator, Icon, Id, Link, Logo, Rights, SubTitle, Title,
   Updated,
 };
 
+pub const ATOM_NAMESPACE: &str = "http://www.w3.org/2005/Atom";
+
+/// Atom feed document. Serialization declares [ATOM_NAMESPACE] on the root element, as the
+/// default namespace or bound to the given prefix.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Feed<'a> {
-  attributes: AttributeMap,
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
   pub authors: Vec<Author<'a>>,
   pub categories: Vec<Category<'a>>,
   pub contributors: Vec<Contributor<'a>>,
@@ -27,67 +31,95 @@ pub struct Feed<'a> {
   pub updated: Updated,
 }
 
-impl Serialize for Feed<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    if let Some(name) = namespace.as_deref() {
-      if !is_valid_name(name) {
-        return Err(XmlSerializeError::InvalidNamespace);
+/// Serializes [Feed] and [super::owned::Feed].
+macro_rules! impl_serialize_for_feed {
+  ($type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        use $crate::{
+          atom::ATOM_NAMESPACE,
+          common::{AttributeMap, AttributeName},
+          error::XmlSerializeError,
+          serializer::ElementSerializer,
+          utils::xml_name_token::is_valid_name,
+        };
+
+        let mut attributes = AttributeMap::new_from(&self.attributes);
+
+        match namespace {
+          Some(name) if !is_valid_name(name) => return Err(XmlSerializeError::InvalidNamespace),
+          Some(name) => attributes.set(
+            AttributeName::new(&format!("xmlns:{}", name))?,
+            ATOM_NAMESPACE.into(),
+          ),
+          None => {
+            set_from_value!(attributes, "xmlns", ATOM_NAMESPACE);
+          }
+        }
+
+        let mut feed = serializer.serialize_element("feed", namespace, Some(&attributes))?;
+
+        for author in self.authors.iter() {
+          feed.serialize(author, namespace)?;
+        }
+
+        for contributor in self.contributors.iter() {
+          feed.serialize(contributor, namespace)?;
+        }
+
+        for category in self.categories.iter() {
+          feed.serialize(category, namespace)?;
+        }
+
+        for link in self.links.iter() {
+          feed.serialize(link, namespace)?;
+        }
+
+        if let Some(generator) = self.generator.as_ref() {
+          feed.serialize(generator, namespace)?;
+        }
+
+        if let Some(subtitle) = self.subtitle.as_ref() {
+          feed.serialize(subtitle, namespace)?;
+        }
+
+        if let Some(logo) = self.logo.as_ref() {
+          feed.serialize(logo, namespace)?;
+        }
+
+        if let Some(icon) = self.icon.as_ref() {
+          feed.serialize(icon, namespace)?;
+        }
+
+        if let Some(rights) = self.rights.as_ref() {
+          feed.serialize(rights, namespace)?;
+        }
+
+        feed.serialize(&self.title, namespace)?;
+        feed.serialize(&self.id, namespace)?;
+        feed.serialize(&self.updated, namespace)?;
+
+        for entry in self.entries.iter() {
+          feed.serialize(entry, namespace)?;
+        }
+
+        feed.end()?;
+        Ok(())
       }
     }
+  };
+}
 
-    let mut feed = serializer.serialize_element("feed", namespace, Some(&self.attributes))?;
-
-    for author in self.authors.iter() {
-      feed.serialize(author, namespace)?;
-    }
-
-    for contributor in self.contributors.iter() {
-      feed.serialize(contributor, namespace)?;
-    }
-
-    for category in self.categories.iter() {
-      feed.serialize(category, namespace)?;
-    }
-
-    for link in self.links.iter() {
-      feed.serialize(link, namespace)?;
-    }
-
-    if let Some(generator) = self.generator.as_ref() {
-      feed.serialize(generator, namespace)?;
-    }
-
-    if let Some(subtitle) = self.subtitle.as_ref() {
-      feed.serialize(subtitle, namespace)?;
-    }
-
-    if let Some(logo) = self.logo.as_ref() {
-      feed.serialize(logo, namespace)?;
-    }
-
-    if let Some(icon) = self.icon.as_ref() {
-      feed.serialize(icon, namespace)?;
-    }
-
-    if let Some(rights) = self.rights.as_ref() {
-      feed.serialize(rights, namespace)?;
-    }
-
-    feed.serialize(&self.title, namespace)?;
-    feed.serialize(&self.id, namespace)?;
-    feed.serialize(&self.updated, namespace)?;
-
-    for entry in self.entries.iter() {
-      feed.serialize(entry, namespace)?;
-    }
+pub(crate) use impl_serialize_for_feed;
 
-    feed.end()?;
-    Ok(())
-  }
-}
+impl_serialize_for_feed!(Feed<'_>);
 
 impl<'a> Feed<'a> {
   pub fn new(id: &'a str, title: XmlText<'a>, updated: TimeStamp) -> Self {
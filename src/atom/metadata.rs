@@ -1,39 +1,10 @@
-use super::ElementNode;
-use crate::{
-  common::{AttributeMap, AttributeName, AttributeValue, LinkRelation, TimeStamp, XmlText},
-  error::XmlSerializeError,
-  serializer::{ElementSerializer, Serialize},
-};
-
-macro_rules! impl_attribute_fns {
-  () => {
-    #[inline]
-    pub fn set_attribute(&mut self, name: AttributeName, value: AttributeValue) -> &mut Self {
-      self.attributes.set(name, value);
-      self
-    }
-
-    #[inline]
-    pub fn get_mut_attributes(&mut self) -> &mut AttributeMap {
-      &mut self.attributes
-    }
-  };
-}
+use crate::common::{AttributeMap, LinkRelation, TimeStamp, XmlText};
 
-macro_rules! set_from_option {
-  ($map:expr, $name:expr, $value:expr) => {
-    if let Some(value) = $value {
-      $map.set(AttributeName::unchecked_new($name), value.into());
-    }
-  };
-}
-
-macro_rules! set_from_value {
-  ($map:expr, $name:expr, $value:expr) => {
-    $map.set(AttributeName::unchecked_new($name), $value.into());
-  };
-}
+// Serialize implementations are macros shared with the [super::owned] types. Field values are
+// borrowed with `&*` and `as_deref`, and `XmlText` and `ContentValue` resolve at the invocation
+// site, so the same body works for both `&str` and `String` fields.
 
+/// Serializes text constructs, [Title], [SubTitle], [Summary] and [Rights].
 macro_rules! impl_serialize_for_text_node {
   ($name:expr, $type:ty) => {
     impl $crate::serializer::Serialize for $type {
@@ -43,35 +14,37 @@ macro_rules! impl_serialize_for_text_node {
         namespace: Option<&str>,
       ) -> Result<(), $crate::error::XmlSerializeError>
       where
-        S: crate::serializer::Serializer,
+        S: $crate::serializer::Serializer,
       {
-        let mut attributes = AttributeMap::new_from(&self.attributes);
+        use $crate::serializer::ElementSerializer;
+
+        let mut attributes = $crate::common::AttributeMap::new_from(&self.attributes);
 
-        match self.value {
+        match &self.value {
           XmlText::Html(value) => {
             set_from_value!(attributes, "type", "html");
             let element = serializer.serialize_element($name, namespace, Some(&attributes))?;
-            element.serialize_escaped_str(&value)?;
+            element.serialize_escaped_str(value)?;
           }
           XmlText::HtmlUnchecked(value) => {
             set_from_value!(attributes, "type", "html");
             let element = serializer.serialize_element($name, namespace, Some(&attributes))?;
-            element.serialize_str(&value)?;
+            element.serialize_str(value)?;
           }
           XmlText::Xhtml(value) => {
             set_from_value!(attributes, "type", "xhtml");
             let element = serializer.serialize_element($name, namespace, Some(&attributes))?;
-            element.serialize_str(&value)?;
+            $crate::atom::serialize_xhtml(element, value)?;
           }
           XmlText::PlainText(value) => {
             set_from_value!(attributes, "type", "text");
             let element = serializer.serialize_element($name, namespace, Some(&attributes))?;
-            element.serialize_escaped_str(&value)?;
+            element.serialize_escaped_str(value)?;
           }
           XmlText::PlainTextUnchecked(value) => {
             set_from_value!(attributes, "type", "text");
             let element = serializer.serialize_element($name, namespace, Some(&attributes))?;
-            element.serialize_str(&value)?;
+            element.serialize_str(value)?;
           }
         };
 
@@ -81,7 +54,296 @@ macro_rules! impl_serialize_for_text_node {
   };
 }
 
+pub(crate) use impl_serialize_for_text_node;
+
+/// Serializes person constructs, [Author] and [Contributor].
+macro_rules! impl_serialize_for_person {
+  ($name:expr, $type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        use $crate::serializer::{ElementNode, ElementSerializer};
+
+        let mut person = serializer.serialize_element($name, namespace, Some(&self.attributes))?;
+
+        let name_element = ElementNode {
+          name: "name",
+          value: Some(&*self.name),
+          attributes: None,
+        };
+
+        person.serialize(name_element, namespace)?;
+
+        if let Some(uri) = self.uri.as_deref() {
+          let uri_element = ElementNode {
+            name: "uri",
+            value: Some(uri),
+            attributes: None,
+          };
+
+          person.serialize(uri_element, namespace)?;
+        }
+
+        if let Some(email) = self.email.as_deref() {
+          let email_element = ElementNode {
+            name: "email",
+            value: Some(email),
+            attributes: None,
+          };
+
+          person.serialize(email_element, namespace)?;
+        }
+
+        person.end()?;
+
+        Ok(())
+      }
+    }
+  };
+}
+
+pub(crate) use impl_serialize_for_person;
+
+/// Serializes single value elements, [Icon], [Logo] and [Id].
+macro_rules! impl_serialize_for_value_node {
+  ($name:expr, $type:ty, $field:ident) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        use $crate::serializer::ElementSerializer;
+
+        let element = serializer.serialize_element($name, namespace, Some(&self.attributes))?;
+        element.serialize_escaped_str(&self.$field)?;
+        Ok(())
+      }
+    }
+  };
+}
+
+pub(crate) use impl_serialize_for_value_node;
+
+/// Serializes date constructs, [Updated] and [Published].
+macro_rules! impl_serialize_for_date_node {
+  ($name:expr, $type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        use $crate::serializer::ElementSerializer;
+
+        let element = serializer.serialize_element($name, namespace, Some(&self.attributes))?;
+        element.serialize_str(&self.value.to_string())?;
+        Ok(())
+      }
+    }
+  };
+}
+
+pub(crate) use impl_serialize_for_date_node;
+
+macro_rules! impl_serialize_for_category {
+  ($type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        let mut attributes = $crate::common::AttributeMap::new_from(&self.attributes);
+        set_from_value!(attributes, "term", &*self.term);
+        set_from_option!(attributes, "scheme", self.scheme.as_deref());
+        set_from_option!(attributes, "label", self.label.as_deref());
+
+        serializer.serialize_empty_element("category", namespace, Some(&attributes))?;
+
+        Ok(())
+      }
+    }
+  };
+}
+
+pub(crate) use impl_serialize_for_category;
+
+macro_rules! impl_serialize_for_generator {
+  ($type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        use $crate::serializer::ElementSerializer;
+
+        let mut attributes = $crate::common::AttributeMap::new_from(&self.attributes);
+        set_from_option!(attributes, "version", self.version.as_deref());
+        set_from_option!(attributes, "uri", self.uri.as_deref());
+
+        let generator = serializer.serialize_element("generator", namespace, Some(&attributes))?;
+
+        generator.serialize_escaped_str(&self.value)?;
+
+        Ok(())
+      }
+    }
+  };
+}
+
+pub(crate) use impl_serialize_for_generator;
+
+macro_rules! impl_serialize_for_link {
+  ($type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        let mut attributes = $crate::common::AttributeMap::new_from(&self.attributes);
+        set_from_value!(attributes, "href", &*self.href);
+        set_from_option!(attributes, "hreflang", self.hreflang.as_deref());
+        set_from_option!(attributes, "rel", self.rel.as_ref().map(|v| v.as_str()));
+        set_from_option!(attributes, "type", self.link_type.as_deref());
+        set_from_option!(attributes, "title", self.title.as_deref());
+        set_from_option!(attributes, "length", self.length.map(|v| v.to_string()));
+
+        serializer.serialize_empty_element("link", namespace, Some(&attributes))?;
+        Ok(())
+      }
+    }
+  };
+}
+
+pub(crate) use impl_serialize_for_link;
+
+/// Serializes [Content], the value type is matched by the variant names of [ContentValue].
+macro_rules! impl_serialize_for_content {
+  ($type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        use core::str::from_utf8;
+        use $crate::{
+          atom::{is_text_media_type, is_xml_media_type, serialize_xhtml},
+          common::AttributeMap,
+          error::XmlSerializeError,
+          serializer::ElementSerializer,
+          utils::base64,
+        };
+
+        const ELEMENT_NAME: &str = "content";
+
+        match &self.value {
+          ContentValue::TextContent { text } => {
+            let mut attributes = AttributeMap::new_from(&self.attributes);
+
+            match text {
+              XmlText::Html(value) => {
+                set_from_value!(attributes, "type", "html");
+                let content =
+                  serializer.serialize_element(ELEMENT_NAME, namespace, Some(&attributes))?;
+                content.serialize_escaped_str(value)?;
+              }
+              XmlText::HtmlUnchecked(value) => {
+                set_from_value!(attributes, "type", "html");
+                let content =
+                  serializer.serialize_element(ELEMENT_NAME, namespace, Some(&attributes))?;
+                content.serialize_str(value)?;
+              }
+              XmlText::Xhtml(value) => {
+                set_from_value!(attributes, "type", "xhtml");
+                let content =
+                  serializer.serialize_element(ELEMENT_NAME, namespace, Some(&attributes))?;
+                serialize_xhtml(content, value)?;
+              }
+              XmlText::PlainText(value) => {
+                set_from_value!(attributes, "type", "text");
+                let content =
+                  serializer.serialize_element(ELEMENT_NAME, namespace, Some(&attributes))?;
+                content.serialize_escaped_str(value)?;
+              }
+              XmlText::PlainTextUnchecked(value) => {
+                set_from_value!(attributes, "type", "text");
+                let content =
+                  serializer.serialize_element(ELEMENT_NAME, namespace, Some(&attributes))?;
+                content.serialize_str(value)?;
+              }
+            };
+
+            Ok(())
+          }
+          ContentValue::LinkContent { media_type, src } => {
+            let mut attributes = AttributeMap::new_from(&self.attributes);
+            set_from_option!(attributes, "type", media_type.as_deref());
+            set_from_value!(attributes, "src", &**src);
+
+            serializer.serialize_empty_element(ELEMENT_NAME, namespace, Some(&attributes))?;
+            Ok(())
+          }
+          ContentValue::InlinedMedia { media_type, data } => {
+            let mut attributes = AttributeMap::new_from(&self.attributes);
+            set_from_value!(attributes, "type", &**media_type);
+
+            let content =
+              serializer.serialize_element(ELEMENT_NAME, namespace, Some(&attributes))?;
+
+            if is_xml_media_type(media_type) {
+              let markup = from_utf8(data).map_err(|_| XmlSerializeError::InvalidContent)?;
+              content.serialize_str(markup)?;
+            } else if is_text_media_type(media_type) {
+              let text = from_utf8(data).map_err(|_| XmlSerializeError::InvalidContent)?;
+              content.serialize_escaped_str(text)?;
+            } else {
+              content.serialize_str(&base64::encode(data))?;
+            }
+
+            Ok(())
+          }
+        }
+      }
+    }
+  };
+}
+
+pub(crate) use impl_serialize_for_content;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Author<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub email: Option<&'a str>,
   pub name: &'a str,
@@ -101,52 +363,14 @@ impl<'a> Author<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Author<'_> {
-  fn serialize<S>(
-    &self,
-    serializer: S,
-    namespace: Option<&str>,
-  ) -> core::result::Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let mut person = serializer.serialize_element("author", namespace, Some(&self.attributes))?;
-
-    let name_element = ElementNode {
-      name: "name",
-      value: Some(self.name),
-      attributes: None,
-    };
-
-    person.serialize(name_element, namespace)?;
-
-    if let Some(uri) = self.uri {
-      let uri_element = ElementNode {
-        name: "uri",
-        value: Some(uri),
-        attributes: None,
-      };
-
-      person.serialize(uri_element, namespace)?;
-    }
-
-    if let Some(email) = self.email {
-      let email_element = ElementNode {
-        name: "email",
-        value: Some(email),
-        attributes: None,
-      };
-
-      person.serialize(email_element, namespace)?;
-    }
-
-    person.end()?;
-
-    Ok(())
-  }
-}
+impl_serialize_for_person!("author", Author<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Contributor<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub email: Option<&'a str>,
   pub name: &'a str,
@@ -166,49 +390,14 @@ impl<'a> Contributor<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Contributor<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let mut person =
-      serializer.serialize_element("contributor", namespace, Some(&self.attributes))?;
-
-    let name_element = ElementNode {
-      name: "name",
-      value: Some(self.name),
-      attributes: None,
-    };
-
-    person.serialize(name_element, namespace)?;
-
-    if let Some(uri) = self.uri {
-      let uri_element = ElementNode {
-        name: "uri",
-        value: Some(uri),
-        attributes: None,
-      };
-
-      person.serialize(uri_element, namespace)?;
-    }
-
-    if let Some(email) = self.email {
-      let email_element = ElementNode {
-        name: "email",
-        value: Some(email),
-        attributes: None,
-      };
-
-      person.serialize(email_element, namespace)?;
-    }
-
-    person.end()?;
-
-    Ok(())
-  }
-}
+impl_serialize_for_person!("contributor", Contributor<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Category<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub label: Option<&'a str>,
   pub scheme: Option<&'a str>,
@@ -228,23 +417,14 @@ impl<'a> Category<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Category<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let mut attributes = AttributeMap::new_from(&self.attributes);
-    set_from_value!(attributes, "term", self.term);
-    set_from_option!(attributes, "scheme", self.scheme);
-    set_from_option!(attributes, "label", self.label);
-
-    serializer.serialize_empty_element("category", namespace, Some(&attributes))?;
-
-    Ok(())
-  }
-}
+impl_serialize_for_category!(Category<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Generator<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub uri: Option<&'a str>,
   pub value: &'a str,
@@ -275,24 +455,14 @@ impl<'a> Generator<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Generator<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let mut attributes = AttributeMap::new_from(&self.attributes);
-    set_from_option!(attributes, "version", self.version);
-    set_from_option!(attributes, "uri", self.uri);
-
-    let generator = serializer.serialize_element("generator", namespace, Some(&attributes))?;
-
-    generator.serialize_escaped_str(self.value)?;
-
-    Ok(())
-  }
-}
+impl_serialize_for_generator!(Generator<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Icon<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub uri: &'a str,
 }
@@ -308,18 +478,14 @@ impl<'a> Icon<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Icon<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let icon = serializer.serialize_element("icon", namespace, Some(&self.attributes))?;
-    icon.serialize_escaped_str(self.uri)?;
-    Ok(())
-  }
-}
+impl_serialize_for_value_node!("icon", Icon<'_>, uri);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Id<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub value: &'a str,
 }
@@ -335,22 +501,19 @@ impl<'a> Id<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Id<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let id = serializer.serialize_element("id", namespace, Some(&self.attributes))?;
-    id.serialize_escaped_str(self.value)?;
-    Ok(())
-  }
-}
+impl_serialize_for_value_node!("id", Id<'_>, value);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Link<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub href: &'a str,
   pub hreflang: Option<&'a str>,
   pub length: Option<usize>,
+  #[cfg_attr(feature = "serde", serde(rename = "type"))]
   pub link_type: Option<&'a str>,
   pub rel: Option<LinkRelation>,
   pub title: Option<&'a str>,
@@ -372,25 +535,14 @@ impl<'a> Link<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Link<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let mut attributes = AttributeMap::new_from(&self.attributes);
-    set_from_value!(attributes, "href", self.href);
-    set_from_option!(attributes, "hreflang", self.hreflang);
-    set_from_option!(attributes, "rel", self.rel.map(|v| v.as_str()));
-    set_from_option!(attributes, "type", self.link_type);
-    set_from_option!(attributes, "title", self.title);
-    set_from_option!(attributes, "length", self.length.map(|v| v.to_string()));
-
-    serializer.serialize_empty_element("link", namespace, Some(&attributes))?;
-    Ok(())
-  }
-}
+impl_serialize_for_link!(Link<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Logo<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub uri: &'a str,
 }
@@ -406,18 +558,14 @@ impl<'a> Logo<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Logo<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let logo = serializer.serialize_element("logo", namespace, Some(&self.attributes))?;
-    logo.serialize_escaped_str(self.uri)?;
-    Ok(())
-  }
-}
+impl_serialize_for_value_node!("logo", Logo<'_>, uri);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Rights<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub value: XmlText<'a>,
 }
@@ -435,7 +583,12 @@ impl<'a> Rights<'a> {
 
 impl_serialize_for_text_node!("rights", Rights<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Title<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub value: XmlText<'a>,
 }
@@ -453,7 +606,12 @@ impl<'a> Title<'a> {
 
 impl_serialize_for_text_node!("title", Title<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SubTitle<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub value: XmlText<'a>,
 }
@@ -471,7 +629,12 @@ impl<'a> SubTitle<'a> {
 
 impl_serialize_for_text_node!("subtitle", SubTitle<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Summary<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub value: XmlText<'a>,
 }
@@ -489,15 +652,60 @@ impl<'a> Summary<'a> {
 
 impl_serialize_for_text_node!("summary", Summary<'_>);
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Content<'a> {
   pub value: ContentValue<'a>,
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
 }
 
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize),
+  serde(tag = "kind", rename_all = "snake_case")
+)]
 pub enum ContentValue<'a> {
-  TextContent { text: XmlText<'a> },
-  LinkContent { media_type: &'a str, src: &'a str },
-  InlinedMedia { media_type: &'a str, data: &'a str },
+  TextContent {
+    text: XmlText<'a>,
+  },
+  LinkContent {
+    media_type: Option<&'a str>,
+    src: &'a str,
+  },
+  /// Content with a media type. XML media types are embedded as markup and `text/*` types as
+  /// escaped text, both must be UTF-8. Other media types are Base64 encoded, RFC4287 section
+  /// 4.1.3.3.
+  InlinedMedia {
+    media_type: &'a str,
+    #[cfg_attr(
+      feature = "serde",
+      serde(serialize_with = "crate::utils::base64::serialize")
+    )]
+    data: &'a [u8],
+  },
+}
+
+#[inline]
+fn essence(media_type: &str) -> &str {
+  media_type
+    .split_once(';')
+    .map_or(media_type, |(value, _)| value)
+    .trim()
+}
+
+/// Checks for the XML media types of RFC3023, `*/xml` and `*+xml`.
+#[inline]
+pub(crate) fn is_xml_media_type(media_type: &str) -> bool {
+  let media_type = essence(media_type);
+  media_type.ends_with("+xml") || media_type.ends_with("/xml")
+}
+
+#[inline]
+pub(crate) fn is_text_media_type(media_type: &str) -> bool {
+  essence(media_type).starts_with("text/")
 }
 
 impl<'a> From<XmlText<'a>> for ContentValue<'a> {
@@ -517,84 +725,39 @@ impl<'a> Content<'a> {
   impl_attribute_fns!();
 }
 
-impl Serialize for Content<'_> {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    const ELEMENT_NAME: &str = "content";
-
-    match &self.value {
-      ContentValue::TextContent { text } => {
-        let mut attributes = AttributeMap::with_capacity(self.attributes.len() + 1);
-
-        match *text {
-          XmlText::Html(value) => {
-            set_from_value!(attributes, "type", "html");
-            let content =
-              serializer.serialize_element(ELEMENT_NAME, namespace, Some(&self.attributes))?;
-            content.serialize_escaped_str(value)?;
-          }
-          XmlText::HtmlUnchecked(value) => {
-            set_from_value!(attributes, "type", "html");
-            let content =
-              serializer.serialize_element(ELEMENT_NAME, namespace, Some(&self.attributes))?;
-            content.serialize_str(value)?;
-          }
-          XmlText::Xhtml(value) => {
-            set_from_value!(attributes, "type", "xhtml");
-            let content =
-              serializer.serialize_element(ELEMENT_NAME, namespace, Some(&self.attributes))?;
-            content.serialize_str(value)?;
-          }
-          XmlText::PlainText(value) => {
-            set_from_value!(attributes, "type", "text");
-            let content =
-              serializer.serialize_element(ELEMENT_NAME, namespace, Some(&self.attributes))?;
-            content.serialize_escaped_str(value)?;
-          }
-          XmlText::PlainTextUnchecked(value) => {
-            set_from_value!(attributes, "type", "text");
-            let content =
-              serializer.serialize_element(ELEMENT_NAME, namespace, Some(&self.attributes))?;
-            content.serialize_str(value)?;
-          }
-        };
-
-        Ok(())
-      }
-      ContentValue::LinkContent { media_type, src } => {
-        let mut attributes = AttributeMap::new_from(&self.attributes);
-        set_from_value!(attributes, "type", *media_type);
-        set_from_value!(attributes, "src", *src);
+impl_serialize_for_content!(Content<'_>);
 
-        serializer.serialize_empty_element(ELEMENT_NAME, namespace, Some(&attributes))?;
-        Ok(())
-      }
-      ContentValue::InlinedMedia { media_type, data } => {
-        let mut attributes = AttributeMap::new_from(&self.attributes);
-        set_from_value!(attributes, "type", *media_type);
-
-        let content = serializer.serialize_element(ELEMENT_NAME, namespace, Some(&attributes))?;
-        content.serialize_escaped_str(data)?;
-        Ok(())
-      }
-    }
-  }
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Updated {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub value: TimeStamp,
 }
 
-pub struct Updated {
+impl_serialize_for_date_node!("updated", Updated);
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Published {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
   pub(crate) attributes: AttributeMap,
   pub value: TimeStamp,
 }
 
-impl Serialize for Updated {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
-  where
-    S: crate::serializer::Serializer,
-  {
-    let updated = serializer.serialize_element("updated", namespace, Some(&self.attributes))?;
-    updated.serialize_str(&self.value.to_string())?;
-    Ok(())
+impl Published {
+  pub fn new(value: TimeStamp) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      value,
+    }
   }
+
+  impl_attribute_fns!();
 }
+
+impl_serialize_for_date_node!("published", Published);
@@ -0,0 +1,832 @@
+//! Owned variants of the Atom types, for the feeds built from computed values or kept after the
+//! source document is gone.
+//!
+//! Each type has the same fields as its borrowed counterpart with `String` values, and serializes
+//! directly without converting to the borrowed form. `as_borrowed` is an allocating conversion:
+//! text values are borrowed, but the attribute maps with their values and the element lists are
+//! copied. `From` conversions copy borrowed values, such as parsed feeds, into the owned form.
+//!
+//! ```
+//! use syndication_format::{
+//!   atom::owned::{Entry, Feed, XmlText},
+//!   common::TimeStamp,
+//!   serializer::{formatter::DefaultWriter, Serializer, XmlSerializer},
+//! };
+//!
+//! fn build_feed(count: usize) -> Feed {
+//!   let mut feed = Feed::new("urn:feed", XmlText::PlainText("Feed".into()), TimeStamp::default());
+//!   feed.generator = None;
+//!
+//!   for idx in 0..count {
+//!     let title = XmlText::PlainText(format!("Entry {}", idx));
+//!     feed
+//!       .entries
+//!       .push(Entry::new(format!("urn:entry:{}", idx), title, TimeStamp::default()));
+//!   }
+//!
+//!   feed
+//! }
+//!
+//! let feed = build_feed(2);
+//! assert_eq!("urn:entry:1", feed.as_borrowed().entries[1].id.value);
+//!
+//! let mut bytes: Vec<u8> = Vec::new();
+//! let mut writer = DefaultWriter::new(&mut bytes);
+//! XmlSerializer::new(&mut writer).serialize(&feed, None).unwrap();
+//! ```
+//!
+//! # Serde
+//!
+//! With the `serde` feature, borrowed types implement `Serialize` and owned types implement both
+//! `Serialize` and `Deserialize` with the same JSON shape, so a parsed feed can be stored and
+//! later read back into the owned model:
+//!
+//! - Structs are objects with the Rust field names, except `link_type` which is `type`. Missing
+//!   values are `null` and empty lists are `[]`.
+//! - Extension attributes are an `attributes` object of names to values, omitted when empty.
+//! - Text constructs are `{"type": "html", "value": "..."}`, see [common::XmlText].
+//! - Dates are RFC3339 strings and link relations are relation names or IRIs.
+//! - Content values are tagged by `kind`, `text_content`, `link_content` or `inlined_media`.
+//!   Inlined media data is a Base64 string.
+//!
+//! Abridged entry:
+//!
+//! ```json
+//! {
+//!   "id": { "value": "urn:entry" },
+//!   "links": [{ "href": "https://example.org/1", "hreflang": null, "length": null,
+//!               "type": "text/html", "rel": "alternate", "title": null }],
+//!   "title": { "value": { "type": "text", "value": "Entry" } },
+//!   "updated": { "value": "2024-09-18T23:28:25Z" },
+//!   "content": { "value": { "kind": "inlined_media", "media_type": "image/png", "data": "iVBORw==" } }
+//! }
+//! ```
+
+use crate::common::{self, AttributeMap, LinkRelation, TimeStamp};
+
+use super::{
+  entry::impl_serialize_for_entry,
+  feed::impl_serialize_for_feed,
+  metadata::{
+    impl_serialize_for_category, impl_serialize_for_content, impl_serialize_for_date_node,
+    impl_serialize_for_generator, impl_serialize_for_link, impl_serialize_for_person,
+    impl_serialize_for_text_node, impl_serialize_for_value_node,
+  },
+  source::impl_serialize_for_source,
+};
+
+/// Same serde representation as [common::XmlText].
+#[derive(Clone)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum XmlText {
+  Html(String),
+  Xhtml(String),
+  #[cfg_attr(feature = "serde", serde(rename = "text"))]
+  PlainText(String),
+  HtmlUnchecked(String),
+  #[cfg_attr(feature = "serde", serde(rename = "text_unchecked"))]
+  PlainTextUnchecked(String),
+}
+
+impl XmlText {
+  pub fn as_borrowed(&self) -> common::XmlText<'_> {
+    match self {
+      XmlText::Html(value) => common::XmlText::Html(value),
+      XmlText::Xhtml(value) => common::XmlText::Xhtml(value),
+      XmlText::PlainText(value) => common::XmlText::PlainText(value),
+      XmlText::HtmlUnchecked(value) => common::XmlText::HtmlUnchecked(value),
+      XmlText::PlainTextUnchecked(value) => common::XmlText::PlainTextUnchecked(value),
+    }
+  }
+}
+
+impl From<&common::XmlText<'_>> for XmlText {
+  fn from(value: &common::XmlText<'_>) -> Self {
+    match *value {
+      common::XmlText::Html(value) => XmlText::Html(value.into()),
+      common::XmlText::Xhtml(value) => XmlText::Xhtml(value.into()),
+      common::XmlText::PlainText(value) => XmlText::PlainText(value.into()),
+      common::XmlText::HtmlUnchecked(value) => XmlText::HtmlUnchecked(value.into()),
+      common::XmlText::PlainTextUnchecked(value) => XmlText::PlainTextUnchecked(value.into()),
+    }
+  }
+}
+
+/// Owned person constructs, [super::Author] and [super::Contributor].
+macro_rules! owned_person {
+  ($name:ident, $element:expr) => {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct $name {
+      #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "AttributeMap::is_empty")
+      )]
+      pub(crate) attributes: AttributeMap,
+      pub email: Option<String>,
+      pub name: String,
+      pub uri: Option<String>,
+    }
+
+    impl $name {
+      pub fn new(name: impl Into<String>) -> Self {
+        Self {
+          attributes: AttributeMap::default(),
+          email: None,
+          name: name.into(),
+          uri: None,
+        }
+      }
+
+      pub fn as_borrowed(&self) -> super::$name<'_> {
+        super::$name {
+          attributes: self.attributes.clone(),
+          email: self.email.as_deref(),
+          name: &self.name,
+          uri: self.uri.as_deref(),
+        }
+      }
+
+      impl_attribute_fns!();
+    }
+
+    impl_serialize_for_person!($element, $name);
+
+    impl From<&super::$name<'_>> for $name {
+      fn from(value: &super::$name<'_>) -> Self {
+        Self {
+          attributes: value.attributes.clone(),
+          email: value.email.map(String::from),
+          name: value.name.into(),
+          uri: value.uri.map(String::from),
+        }
+      }
+    }
+  };
+}
+
+/// Owned text constructs, [super::Title], [super::SubTitle], [super::Summary] and
+/// [super::Rights].
+macro_rules! owned_text {
+  ($name:ident, $element:expr) => {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct $name {
+      #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "AttributeMap::is_empty")
+      )]
+      pub(crate) attributes: AttributeMap,
+      pub value: XmlText,
+    }
+
+    impl $name {
+      pub fn new(value: XmlText) -> Self {
+        Self {
+          attributes: AttributeMap::default(),
+          value,
+        }
+      }
+
+      pub fn as_borrowed(&self) -> super::$name<'_> {
+        super::$name {
+          attributes: self.attributes.clone(),
+          value: self.value.as_borrowed(),
+        }
+      }
+
+      impl_attribute_fns!();
+    }
+
+    impl_serialize_for_text_node!($element, $name);
+
+    impl From<&super::$name<'_>> for $name {
+      fn from(value: &super::$name<'_>) -> Self {
+        Self {
+          attributes: value.attributes.clone(),
+          value: XmlText::from(&value.value),
+        }
+      }
+    }
+  };
+}
+
+/// Owned single value elements, [super::Icon], [super::Logo] and [super::Id].
+macro_rules! owned_value {
+  ($name:ident, $element:expr, $field:ident) => {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct $name {
+      #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "AttributeMap::is_empty")
+      )]
+      pub(crate) attributes: AttributeMap,
+      pub $field: String,
+    }
+
+    impl $name {
+      pub fn new($field: impl Into<String>) -> Self {
+        Self {
+          attributes: AttributeMap::default(),
+          $field: $field.into(),
+        }
+      }
+
+      pub fn as_borrowed(&self) -> super::$name<'_> {
+        super::$name {
+          attributes: self.attributes.clone(),
+          $field: &self.$field,
+        }
+      }
+
+      impl_attribute_fns!();
+    }
+
+    impl_serialize_for_value_node!($element, $name, $field);
+
+    impl From<&super::$name<'_>> for $name {
+      fn from(value: &super::$name<'_>) -> Self {
+        Self {
+          attributes: value.attributes.clone(),
+          $field: value.$field.into(),
+        }
+      }
+    }
+  };
+}
+
+owned_person!(Author, "author");
+owned_person!(Contributor, "contributor");
+owned_text!(Title, "title");
+owned_text!(SubTitle, "subtitle");
+owned_text!(Summary, "summary");
+owned_text!(Rights, "rights");
+owned_value!(Icon, "icon", uri);
+owned_value!(Logo, "logo", uri);
+owned_value!(Id, "id", value);
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Category {
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub label: Option<String>,
+  pub scheme: Option<String>,
+  pub term: String,
+}
+
+impl Category {
+  pub fn new(term: impl Into<String>) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      label: None,
+      scheme: None,
+      term: term.into(),
+    }
+  }
+
+  pub fn as_borrowed(&self) -> super::Category<'_> {
+    super::Category {
+      attributes: self.attributes.clone(),
+      label: self.label.as_deref(),
+      scheme: self.scheme.as_deref(),
+      term: &self.term,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl_serialize_for_category!(Category);
+
+impl From<&super::Category<'_>> for Category {
+  fn from(value: &super::Category<'_>) -> Self {
+    Self {
+      attributes: value.attributes.clone(),
+      label: value.label.map(String::from),
+      scheme: value.scheme.map(String::from),
+      term: value.term.into(),
+    }
+  }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Generator {
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub uri: Option<String>,
+  pub value: String,
+  pub version: Option<String>,
+}
+
+impl Default for Generator {
+  fn default() -> Self {
+    Self::from(&super::Generator::default())
+  }
+}
+
+impl Generator {
+  pub fn new(value: impl Into<String>) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      uri: None,
+      value: value.into(),
+      version: None,
+    }
+  }
+
+  pub fn as_borrowed(&self) -> super::Generator<'_> {
+    super::Generator {
+      attributes: self.attributes.clone(),
+      uri: self.uri.as_deref(),
+      value: &self.value,
+      version: self.version.as_deref(),
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl_serialize_for_generator!(Generator);
+
+impl From<&super::Generator<'_>> for Generator {
+  fn from(value: &super::Generator<'_>) -> Self {
+    Self {
+      attributes: value.attributes.clone(),
+      uri: value.uri.map(String::from),
+      value: value.value.into(),
+      version: value.version.map(String::from),
+    }
+  }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Link {
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub href: String,
+  pub hreflang: Option<String>,
+  pub length: Option<usize>,
+  #[cfg_attr(feature = "serde", serde(rename = "type"))]
+  pub link_type: Option<String>,
+  pub rel: Option<LinkRelation>,
+  pub title: Option<String>,
+}
+
+impl Link {
+  pub fn new(href: impl Into<String>) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      href: href.into(),
+      hreflang: None,
+      length: None,
+      link_type: None,
+      rel: None,
+      title: None,
+    }
+  }
+
+  pub fn as_borrowed(&self) -> super::Link<'_> {
+    super::Link {
+      attributes: self.attributes.clone(),
+      href: &self.href,
+      hreflang: self.hreflang.as_deref(),
+      length: self.length,
+      link_type: self.link_type.as_deref(),
+      rel: self.rel.clone(),
+      title: self.title.as_deref(),
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl_serialize_for_link!(Link);
+
+impl From<&super::Link<'_>> for Link {
+  fn from(value: &super::Link<'_>) -> Self {
+    Self {
+      attributes: value.attributes.clone(),
+      href: value.href.into(),
+      hreflang: value.hreflang.map(String::from),
+      length: value.length,
+      link_type: value.link_type.map(String::from),
+      rel: value.rel.clone(),
+      title: value.title.map(String::from),
+    }
+  }
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "kind", rename_all = "snake_case")
+)]
+pub enum ContentValue {
+  TextContent {
+    text: XmlText,
+  },
+  LinkContent {
+    media_type: Option<String>,
+    src: String,
+  },
+  InlinedMedia {
+    media_type: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::base64"))]
+    data: Vec<u8>,
+  },
+}
+
+impl From<XmlText> for ContentValue {
+  fn from(value: XmlText) -> Self {
+    Self::TextContent { text: value }
+  }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Content {
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub value: ContentValue,
+}
+
+impl Content {
+  pub fn new(value: ContentValue) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      value,
+    }
+  }
+
+  pub fn as_borrowed(&self) -> super::Content<'_> {
+    let value = match &self.value {
+      ContentValue::TextContent { text } => super::ContentValue::TextContent {
+        text: text.as_borrowed(),
+      },
+      ContentValue::LinkContent { media_type, src } => super::ContentValue::LinkContent {
+        media_type: media_type.as_deref(),
+        src,
+      },
+      ContentValue::InlinedMedia { media_type, data } => {
+        super::ContentValue::InlinedMedia { media_type, data }
+      }
+    };
+
+    super::Content {
+      attributes: self.attributes.clone(),
+      value,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl_serialize_for_content!(Content);
+
+impl From<&super::Content<'_>> for Content {
+  fn from(value: &super::Content<'_>) -> Self {
+    let content = match &value.value {
+      super::ContentValue::TextContent { text } => ContentValue::TextContent { text: text.into() },
+      super::ContentValue::LinkContent { media_type, src } => ContentValue::LinkContent {
+        media_type: media_type.map(String::from),
+        src: (*src).into(),
+      },
+      super::ContentValue::InlinedMedia { media_type, data } => ContentValue::InlinedMedia {
+        media_type: (*media_type).into(),
+        data: data.to_vec(),
+      },
+    };
+
+    Self {
+      attributes: value.attributes.clone(),
+      value: content,
+    }
+  }
+}
+
+/// Owned date constructs, [super::Updated] and [super::Published].
+macro_rules! owned_date {
+  ($name:ident, $element:expr) => {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct $name {
+      #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "AttributeMap::is_empty")
+      )]
+      pub(crate) attributes: AttributeMap,
+      pub value: TimeStamp,
+    }
+
+    impl $name {
+      pub fn new(value: TimeStamp) -> Self {
+        Self {
+          attributes: AttributeMap::default(),
+          value,
+        }
+      }
+
+      pub fn as_borrowed(&self) -> super::$name {
+        super::$name {
+          attributes: self.attributes.clone(),
+          value: self.value,
+        }
+      }
+
+      impl_attribute_fns!();
+    }
+
+    impl_serialize_for_date_node!($element, $name);
+
+    impl From<&super::$name> for $name {
+      fn from(value: &super::$name) -> Self {
+        Self {
+          attributes: value.attributes.clone(),
+          value: value.value,
+        }
+      }
+    }
+  };
+}
+
+owned_date!(Updated, "updated");
+owned_date!(Published, "published");
+
+#[inline]
+fn borrow_all<'a, T, B>(values: &'a [T], f: impl Fn(&'a T) -> B) -> Vec<B> {
+  values.iter().map(f).collect()
+}
+
+#[inline]
+fn own_all<'a, B: 'a, T>(values: &'a [B]) -> Vec<T>
+where
+  T: From<&'a B>,
+{
+  values.iter().map(T::from).collect()
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Source {
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub authors: Vec<Author>,
+  pub categories: Vec<Category>,
+  pub contributors: Vec<Contributor>,
+  pub generator: Option<Generator>,
+  pub icon: Option<Icon>,
+  pub id: Option<Id>,
+  pub links: Vec<Link>,
+  pub logo: Option<Logo>,
+  pub rights: Option<Rights>,
+  pub subtitle: Option<SubTitle>,
+  pub title: Option<Title>,
+  pub updated: Option<Updated>,
+}
+
+impl Source {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn as_borrowed(&self) -> super::Source<'_> {
+    super::Source {
+      attributes: self.attributes.clone(),
+      authors: borrow_all(&self.authors, Author::as_borrowed),
+      categories: borrow_all(&self.categories, Category::as_borrowed),
+      contributors: borrow_all(&self.contributors, Contributor::as_borrowed),
+      generator: self.generator.as_ref().map(Generator::as_borrowed),
+      icon: self.icon.as_ref().map(Icon::as_borrowed),
+      id: self.id.as_ref().map(Id::as_borrowed),
+      links: borrow_all(&self.links, Link::as_borrowed),
+      logo: self.logo.as_ref().map(Logo::as_borrowed),
+      rights: self.rights.as_ref().map(Rights::as_borrowed),
+      subtitle: self.subtitle.as_ref().map(SubTitle::as_borrowed),
+      title: self.title.as_ref().map(Title::as_borrowed),
+      updated: self.updated.as_ref().map(Updated::as_borrowed),
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl_serialize_for_source!(Source);
+
+impl From<&super::Source<'_>> for Source {
+  fn from(value: &super::Source<'_>) -> Self {
+    Self {
+      attributes: value.attributes.clone(),
+      authors: own_all(&value.authors),
+      categories: own_all(&value.categories),
+      contributors: own_all(&value.contributors),
+      generator: value.generator.as_ref().map(Generator::from),
+      icon: value.icon.as_ref().map(Icon::from),
+      id: value.id.as_ref().map(Id::from),
+      links: own_all(&value.links),
+      logo: value.logo.as_ref().map(Logo::from),
+      rights: value.rights.as_ref().map(Rights::from),
+      subtitle: value.subtitle.as_ref().map(SubTitle::from),
+      title: value.title.as_ref().map(Title::from),
+      updated: value.updated.as_ref().map(Updated::from),
+    }
+  }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entry {
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub authors: Vec<Author>,
+  pub categories: Vec<Category>,
+  pub content: Option<Content>,
+  pub contributors: Vec<Contributor>,
+  pub id: Id,
+  pub links: Vec<Link>,
+  pub published: Option<Published>,
+  pub source: Option<Source>,
+  pub summary: Option<Summary>,
+  pub title: Title,
+  pub updated: Updated,
+  pub rights: Option<Rights>,
+}
+
+impl Entry {
+  pub fn new(id: impl Into<String>, title: XmlText, updated: TimeStamp) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      authors: Vec::default(),
+      categories: Vec::default(),
+      content: None,
+      contributors: Vec::default(),
+      id: Id::new(id),
+      links: Vec::default(),
+      published: None,
+      rights: None,
+      source: None,
+      summary: None,
+      title: Title::new(title),
+      updated: Updated::new(updated),
+    }
+  }
+
+  pub fn as_borrowed(&self) -> super::Entry<'_> {
+    super::Entry {
+      attributes: self.attributes.clone(),
+      authors: borrow_all(&self.authors, Author::as_borrowed),
+      categories: borrow_all(&self.categories, Category::as_borrowed),
+      content: self.content.as_ref().map(Content::as_borrowed),
+      contributors: borrow_all(&self.contributors, Contributor::as_borrowed),
+      id: self.id.as_borrowed(),
+      links: borrow_all(&self.links, Link::as_borrowed),
+      published: self.published.as_ref().map(Published::as_borrowed),
+      rights: self.rights.as_ref().map(Rights::as_borrowed),
+      source: self.source.as_ref().map(Source::as_borrowed),
+      summary: self.summary.as_ref().map(Summary::as_borrowed),
+      title: self.title.as_borrowed(),
+      updated: self.updated.as_borrowed(),
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl From<&super::Entry<'_>> for Entry {
+  fn from(value: &super::Entry<'_>) -> Self {
+    Self {
+      attributes: value.attributes.clone(),
+      authors: own_all(&value.authors),
+      categories: own_all(&value.categories),
+      content: value.content.as_ref().map(Content::from),
+      contributors: own_all(&value.contributors),
+      id: Id::from(&value.id),
+      links: own_all(&value.links),
+      published: value.published.as_ref().map(Published::from),
+      rights: value.rights.as_ref().map(Rights::from),
+      source: value.source.as_ref().map(Source::from),
+      summary: value.summary.as_ref().map(Summary::from),
+      title: Title::from(&value.title),
+      updated: Updated::from(&value.updated),
+    }
+  }
+}
+
+impl_serialize_for_entry!(Entry);
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Feed {
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub authors: Vec<Author>,
+  pub categories: Vec<Category>,
+  pub contributors: Vec<Contributor>,
+  pub entries: Vec<Entry>,
+  pub generator: Option<Generator>,
+  pub icon: Option<Icon>,
+  pub id: Id,
+  pub links: Vec<Link>,
+  pub logo: Option<Logo>,
+  pub rights: Option<Rights>,
+  pub subtitle: Option<SubTitle>,
+  pub title: Title,
+  pub updated: Updated,
+}
+
+impl Feed {
+  pub fn new(id: impl Into<String>, title: XmlText, updated: TimeStamp) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      authors: Vec::default(),
+      categories: Vec::default(),
+      contributors: Vec::default(),
+      entries: Vec::default(),
+      generator: Some(Generator::default()),
+      icon: None,
+      id: Id::new(id),
+      links: Vec::default(),
+      logo: None,
+      rights: None,
+      subtitle: None,
+      title: Title::new(title),
+      updated: Updated::new(updated),
+    }
+  }
+
+  /// Borrowed form of the feed. Text values are borrowed, but every attribute map, including the
+  /// attribute values, and every element list is copied, so prefer serializing the owned feed
+  /// directly.
+  pub fn as_borrowed(&self) -> super::Feed<'_> {
+    super::Feed {
+      attributes: self.attributes.clone(),
+      authors: borrow_all(&self.authors, Author::as_borrowed),
+      categories: borrow_all(&self.categories, Category::as_borrowed),
+      contributors: borrow_all(&self.contributors, Contributor::as_borrowed),
+      entries: borrow_all(&self.entries, Entry::as_borrowed),
+      generator: self.generator.as_ref().map(Generator::as_borrowed),
+      icon: self.icon.as_ref().map(Icon::as_borrowed),
+      id: self.id.as_borrowed(),
+      links: borrow_all(&self.links, Link::as_borrowed),
+      logo: self.logo.as_ref().map(Logo::as_borrowed),
+      rights: self.rights.as_ref().map(Rights::as_borrowed),
+      subtitle: self.subtitle.as_ref().map(SubTitle::as_borrowed),
+      title: self.title.as_borrowed(),
+      updated: self.updated.as_borrowed(),
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl From<&super::Feed<'_>> for Feed {
+  fn from(value: &super::Feed<'_>) -> Self {
+    Self {
+      attributes: value.attributes.clone(),
+      authors: own_all(&value.authors),
+      categories: own_all(&value.categories),
+      contributors: own_all(&value.contributors),
+      entries: own_all(&value.entries),
+      generator: value.generator.as_ref().map(Generator::from),
+      icon: value.icon.as_ref().map(Icon::from),
+      id: Id::from(&value.id),
+      links: own_all(&value.links),
+      logo: value.logo.as_ref().map(Logo::from),
+      rights: value.rights.as_ref().map(Rights::from),
+      subtitle: value.subtitle.as_ref().map(SubTitle::from),
+      title: Title::from(&value.title),
+      updated: Updated::from(&value.updated),
+    }
+  }
+}
+
+impl_serialize_for_feed!(Feed);
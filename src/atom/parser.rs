@@ -0,0 +1,599 @@
+use core::str::FromStr;
+
+use crate::{
+  common::{AttributeMap, AttributeName, LinkRelation, TimeStamp, XmlText},
+  error::XmlParseError,
+  escape::unescape,
+  reader::{StartElement, StringArena, XmlEvent, XmlReader},
+  utils::base64,
+};
+
+use super::{
+  is_text_media_type, is_xml_media_type, Author, Category, Content, ContentValue, Contributor,
+  Entry, Feed, Generator, Icon, Id, Link, Logo, Published, Rights, Source, SubTitle, Summary,
+  Title, Updated, ATOM_NAMESPACE, XHTML_NAMESPACE,
+};
+
+/// Parses an Atom feed document.
+///
+/// Parser doesn't copy the text values. They are borrowed from `input`, except the values with
+/// XML entities or CDATA sections mixed with text. Those are decoded into `arena`, so both must
+/// outlive the returned feed.
+///
+/// ```
+/// use syndication_format::{atom, reader::StringArena};
+///
+/// let input = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+///   <title>Example Feed</title>
+///   <id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>
+///   <updated>2003-12-13T18:30:02Z</updated>
+/// </feed>"#;
+///
+/// let arena = StringArena::new();
+/// let feed = atom::parse(input, &arena).unwrap();
+///
+/// assert_eq!("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6", feed.id.value);
+/// ```
+pub fn parse<'a>(input: &'a str, arena: &'a StringArena) -> Result<Feed<'a>, XmlParseError> {
+  let mut parser = AtomParser::new(XmlReader::new(input), arena);
+  let root = parser.root("feed")?;
+  let feed = parser.feed(root)?;
+  parser.finish()?;
+
+  Ok(feed)
+}
+
+/// Parses an Atom entry document, an `atom:entry` element as the root.
+pub fn parse_entry<'a>(input: &'a str, arena: &'a StringArena) -> Result<Entry<'a>, XmlParseError> {
+  let mut parser = AtomParser::new(XmlReader::new(input), arena);
+  let root = parser.root("entry")?;
+  let entry = parser.entry(root)?;
+  parser.finish()?;
+
+  Ok(entry)
+}
+
+/// Unwraps required element fields.
+macro_rules! required {
+  ($value:expr, $name:expr) => {
+    $value.ok_or(XmlParseError::MissingElement { name: $name })?
+  };
+}
+
+pub(crate) struct AtomParser<'a> {
+  reader: XmlReader<'a>,
+  arena: &'a StringArena,
+}
+
+impl<'a> AtomParser<'a> {
+  pub(crate) fn new(reader: XmlReader<'a>, arena: &'a StringArena) -> Self {
+    Self { reader, arena }
+  }
+
+  /// Skips the prolog and returns the root element when it's the expected Atom element.
+  pub(crate) fn root(&mut self, name: &str) -> Result<StartElement<'a>, XmlParseError> {
+    loop {
+      match self.reader.next_event()? {
+        Some(XmlEvent::Start(start)) if is_atom(&start, name) => return Ok(start),
+        Some(XmlEvent::Start(start)) => {
+          return Err(XmlParseError::UnexpectedElement {
+            position: start.position(),
+          })
+        }
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    }
+  }
+
+  /// Consumes the trailing comments and processing instructions after the root element.
+  pub(crate) fn finish(&mut self) -> Result<(), XmlParseError> {
+    while self.reader.next_event()?.is_some() {}
+    Ok(())
+  }
+
+  /// Returns the next child element of the current element, or `None` when the current element
+  /// ends.
+  fn next_child(&mut self) -> Result<Option<StartElement<'a>>, XmlParseError> {
+    loop {
+      match self.reader.next_event()? {
+        Some(XmlEvent::Start(start)) => return Ok(Some(start)),
+        Some(XmlEvent::End(_)) => return Ok(None),
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    }
+  }
+
+  #[inline]
+  fn read_text(&mut self) -> Result<&'a str, XmlParseError> {
+    let text = self.reader.read_text()?;
+    Ok(self.arena.store(text))
+  }
+
+  /// Returns the markup between the start and the end tag as is.
+  fn read_markup(&mut self, start: &StartElement<'a>) -> Result<&'a str, XmlParseError> {
+    let end = self.reader.skip_element()?;
+    Ok(&self.reader.input()[start.end_position()..end.position()])
+  }
+
+  /// Reads the markup of an XHTML text construct. Declaration of the XHTML `div` prefix made on
+  /// an ancestor element is copied to the `div`, so the markup stays valid on its own.
+  fn read_xhtml(&mut self, start: &StartElement<'a>) -> Result<&'a str, XmlParseError> {
+    let div = loop {
+      match self.reader.next_event()? {
+        Some(XmlEvent::Start(child)) => {
+          self.reader.skip_element()?;
+          break child;
+        }
+        Some(XmlEvent::End(end)) => {
+          return Ok(&self.reader.input()[start.end_position()..end.position()]);
+        }
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    };
+
+    let end = self.reader.skip_element()?;
+    let markup = &self.reader.input()[start.end_position()..end.position()];
+
+    if div.local_name() != "div" || div.namespace() != Some(XHTML_NAMESPACE) {
+      return Ok(markup);
+    }
+
+    let declaration = match div.prefix() {
+      Some(prefix) => format!("xmlns:{}", prefix),
+      None => String::from("xmlns"),
+    };
+
+    if div.attribute(&declaration).is_some() {
+      return Ok(markup);
+    }
+
+    let (head, tail) =
+      markup.split_at(div.position() - start.end_position() + 1 + div.name().len());
+
+    Ok(self.arena.alloc(format!(
+      "{} {}=\"{}\"{}",
+      head, declaration, XHTML_NAMESPACE, tail
+    )))
+  }
+
+  #[inline]
+  fn read_attribute(
+    &self,
+    start: &StartElement<'a>,
+    name: &str,
+  ) -> Result<Option<&'a str>, XmlParseError> {
+    match start.attribute(name) {
+      Some(value) => Ok(Some(self.arena.store(unescape(value)?))),
+      None => Ok(None),
+    }
+  }
+
+  /// Copies element attributes except the ones mapped to struct fields.
+  fn read_attributes(
+    &self,
+    start: &StartElement<'a>,
+    mapped: &[&str],
+  ) -> Result<AttributeMap, XmlParseError> {
+    let mut attributes = AttributeMap::new();
+
+    for attribute in start.attributes() {
+      if mapped.contains(&attribute.name) {
+        continue;
+      }
+
+      attributes.set(
+        AttributeName::new(attribute.name)?,
+        unescape(attribute.value)?.into(),
+      );
+    }
+
+    Ok(attributes)
+  }
+
+  fn read_xml_text(&mut self, start: &StartElement<'a>) -> Result<XmlText<'a>, XmlParseError> {
+    match start.attribute("type") {
+      Some("html") => Ok(XmlText::Html(self.read_text()?)),
+      Some("xhtml") => Ok(XmlText::Xhtml(self.read_xhtml(start)?)),
+      _ => Ok(XmlText::PlainText(self.read_text()?)),
+    }
+  }
+
+  fn read_timestamp(&mut self) -> Result<TimeStamp, XmlParseError> {
+    let value = self.read_text()?;
+    Ok(TimeStamp::from_str(value.trim())?)
+  }
+
+  pub(crate) fn feed(&mut self, start: StartElement<'a>) -> Result<Feed<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &[])?;
+    let mut authors = Vec::new();
+    let mut categories = Vec::new();
+    let mut contributors = Vec::new();
+    let mut entries = Vec::new();
+    let mut generator = None;
+    let mut icon = None;
+    let mut id = None;
+    let mut links = Vec::new();
+    let mut logo = None;
+    let mut rights = None;
+    let mut subtitle = None;
+    let mut title = None;
+    let mut updated = None;
+
+    while let Some(child) = self.next_child()? {
+      if !is_atom(&child, child.local_name()) {
+        self.reader.skip_element()?;
+        continue;
+      }
+
+      match child.local_name() {
+        "author" => authors.push(self.author(child)?),
+        "category" => categories.push(self.category(child)?),
+        "contributor" => contributors.push(self.contributor(child)?),
+        "entry" => entries.push(self.entry(child)?),
+        "generator" => generator = Some(self.generator(child)?),
+        "icon" => icon = Some(self.icon(child)?),
+        "id" => id = Some(self.id(child)?),
+        "link" => links.push(self.link(child)?),
+        "logo" => logo = Some(self.logo(child)?),
+        "rights" => rights = Some(self.rights(child)?),
+        "subtitle" => subtitle = Some(self.subtitle(child)?),
+        "title" => title = Some(self.title(child)?),
+        "updated" => updated = Some(self.updated(child)?),
+        _ => {
+          self.reader.skip_element()?;
+        }
+      }
+    }
+
+    Ok(Feed {
+      attributes,
+      authors,
+      categories,
+      contributors,
+      entries,
+      generator,
+      icon,
+      id: required!(id, "id"),
+      links,
+      logo,
+      rights,
+      subtitle,
+      title: required!(title, "title"),
+      updated: required!(updated, "updated"),
+    })
+  }
+
+  pub(crate) fn entry(&mut self, start: StartElement<'a>) -> Result<Entry<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &[])?;
+    let mut authors = Vec::new();
+    let mut categories = Vec::new();
+    let mut content = None;
+    let mut contributors = Vec::new();
+    let mut id = None;
+    let mut links = Vec::new();
+    let mut published = None;
+    let mut rights = None;
+    let mut source = None;
+    let mut summary = None;
+    let mut title = None;
+    let mut updated = None;
+
+    while let Some(child) = self.next_child()? {
+      if !is_atom(&child, child.local_name()) {
+        self.reader.skip_element()?;
+        continue;
+      }
+
+      match child.local_name() {
+        "author" => authors.push(self.author(child)?),
+        "category" => categories.push(self.category(child)?),
+        "content" => content = Some(self.content(child)?),
+        "contributor" => contributors.push(self.contributor(child)?),
+        "id" => id = Some(self.id(child)?),
+        "link" => links.push(self.link(child)?),
+        "published" => published = Some(self.published(child)?),
+        "rights" => rights = Some(self.rights(child)?),
+        "source" => source = Some(self.source(child)?),
+        "summary" => summary = Some(self.summary(child)?),
+        "title" => title = Some(self.title(child)?),
+        "updated" => updated = Some(self.updated(child)?),
+        _ => {
+          self.reader.skip_element()?;
+        }
+      }
+    }
+
+    Ok(Entry {
+      attributes,
+      authors,
+      categories,
+      content,
+      contributors,
+      id: required!(id, "id"),
+      links,
+      published,
+      rights,
+      source,
+      summary,
+      title: required!(title, "title"),
+      updated: required!(updated, "updated"),
+    })
+  }
+
+  fn source(&mut self, start: StartElement<'a>) -> Result<Source<'a>, XmlParseError> {
+    let mut source = Source {
+      attributes: self.read_attributes(&start, &[])?,
+      ..Source::default()
+    };
+
+    while let Some(child) = self.next_child()? {
+      if !is_atom(&child, child.local_name()) {
+        self.reader.skip_element()?;
+        continue;
+      }
+
+      match child.local_name() {
+        "author" => source.authors.push(self.author(child)?),
+        "category" => source.categories.push(self.category(child)?),
+        "contributor" => source.contributors.push(self.contributor(child)?),
+        "generator" => source.generator = Some(self.generator(child)?),
+        "icon" => source.icon = Some(self.icon(child)?),
+        "id" => source.id = Some(self.id(child)?),
+        "link" => source.links.push(self.link(child)?),
+        "logo" => source.logo = Some(self.logo(child)?),
+        "rights" => source.rights = Some(self.rights(child)?),
+        "subtitle" => source.subtitle = Some(self.subtitle(child)?),
+        "title" => source.title = Some(self.title(child)?),
+        "updated" => source.updated = Some(self.updated(child)?),
+        _ => {
+          self.reader.skip_element()?;
+        }
+      }
+    }
+
+    Ok(source)
+  }
+
+  /// Reads Atom person construct as `(attributes, name, uri, email)`.
+  #[allow(clippy::type_complexity)]
+  fn person(
+    &mut self,
+    start: StartElement<'a>,
+  ) -> Result<(AttributeMap, &'a str, Option<&'a str>, Option<&'a str>), XmlParseError> {
+    let attributes = self.read_attributes(&start, &[])?;
+    let mut name = None;
+    let mut uri = None;
+    let mut email = None;
+
+    while let Some(child) = self.next_child()? {
+      if is_atom(&child, "name") {
+        name = Some(self.read_text()?);
+      } else if is_atom(&child, "uri") {
+        uri = Some(self.read_text()?);
+      } else if is_atom(&child, "email") {
+        email = Some(self.read_text()?);
+      } else {
+        self.reader.skip_element()?;
+      }
+    }
+
+    Ok((attributes, required!(name, "name"), uri, email))
+  }
+
+  fn author(&mut self, start: StartElement<'a>) -> Result<Author<'a>, XmlParseError> {
+    let (attributes, name, uri, email) = self.person(start)?;
+
+    Ok(Author {
+      attributes,
+      email,
+      name,
+      uri,
+    })
+  }
+
+  fn contributor(&mut self, start: StartElement<'a>) -> Result<Contributor<'a>, XmlParseError> {
+    let (attributes, name, uri, email) = self.person(start)?;
+
+    Ok(Contributor {
+      attributes,
+      email,
+      name,
+      uri,
+    })
+  }
+
+  fn category(&mut self, start: StartElement<'a>) -> Result<Category<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &["term", "scheme", "label"])?;
+    let term = self
+      .read_attribute(&start, "term")?
+      .ok_or(XmlParseError::MissingAttribute { name: "term" })?;
+    let scheme = self.read_attribute(&start, "scheme")?;
+    let label = self.read_attribute(&start, "label")?;
+    self.reader.skip_element()?;
+
+    Ok(Category {
+      attributes,
+      label,
+      scheme,
+      term,
+    })
+  }
+
+  fn generator(&mut self, start: StartElement<'a>) -> Result<Generator<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &["uri", "version"])?;
+    let uri = self.read_attribute(&start, "uri")?;
+    let version = self.read_attribute(&start, "version")?;
+    let value = self.read_text()?;
+
+    Ok(Generator {
+      attributes,
+      uri,
+      value,
+      version,
+    })
+  }
+
+  fn icon(&mut self, start: StartElement<'a>) -> Result<Icon<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &[])?;
+    let uri = self.read_text()?;
+
+    Ok(Icon { attributes, uri })
+  }
+
+  fn logo(&mut self, start: StartElement<'a>) -> Result<Logo<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &[])?;
+    let uri = self.read_text()?;
+
+    Ok(Logo { attributes, uri })
+  }
+
+  fn id(&mut self, start: StartElement<'a>) -> Result<Id<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &[])?;
+    let value = self.read_text()?.trim();
+
+    Ok(Id { attributes, value })
+  }
+
+  fn link(&mut self, start: StartElement<'a>) -> Result<Link<'a>, XmlParseError> {
+    let mut attributes = self.read_attributes(
+      &start,
+      &["href", "hreflang", "rel", "type", "title", "length"],
+    )?;
+    let href = self
+      .read_attribute(&start, "href")?
+      .ok_or(XmlParseError::MissingAttribute { name: "href" })?;
+    let hreflang = self.read_attribute(&start, "hreflang")?;
+    let link_type = self.read_attribute(&start, "type")?;
+    let title = self.read_attribute(&start, "title")?;
+
+    // Values that can't be represented with the struct fields are kept as plain attributes.
+    let rel = match self.read_attribute(&start, "rel")? {
+      Some(value) => match LinkRelation::new(value) {
+        Ok(rel) => Some(rel),
+        Err(_) => {
+          attributes.set(AttributeName::unchecked_new("rel"), value.into());
+          None
+        }
+      },
+      None => None,
+    };
+
+    let length = match self.read_attribute(&start, "length")? {
+      Some(value) => match value.trim().parse::<usize>() {
+        Ok(length) => Some(length),
+        Err(_) => {
+          attributes.set(AttributeName::unchecked_new("length"), value.into());
+          None
+        }
+      },
+      None => None,
+    };
+
+    self.reader.skip_element()?;
+
+    Ok(Link {
+      attributes,
+      href,
+      hreflang,
+      length,
+      link_type,
+      rel,
+      title,
+    })
+  }
+
+  fn rights(&mut self, start: StartElement<'a>) -> Result<Rights<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &["type"])?;
+    let value = self.read_xml_text(&start)?;
+
+    Ok(Rights { attributes, value })
+  }
+
+  fn subtitle(&mut self, start: StartElement<'a>) -> Result<SubTitle<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &["type"])?;
+    let value = self.read_xml_text(&start)?;
+
+    Ok(SubTitle { attributes, value })
+  }
+
+  fn summary(&mut self, start: StartElement<'a>) -> Result<Summary<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &["type"])?;
+    let value = self.read_xml_text(&start)?;
+
+    Ok(Summary { attributes, value })
+  }
+
+  fn title(&mut self, start: StartElement<'a>) -> Result<Title<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &["type"])?;
+    let value = self.read_xml_text(&start)?;
+
+    Ok(Title { attributes, value })
+  }
+
+  fn updated(&mut self, start: StartElement<'a>) -> Result<Updated, XmlParseError> {
+    let attributes = self.read_attributes(&start, &[])?;
+    let value = self.read_timestamp()?;
+
+    Ok(Updated { attributes, value })
+  }
+
+  fn published(&mut self, start: StartElement<'a>) -> Result<Published, XmlParseError> {
+    let attributes = self.read_attributes(&start, &[])?;
+    let value = self.read_timestamp()?;
+
+    Ok(Published { attributes, value })
+  }
+
+  fn content(&mut self, start: StartElement<'a>) -> Result<Content<'a>, XmlParseError> {
+    let attributes = self.read_attributes(&start, &["type", "src"])?;
+    let media_type = self.read_attribute(&start, "type")?;
+
+    let value = if let Some(src) = self.read_attribute(&start, "src")? {
+      self.reader.skip_element()?;
+      ContentValue::LinkContent { media_type, src }
+    } else {
+      match media_type {
+        None | Some("text") => ContentValue::TextContent {
+          text: XmlText::PlainText(self.read_text()?),
+        },
+        Some("html") => ContentValue::TextContent {
+          text: XmlText::Html(self.read_text()?),
+        },
+        Some("xhtml") => ContentValue::TextContent {
+          text: XmlText::Xhtml(self.read_xhtml(&start)?),
+        },
+        Some(media_type) if is_xml_media_type(media_type) => ContentValue::InlinedMedia {
+          media_type,
+          data: self.read_markup(&start)?.as_bytes(),
+        },
+        Some(media_type) if is_text_media_type(media_type) => ContentValue::InlinedMedia {
+          media_type,
+          data: self.read_text()?.as_bytes(),
+        },
+        Some(media_type) => {
+          let data =
+            base64::decode(&self.reader.read_text()?).ok_or(XmlParseError::InvalidBase64 {
+              position: start.position(),
+            })?;
+
+          ContentValue::InlinedMedia {
+            media_type,
+            data: self.arena.alloc_bytes(data),
+          }
+        }
+      }
+    };
+
+    Ok(Content { attributes, value })
+  }
+}
+
+/// Checks element name and namespace. Elements without namespace are also accepted as Atom
+/// elements, to tolerate documents which don't declare the Atom namespace.
+#[inline]
+pub(crate) fn is_atom(start: &StartElement<'_>, name: &str) -> bool {
+  start.local_name() == name && matches!(start.namespace(), None | Some(ATOM_NAMESPACE))
+}
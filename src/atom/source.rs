@@ -0,0 +1,114 @@
+use crate::common::AttributeMap;
+
+use super::{
+  Author, Category, Contributor, Generator, Icon, Id, Link, Logo, Rights, SubTitle, Title, Updated,
+};
+
+/// Metadata of the feed an entry was copied from, RFC4287 section 4.2.11. All elements are
+/// optional, aggregators should keep at least the `id`, `title` and `updated` of the original
+/// feed.
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Source<'a> {
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "AttributeMap::is_empty")
+  )]
+  pub(crate) attributes: AttributeMap,
+  pub authors: Vec<Author<'a>>,
+  pub categories: Vec<Category<'a>>,
+  pub contributors: Vec<Contributor<'a>>,
+  pub generator: Option<Generator<'a>>,
+  pub icon: Option<Icon<'a>>,
+  pub id: Option<Id<'a>>,
+  pub links: Vec<Link<'a>>,
+  pub logo: Option<Logo<'a>>,
+  pub rights: Option<Rights<'a>>,
+  pub subtitle: Option<SubTitle<'a>>,
+  pub title: Option<Title<'a>>,
+  pub updated: Option<Updated>,
+}
+
+/// Serializes [Source] and [super::owned::Source].
+macro_rules! impl_serialize_for_source {
+  ($type:ty) => {
+    impl $crate::serializer::Serialize for $type {
+      fn serialize<S>(
+        &self,
+        serializer: S,
+        namespace: Option<&str>,
+      ) -> Result<(), $crate::error::XmlSerializeError>
+      where
+        S: $crate::serializer::Serializer,
+      {
+        use $crate::serializer::ElementSerializer;
+
+        let mut source =
+          serializer.serialize_element("source", namespace, Some(&self.attributes))?;
+
+        for author in self.authors.iter() {
+          source.serialize(author, namespace)?;
+        }
+
+        for contributor in self.contributors.iter() {
+          source.serialize(contributor, namespace)?;
+        }
+
+        for category in self.categories.iter() {
+          source.serialize(category, namespace)?;
+        }
+
+        for link in self.links.iter() {
+          source.serialize(link, namespace)?;
+        }
+
+        if let Some(generator) = self.generator.as_ref() {
+          source.serialize(generator, namespace)?;
+        }
+
+        if let Some(subtitle) = self.subtitle.as_ref() {
+          source.serialize(subtitle, namespace)?;
+        }
+
+        if let Some(logo) = self.logo.as_ref() {
+          source.serialize(logo, namespace)?;
+        }
+
+        if let Some(icon) = self.icon.as_ref() {
+          source.serialize(icon, namespace)?;
+        }
+
+        if let Some(rights) = self.rights.as_ref() {
+          source.serialize(rights, namespace)?;
+        }
+
+        if let Some(title) = self.title.as_ref() {
+          source.serialize(title, namespace)?;
+        }
+
+        if let Some(id) = self.id.as_ref() {
+          source.serialize(id, namespace)?;
+        }
+
+        if let Some(updated) = self.updated.as_ref() {
+          source.serialize(updated, namespace)?;
+        }
+
+        source.end()?;
+        Ok(())
+      }
+    }
+  };
+}
+
+pub(crate) use impl_serialize_for_source;
+
+impl_serialize_for_source!(Source<'_>);
+
+impl Source<'_> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  impl_attribute_fns!();
+}
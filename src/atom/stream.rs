@@ -0,0 +1,226 @@
+use std::io::BufRead;
+
+use crate::{
+  error::XmlParseError,
+  escape::unescape,
+  reader::{
+    chunk::{ChunkReader, NodeKind},
+    NamespaceBinding, StringArena, XmlEvent, XmlReader,
+  },
+};
+
+use super::{is_atom, parser::AtomParser, Entry, Feed};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ReaderState {
+  Prolog,
+  PendingEntry,
+  Entries,
+  Done,
+}
+
+/// Streaming Atom feed reader.
+///
+/// Reader keeps only the feed metadata and a single entry in memory, entries are parsed one at a
+/// time as they're requested. Metadata elements are expected before the entries as RFC4287
+/// examples do, the ones placed after the first entry are skipped.
+///
+/// ```
+/// use std::io::Cursor;
+/// use syndication_format::atom::FeedReader;
+///
+/// let input = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+///   <title>Example Feed</title>
+///   <id>urn:feed</id>
+///   <updated>2003-12-13T18:30:02Z</updated>
+///   <entry>
+///     <title>Entry</title>
+///     <id>urn:entry</id>
+///     <updated>2003-12-13T18:30:02Z</updated>
+///   </entry>
+/// </feed>"#;
+///
+/// let mut reader = FeedReader::new(Cursor::new(input));
+/// assert_eq!("urn:feed", reader.feed().unwrap().id.value);
+///
+/// while let Some(entry) = reader.next_entry().unwrap() {
+///   assert_eq!("urn:entry", entry.id.value);
+/// }
+/// ```
+pub struct FeedReader<R>
+where
+  R: BufRead,
+{
+  arena: StringArena,
+  buffer: Vec<u8>,
+  chunks: ChunkReader<R>,
+  metadata: Vec<u8>,
+  namespaces: Vec<(String, String)>,
+  state: ReaderState,
+}
+
+impl<R> FeedReader<R>
+where
+  R: BufRead,
+{
+  pub fn new(reader: R) -> Self {
+    Self {
+      arena: StringArena::new(),
+      buffer: Vec::new(),
+      chunks: ChunkReader::new(reader),
+      metadata: Vec::new(),
+      namespaces: Vec::new(),
+      state: ReaderState::Prolog,
+    }
+  }
+
+  /// Returns the feed metadata. Returned feed has no entries, use [`FeedReader::next_entry`] to
+  /// read them.
+  pub fn feed(&mut self) -> Result<Feed<'_>, XmlParseError> {
+    self.read_metadata()?;
+    self.arena.clear();
+
+    let input = core::str::from_utf8(&self.metadata)?;
+    let mut parser = AtomParser::new(XmlReader::new(input), &self.arena);
+    let root = parser.root("feed")?;
+    let feed = parser.feed(root)?;
+    parser.finish()?;
+
+    Ok(feed)
+  }
+
+  /// Reads the next entry. Returns `None` after the last entry.
+  pub fn next_entry(&mut self) -> Result<Option<Entry<'_>>, XmlParseError> {
+    self.read_metadata()?;
+
+    loop {
+      match self.state {
+        ReaderState::Done | ReaderState::Prolog => return Ok(None),
+        ReaderState::PendingEntry => {
+          self.state = ReaderState::Entries;
+          break;
+        }
+        ReaderState::Entries => {
+          self.buffer.clear();
+
+          match self.chunks.read_node(&mut self.buffer)? {
+            Some(NodeKind::Start { is_empty }) => {
+              if !is_empty {
+                self.chunks.read_element(&mut self.buffer)?;
+              }
+
+              if self.is_entry()? {
+                break;
+              }
+            }
+            Some(NodeKind::End) => self.state = ReaderState::Done,
+            Some(_) => continue,
+            None => return Err(XmlParseError::UnexpectedEof),
+          }
+        }
+      }
+    }
+
+    self.arena.clear();
+
+    let input = core::str::from_utf8(&self.buffer)?;
+    let reader = XmlReader::with_namespaces(input, self.bindings());
+    let mut parser = AtomParser::new(reader, &self.arena);
+    let root = parser.root("entry")?;
+    let entry = parser.entry(root)?;
+    parser.finish()?;
+
+    Ok(Some(entry))
+  }
+
+  #[inline]
+  fn bindings(&self) -> impl Iterator<Item = NamespaceBinding<'_>> {
+    self
+      .namespaces
+      .iter()
+      .map(|(prefix, uri)| NamespaceBinding { prefix, uri })
+  }
+
+  /// Checks whether the element in the buffer is an Atom entry.
+  fn is_entry(&self) -> Result<bool, XmlParseError> {
+    let input = core::str::from_utf8(&self.buffer)?;
+    let mut reader = XmlReader::with_namespaces(input, self.bindings());
+
+    match reader.next_event()? {
+      Some(XmlEvent::Start(start)) => Ok(is_atom(&start, "entry")),
+      _ => Ok(false),
+    }
+  }
+
+  /// Reads the root element and its children until the first entry.
+  fn read_metadata(&mut self) -> Result<(), XmlParseError> {
+    if self.state != ReaderState::Prolog {
+      return Ok(());
+    }
+
+    let is_empty = loop {
+      self.metadata.clear();
+
+      match self.chunks.read_node(&mut self.metadata)? {
+        Some(NodeKind::Start { is_empty }) => break is_empty,
+        Some(NodeKind::End) => return Err(XmlParseError::InvalidSyntax { position: 0 }),
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    };
+
+    let root_name = {
+      let input = core::str::from_utf8(&self.metadata)?;
+      let mut reader = XmlReader::new(input);
+
+      let root = match reader.next_event()? {
+        Some(XmlEvent::Start(root)) if is_atom(&root, "feed") => root,
+        _ => return Err(XmlParseError::UnexpectedElement { position: 0 }),
+      };
+
+      for attribute in root.attributes() {
+        if let Some(prefix) = attribute.namespace_declaration() {
+          let uri = unescape(attribute.value)?;
+          self.namespaces.push((prefix.to_owned(), uri.into_owned()));
+        }
+      }
+
+      root.name().to_owned()
+    };
+
+    if is_empty {
+      self.state = ReaderState::Done;
+      return Ok(());
+    }
+
+    loop {
+      let node_start = self.metadata.len();
+
+      match self.chunks.read_node(&mut self.metadata)? {
+        Some(NodeKind::Start { is_empty }) => {
+          if !is_empty {
+            self.chunks.read_element(&mut self.metadata)?;
+          }
+
+          self.buffer.clear();
+          self.buffer.extend_from_slice(&self.metadata[node_start..]);
+
+          if self.is_entry()? {
+            self.metadata.truncate(node_start);
+            self.metadata.extend_from_slice(b"</");
+            self.metadata.extend_from_slice(root_name.as_bytes());
+            self.metadata.push(b'>');
+            self.state = ReaderState::PendingEntry;
+            return Ok(());
+          }
+        }
+        Some(NodeKind::End) => {
+          self.state = ReaderState::Done;
+          return Ok(());
+        }
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    }
+  }
+}
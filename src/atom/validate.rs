@@ -0,0 +1,223 @@
+use crate::common::LinkRelation;
+
+use super::{is_text_media_type, is_xml_media_type, ContentValue, Entry, Feed, Link};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+  /// Violates a MUST requirement, the document is not a valid Atom document.
+  Error,
+  /// Violates a SHOULD requirement.
+  Warning,
+}
+
+/// RFC4287 requirements checked by [Feed::validate] and [Entry::validate].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+  /// Entry has no author, and neither its source nor the feed has one.
+  MissingAuthor,
+  /// Entry has no content and no alternate link.
+  MissingAlternateLink,
+  /// Alternate links with the same `type` and `hreflang`.
+  DuplicateAlternateLink,
+  /// Entry content is linked with `src` or Base64 encoded, but the entry has no summary.
+  MissingSummary,
+  /// Linked content has `text`, `html` or `xhtml` as its type.
+  InvalidContentType,
+  /// Linked content has no type.
+  MissingContentType,
+  /// Feed has no `self` link.
+  MissingSelfLink,
+  /// Entries with the same id and the same `updated` date.
+  DuplicateEntry,
+  /// Feed or entry id is empty.
+  EmptyId,
+}
+
+impl Rule {
+  /// RFC4287 section that defines the requirement.
+  pub const fn section(&self) -> &'static str {
+    match self {
+      Rule::MissingAuthor => "4.1.2",
+      Rule::MissingAlternateLink => "4.1.2",
+      Rule::DuplicateAlternateLink => "4.1.1",
+      Rule::MissingSummary => "4.1.2",
+      Rule::InvalidContentType => "4.1.3.1",
+      Rule::MissingContentType => "4.1.3.1",
+      Rule::MissingSelfLink => "4.1.1",
+      Rule::DuplicateEntry => "4.1.1",
+      Rule::EmptyId => "4.2.6",
+    }
+  }
+
+  pub const fn severity(&self) -> Severity {
+    match self {
+      Rule::MissingContentType | Rule::MissingSelfLink | Rule::DuplicateEntry => Severity::Warning,
+      _ => Severity::Error,
+    }
+  }
+}
+
+/// Requirement violated by a feed or an entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Violation {
+  pub rule: Rule,
+  /// Index of the violating entry in [Feed::entries], `None` for the feed itself and for
+  /// standalone entries.
+  pub entry: Option<usize>,
+}
+
+impl Violation {
+  #[inline]
+  pub const fn new(rule: Rule, entry: Option<usize>) -> Self {
+    Self { rule, entry }
+  }
+
+  #[inline]
+  pub const fn severity(&self) -> Severity {
+    self.rule.severity()
+  }
+
+  #[inline]
+  pub const fn section(&self) -> &'static str {
+    self.rule.section()
+  }
+}
+
+impl Feed<'_> {
+  /// Checks the RFC4287 requirements which the types cannot enforce. Returns an empty list for
+  /// conforming feeds.
+  ///
+  /// ```
+  /// use syndication_format::{
+  ///   atom::{Entry, Feed, Rule, Severity},
+  ///   common::{TimeStamp, XmlText},
+  /// };
+  ///
+  /// let mut feed = Feed::new("urn:feed", XmlText::PlainText("Feed"), TimeStamp::default());
+  /// feed
+  ///   .entries
+  ///   .push(Entry::new("urn:entry", XmlText::PlainText("Entry"), TimeStamp::default()));
+  ///
+  /// let violations = feed.validate();
+  /// let error = violations.iter().find(|v| v.rule == Rule::MissingAuthor).unwrap();
+  ///
+  /// assert_eq!(Some(0), error.entry);
+  /// assert_eq!(Severity::Error, error.severity());
+  /// assert_eq!("4.1.2", error.section());
+  /// ```
+  pub fn validate(&self) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if self.id.value.trim().is_empty() {
+      violations.push(Violation::new(Rule::EmptyId, None));
+    }
+
+    if !self
+      .links
+      .iter()
+      .any(|link| matches!(link.rel, Some(LinkRelation::SelfRel)))
+    {
+      violations.push(Violation::new(Rule::MissingSelfLink, None));
+    }
+
+    if has_duplicate_alternate(&self.links) {
+      violations.push(Violation::new(Rule::DuplicateAlternateLink, None));
+    }
+
+    for (index, entry) in self.entries.iter().enumerate() {
+      let is_duplicate = self.entries[..index].iter().any(|other| {
+        other.id.value == entry.id.value && other.updated.value == entry.updated.value
+      });
+
+      if is_duplicate {
+        violations.push(Violation::new(Rule::DuplicateEntry, Some(index)));
+      }
+
+      validate_entry(
+        entry,
+        Some(index),
+        !self.authors.is_empty(),
+        &mut violations,
+      );
+    }
+
+    violations
+  }
+}
+
+impl Entry<'_> {
+  /// Checks the entry as an Atom Entry Document, where the authors can't be inherited from a
+  /// feed. Use [Feed::validate] for the entries of a feed.
+  pub fn validate(&self) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    validate_entry(self, None, false, &mut violations);
+    violations
+  }
+}
+
+fn validate_entry(
+  entry: &Entry<'_>,
+  index: Option<usize>,
+  has_feed_author: bool,
+  violations: &mut Vec<Violation>,
+) {
+  let mut report = |is_violated: bool, rule: Rule| {
+    if is_violated {
+      violations.push(Violation::new(rule, index));
+    }
+  };
+
+  let has_source_author = entry
+    .source
+    .as_ref()
+    .is_some_and(|source| !source.authors.is_empty());
+
+  let has_alternate = entry
+    .links
+    .iter()
+    .any(|link| matches!(link.rel, None | Some(LinkRelation::Alternate)));
+
+  report(entry.id.value.trim().is_empty(), Rule::EmptyId);
+  report(
+    entry.authors.is_empty() && !has_source_author && !has_feed_author,
+    Rule::MissingAuthor,
+  );
+  report(
+    entry.content.is_none() && !has_alternate,
+    Rule::MissingAlternateLink,
+  );
+  report(
+    has_duplicate_alternate(&entry.links),
+    Rule::DuplicateAlternateLink,
+  );
+
+  match entry.content.as_ref().map(|content| &content.value) {
+    Some(ContentValue::LinkContent { media_type, .. }) => {
+      report(entry.summary.is_none(), Rule::MissingSummary);
+      report(
+        matches!(media_type, Some("text" | "html" | "xhtml")),
+        Rule::InvalidContentType,
+      );
+      report(media_type.is_none(), Rule::MissingContentType);
+    }
+    // Base64 encoded content can't be displayed inline, summary is required.
+    Some(ContentValue::InlinedMedia { media_type, .. }) => report(
+      entry.summary.is_none() && !is_xml_media_type(media_type) && !is_text_media_type(media_type),
+      Rule::MissingSummary,
+    ),
+    _ => (),
+  }
+}
+
+fn has_duplicate_alternate(links: &[Link<'_>]) -> bool {
+  let alternates: Vec<&Link<'_>> = links
+    .iter()
+    .filter(|link| matches!(link.rel, None | Some(LinkRelation::Alternate)))
+    .collect();
+
+  alternates.iter().enumerate().any(|(index, link)| {
+    alternates[..index]
+      .iter()
+      .any(|other| other.link_type == link.link_type && other.hreflang == link.hreflang)
+  })
+}
@@ -0,0 +1,135 @@
+use crate::{error::XmlSerializeError, serializer::ElementSerializer};
+
+pub const XHTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+/// Writes an XHTML text construct value. RFC4287 section 3.1.1.3 requires the markup to be
+/// wrapped in a single XHTML `div`, values without one are wrapped in a
+/// `<div xmlns="http://www.w3.org/1999/xhtml">` before writing.
+pub(crate) fn serialize_xhtml<E>(element: E, value: &str) -> Result<(), XmlSerializeError>
+where
+  E: ElementSerializer,
+{
+  if is_xhtml_div(value, |prefix| element.namespace_uri(prefix)) {
+    return element.serialize_str(value);
+  }
+
+  element.serialize_str(&format!(
+    "<div xmlns=\"{}\">{}</div>",
+    XHTML_NAMESPACE, value
+  ))
+}
+
+/// Checks whether the markup is a single `div` element in the XHTML namespace. Prefix of the
+/// `div` is resolved by the declarations on the `div` itself, then by `resolve_prefix`.
+fn is_xhtml_div<'a, F>(value: &str, resolve_prefix: F) -> bool
+where
+  F: Fn(&str) -> Option<&'a str>,
+{
+  let value = value.trim();
+
+  let Some(start_tag) = value.strip_prefix('<') else {
+    return false;
+  };
+
+  let name_length = start_tag
+    .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+    .unwrap_or(start_tag.len());
+  let name = &start_tag[..name_length];
+
+  let (prefix, local_name) = name.split_once(':').unwrap_or(("", name));
+
+  if local_name != "div" {
+    return false;
+  }
+
+  let Some(tag_length) = tag_length(start_tag) else {
+    return false;
+  };
+
+  let attributes = &start_tag[name_length..tag_length];
+  let declaration = match prefix {
+    "" => String::from("xmlns"),
+    prefix => format!("xmlns:{}", prefix),
+  };
+
+  let namespace = attribute_value(attributes, &declaration).or_else(|| resolve_prefix(prefix));
+
+  namespace == Some(XHTML_NAMESPACE) && element_length(value) == Some(value.len())
+}
+
+/// Length of the tag up to the closing `>`, quoted attribute values may contain `>`.
+fn tag_length(tag: &str) -> Option<usize> {
+  let mut quote = None;
+
+  for (idx, c) in tag.char_indices() {
+    match (quote, c) {
+      (Some(quote_char), c) if quote_char == c => quote = None,
+      (Some(_), _) => (),
+      (None, '"' | '\'') => quote = Some(c),
+      (None, '>') => return Some(idx),
+      _ => (),
+    }
+  }
+
+  None
+}
+
+fn attribute_value<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+  let mut remaining = attributes.trim_start();
+
+  while let Some(equals) = remaining.find('=') {
+    let attribute_name = remaining[..equals].trim();
+    let value = remaining[equals + 1..].trim_start();
+    let quote = value.chars().next()?;
+
+    if !matches!(quote, '"' | '\'') {
+      return None;
+    }
+
+    let value_length = value[1..].find(quote)?;
+
+    if attribute_name == name {
+      return Some(&value[1..value_length + 1]);
+    }
+
+    remaining = value[value_length + 2..].trim_start();
+  }
+
+  None
+}
+
+/// Length of the first element in the markup, including its end tag.
+fn element_length(markup: &str) -> Option<usize> {
+  let mut depth = 0usize;
+  let mut position = 0;
+
+  while let Some(offset) = markup[position..].find('<') {
+    let tag = &markup[position + offset..];
+
+    let length = if tag.starts_with("<!--") {
+      tag.find("-->")? + 3
+    } else if tag.starts_with("<![CDATA[") {
+      tag.find("]]>")? + 3
+    } else if tag.starts_with("<?") {
+      tag.find("?>")? + 2
+    } else {
+      let length = tag_length(tag)? + 1;
+
+      if tag.starts_with("</") {
+        depth = depth.checked_sub(1)?;
+      } else if !tag[..length].ends_with("/>") {
+        depth += 1;
+      }
+
+      length
+    };
+
+    position += offset + length;
+
+    if depth == 0 {
+      return Some(position);
+    }
+  }
+
+  None
+}
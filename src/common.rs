@@ -1,17 +1,24 @@
-use core::fmt::Display;
-
 mod attributes;
+mod link_relation;
 mod timestamp;
 
 pub use attributes::*;
+pub use link_relation::*;
 pub use timestamp::*;
 
+/// Serialized with serde as `{"type": "text", "value": "..."}`, where `type` is one of `text`,
+/// `html`, `xhtml`, `text_unchecked` or `html_unchecked`.
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize),
+  serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
 pub enum XmlText<'a> {
   /// Raw HTML type. Serializer will set element's type attribute to "html" and escapes any special characters.
   ///
   /// Input:
   /// ```
-  /// use atom_syndication_format::common::XmlText;
+  /// use syndication_format::common::XmlText;
   /// let example_input = XmlText::Html("<div> Example </div>");
   /// ```
   ///
@@ -21,17 +28,18 @@ pub enum XmlText<'a> {
   /// ```
   Html(&'a str),
 
-  /// XHTML text type. Serializer will set elemet's type attribute to "xhtml" and embeds data as is.
+  /// XHTML text type. Serializer will set elemet's type attribute to "xhtml" and embeds data as is,
+  /// wrapped in an XHTML `div` unless the data already is a single XHTML `div` element.
   ///
   /// Input:
   /// ```
-  /// use atom_syndication_format::common::XmlText;
-  /// let example_input = XmlText::Xhtml("<xhtml:div> Example </xhtml:div>");
+  /// use syndication_format::common::XmlText;
+  /// let example_input = XmlText::Xhtml("<b>Example</b>");
   /// ```
   ///
   /// Serialized:
   /// ```xml
-  /// <example-input type="xhtml"><xhtml:div> Example </xhtml:div></example-input>;
+  /// <example-input type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml"><b>Example</b></div></example-input>;
   /// ```
   Xhtml(&'a str),
 
@@ -39,7 +47,7 @@ pub enum XmlText<'a> {
   ///
   /// Input:
   /// ```
-  /// use atom_syndication_format::common::XmlText;
+  /// use syndication_format::common::XmlText;
   /// let example_input = XmlText::PlainText("COOL&CREATE");
   /// ```
   ///
@@ -47,13 +55,14 @@ pub enum XmlText<'a> {
   /// ```xml
   /// <example-input type="html">COOL&amp;CREATE</example-input>;
   /// ```
+  #[cfg_attr(feature = "serde", serde(rename = "text"))]
   PlainText(&'a str),
 
   /// Html text without escape check. Use this type when the data is already escaped.
   ///
   /// Input:
   /// ```
-  /// use atom_syndication_format::common::XmlText;
+  /// use syndication_format::common::XmlText;
   /// let example_input = XmlText::HtmlUnchecked("&lt;b&gt;COOL&amp;CREATE&lt;/b&gt;");
   /// ```
   ///
@@ -67,7 +76,7 @@ pub enum XmlText<'a> {
   ///
   /// Input:
   /// ```
-  /// use atom_syndication_format::common::XmlText;
+  /// use syndication_format::common::XmlText;
   /// let example_input = XmlText::PlainTextUnchecked("COOL&amp;CREATE");
   /// ```
   ///
@@ -75,36 +84,10 @@ pub enum XmlText<'a> {
   /// ```xml
   /// <example-input type="text">COOL&amp;CREATE</example-input>;
   /// ```
+  #[cfg_attr(feature = "serde", serde(rename = "text_unchecked"))]
   PlainTextUnchecked(&'a str),
 }
 
-#[derive(Copy, Clone, Debug)]
-pub enum LinkRelation {
-  Alternate,
-  Related,
-  SelfRel,
-  Enclosure,
-  Via,
-}
-
-impl Display for LinkRelation {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    f.write_str(self.as_str())
-  }
-}
-
-impl LinkRelation {
-  pub fn as_str(&self) -> &'static str {
-    match self {
-      LinkRelation::Alternate => "alternate",
-      LinkRelation::Related => "related",
-      LinkRelation::SelfRel => "self",
-      LinkRelation::Enclosure => "enclosure",
-      LinkRelation::Via => "via",
-    }
-  }
-}
-
 #[macro_export]
 macro_rules! html {
   ($input:expr) => {
@@ -23,18 +23,12 @@ pub struct Attribute {
 
 // Attributes impls
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct AttributeMap {
   // implementing Map structure with Vec since expected item count is mostly less than 5.
   inner: Vec<Attribute>,
 }
 
-impl Default for AttributeMap {
-  fn default() -> Self {
-    Self { inner: Vec::new() }
-  }
-}
-
 pub struct AttributeMapIter<I, T>
 where
   I: Sized + Iterator<Item = T>,
@@ -132,6 +126,59 @@ impl<'a> IntoIterator for &'a AttributeMap {
   }
 }
 
+/// Serialized as a map of attribute names to values.
+#[cfg(feature = "serde")]
+impl serde::Serialize for AttributeMap {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.collect_map(
+      self
+        .inner
+        .iter()
+        .map(|attribute| (attribute.name.as_str(), attribute.value.as_str())),
+    )
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AttributeMap {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    struct AttributeMapVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for AttributeMapVisitor {
+      type Value = AttributeMap;
+
+      fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a map of XML attribute names to values")
+      }
+
+      fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+      where
+        A: serde::de::MapAccess<'de>,
+      {
+        let mut attributes = AttributeMap::with_capacity(map.size_hint().unwrap_or(0));
+
+        while let Some((name, value)) = map.next_entry::<String, String>()? {
+          let name = AttributeName::new(&name).map_err(|_| {
+            serde::de::Error::invalid_value(serde::de::Unexpected::Str(&name), &"an XML name")
+          })?;
+
+          attributes.set(name, AttributeValue::from(value));
+        }
+
+        Ok(attributes)
+      }
+    }
+
+    deserializer.deserialize_map(AttributeMapVisitor)
+  }
+}
+
 // AttributeName impls
 
 impl FromStr for AttributeName {
@@ -0,0 +1,272 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::error::InvalidLinkRelation;
+
+/// IANA registry prefix of the registered relation names, RFC4287 section 4.2.7.2.
+const IANA_RELATION_PREFIX: &str = "http://www.iana.org/assignments/relation/";
+
+macro_rules! link_relations {
+  ($($variant:ident => $name:literal,)*) => {
+    /// Link relation types of the IANA link relations registry, and the extension relation
+    /// types as absolute IRIs.
+    ///
+    /// ```
+    /// use syndication_format::common::LinkRelation;
+    ///
+    /// assert_eq!(LinkRelation::EditMedia, "edit-media".parse().unwrap());
+    /// assert_eq!(
+    ///   LinkRelation::Extension("https://example.org/rel/similar".into()),
+    ///   "https://example.org/rel/similar".parse().unwrap()
+    /// );
+    /// assert!("not a relation".parse::<LinkRelation>().is_err());
+    /// ```
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum LinkRelation {
+      $(
+        #[doc = concat!("`", $name, "`")]
+        $variant,
+      )*
+      /// Extension relation type, an absolute IRI.
+      Extension(Box<str>),
+    }
+
+    impl LinkRelation {
+      pub fn as_str(&self) -> &str {
+        match self {
+          $(LinkRelation::$variant => $name,)*
+          LinkRelation::Extension(iri) => iri,
+        }
+      }
+
+      /// Looks up a registered relation name, names are case-insensitive.
+      fn registered(name: &str) -> Option<Self> {
+        $(
+          if name.eq_ignore_ascii_case($name) {
+            return Some(LinkRelation::$variant);
+          }
+        )*
+
+        None
+      }
+    }
+  };
+}
+
+link_relations! {
+  About => "about",
+  Acl => "acl",
+  Alternate => "alternate",
+  AmpHtml => "amphtml",
+  ApiCatalog => "api-catalog",
+  Appendix => "appendix",
+  AppleTouchIcon => "apple-touch-icon",
+  AppleTouchStartupImage => "apple-touch-startup-image",
+  Archives => "archives",
+  Author => "author",
+  BlockedBy => "blocked-by",
+  Bookmark => "bookmark",
+  C2paManifest => "c2pa-manifest",
+  Canonical => "canonical",
+  Chapter => "chapter",
+  CiteAs => "cite-as",
+  Collection => "collection",
+  CompressionDictionary => "compression-dictionary",
+  Contents => "contents",
+  ConvertedFrom => "convertedfrom",
+  Copyright => "copyright",
+  CreateForm => "create-form",
+  Current => "current",
+  Deprecation => "deprecation",
+  DescribedBy => "describedby",
+  Describes => "describes",
+  Disclosure => "disclosure",
+  DnsPrefetch => "dns-prefetch",
+  Duplicate => "duplicate",
+  Edit => "edit",
+  EditForm => "edit-form",
+  EditMedia => "edit-media",
+  Enclosure => "enclosure",
+  External => "external",
+  First => "first",
+  Geofeed => "geofeed",
+  Glossary => "glossary",
+  Help => "help",
+  Hosts => "hosts",
+  Hub => "hub",
+  IceServer => "ice-server",
+  Icon => "icon",
+  Index => "index",
+  IntervalAfter => "intervalafter",
+  IntervalBefore => "intervalbefore",
+  IntervalContains => "intervalcontains",
+  IntervalDisjoint => "intervaldisjoint",
+  IntervalDuring => "intervalduring",
+  IntervalEquals => "intervalequals",
+  IntervalFinishedBy => "intervalfinishedby",
+  IntervalFinishes => "intervalfinishes",
+  IntervalIn => "intervalin",
+  IntervalMeets => "intervalmeets",
+  IntervalMetBy => "intervalmetby",
+  IntervalOverlappedBy => "intervaloverlappedby",
+  IntervalOverlaps => "intervaloverlaps",
+  IntervalStartedBy => "intervalstartedby",
+  IntervalStarts => "intervalstarts",
+  Item => "item",
+  Last => "last",
+  LatestVersion => "latest-version",
+  License => "license",
+  Linkset => "linkset",
+  Lrdd => "lrdd",
+  Manifest => "manifest",
+  MaskIcon => "mask-icon",
+  Me => "me",
+  MediaFeed => "media-feed",
+  Memento => "memento",
+  Micropub => "micropub",
+  ModulePreload => "modulepreload",
+  Monitor => "monitor",
+  MonitorGroup => "monitor-group",
+  Next => "next",
+  NextArchive => "next-archive",
+  NoFollow => "nofollow",
+  NoOpener => "noopener",
+  NoReferrer => "noreferrer",
+  Opener => "opener",
+  OpenId2LocalId => "openid2.local_id",
+  OpenId2Provider => "openid2.provider",
+  Original => "original",
+  P3pv1 => "p3pv1",
+  Payment => "payment",
+  Pingback => "pingback",
+  Preconnect => "preconnect",
+  PredecessorVersion => "predecessor-version",
+  Prefetch => "prefetch",
+  Preload => "preload",
+  Prerender => "prerender",
+  Prev => "prev",
+  PrevArchive => "prev-archive",
+  Preview => "preview",
+  Previous => "previous",
+  PrivacyPolicy => "privacy-policy",
+  Profile => "profile",
+  Publication => "publication",
+  Related => "related",
+  Replies => "replies",
+  Restconf => "restconf",
+  RuleInput => "ruleinput",
+  Search => "search",
+  Section => "section",
+  SelfRel => "self",
+  Service => "service",
+  ServiceDesc => "service-desc",
+  ServiceDoc => "service-doc",
+  ServiceMeta => "service-meta",
+  SipTrunkingCapability => "sip-trunking-capability",
+  Sponsored => "sponsored",
+  Start => "start",
+  Status => "status",
+  Stylesheet => "stylesheet",
+  Subsection => "subsection",
+  SuccessorVersion => "successor-version",
+  Sunset => "sunset",
+  Tag => "tag",
+  TermsOfService => "terms-of-service",
+  TimeGate => "timegate",
+  TimeMap => "timemap",
+  Type => "type",
+  Ugc => "ugc",
+  Up => "up",
+  VersionHistory => "version-history",
+  Via => "via",
+  Webmention => "webmention",
+  WorkingCopy => "working-copy",
+  WorkingCopyOf => "working-copy-of",
+}
+
+impl LinkRelation {
+  /// Parses a registered relation name or an absolute IRI. Registered names written as IANA
+  /// registry IRIs are mapped to the registered variants.
+  pub fn new(value: &str) -> Result<Self, InvalidLinkRelation> {
+    let value = value.trim();
+
+    if let Some(relation) = Self::registered(value) {
+      return Ok(relation);
+    }
+
+    if let Some(relation) = value
+      .strip_prefix(IANA_RELATION_PREFIX)
+      .and_then(Self::registered)
+    {
+      return Ok(relation);
+    }
+
+    if is_absolute_iri(value) {
+      Ok(LinkRelation::Extension(Box::from(value)))
+    } else {
+      Err(InvalidLinkRelation)
+    }
+  }
+}
+
+impl FromStr for LinkRelation {
+  type Err = InvalidLinkRelation;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::new(s)
+  }
+}
+
+impl Display for LinkRelation {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Serialized as the relation name or the extension IRI.
+#[cfg(feature = "serde")]
+impl serde::Serialize for LinkRelation {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.serialize_str(self.as_str())
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LinkRelation {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let value = String::deserialize(deserializer)?;
+
+    Self::new(&value).map_err(|_| {
+      serde::de::Error::invalid_value(
+        serde::de::Unexpected::Str(&value),
+        &"a registered link relation or an absolute IRI",
+      )
+    })
+  }
+}
+
+/// Checks for an RFC3987 scheme followed by a non-empty part without whitespace or the
+/// characters IRIs don't allow.
+fn is_absolute_iri(value: &str) -> bool {
+  let Some((scheme, rest)) = value.split_once(':') else {
+    return false;
+  };
+
+  let mut scheme_chars = scheme.chars();
+  let is_valid_scheme = scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+    && scheme_chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+
+  is_valid_scheme
+    && !rest.is_empty()
+    && !rest.chars().any(|c| {
+      c.is_whitespace()
+        || c.is_control()
+        || matches!(c, '<' | '>' | '"' | '{' | '}' | '|' | '\\' | '^' | '`')
+    })
+}
@@ -6,11 +6,15 @@ use core::{
 
 use crate::error::TimeStampFormatErrors;
 
-/// Division helper that returns both quotient and remainder.
+mod lenient;
+
+pub use lenient::*;
+
+/// Floored division helper that returns both quotient and non-negative remainder.
 macro_rules! divide {
   ($dividend:expr, $divisor:expr) => {{
-    let quotient = $dividend / $divisor;
-    let remainder = $dividend % $divisor;
+    let quotient = ($dividend).div_euclid($divisor);
+    let remainder = ($dividend).rem_euclid($divisor);
 
     (quotient, remainder)
   }};
@@ -18,15 +22,28 @@ macro_rules! divide {
 
 macro_rules! is_leap_year {
   ($year:expr) => {
-    !(($year & 0x03) > 0 && ($year % 100 != 0 && $year % 400 != 0))
+    ($year % 4 == 0 && $year % 100 != 0) || $year % 400 == 0
   };
 }
 
-const HOUR_IN_SECS: u64 = 60 * 60;
-const DAY_IN_SECS: u64 = HOUR_IN_SECS * 24;
-const NON_LEAP_YEAR: u64 = 365 * DAY_IN_SECS;
-const LEAP_YEAR_BLOCK: u64 = NON_LEAP_YEAR * 4 + DAY_IN_SECS;
+const HOUR_IN_SECS: i64 = 60 * 60;
+const DAY_IN_SECS: i64 = HOUR_IN_SECS * 24;
+/// Days in a 400 years Gregorian calendar cycle.
+const DAYS_IN_ERA: i64 = 146_097;
+/// Days from 0000-03-01 to the unix epoch, calendar eras start in March so the leap day is the
+/// last day of an era year.
+const EPOCH_DAY_OFFSET: i64 = 719_468;
+const MAX_YEAR: u64 = 9999;
+/// Unix seconds of `0000-01-01T00:00:00Z`, the earliest instant that can be formatted.
+const MIN_UNIX_SECONDS: i64 = -62_167_219_200;
+/// Unix seconds of `9999-12-31T23:59:59Z`, the latest instant that can be formatted.
+const MAX_UNIX_SECONDS: i64 = 253_402_300_799;
+const NANOS_IN_SEC: u32 = 1_000_000_000;
 const DAYS_IN_MONTHS: &[u64] = &[31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+const MONTH_NAMES: &[&str] = &[
+  "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const WEEKDAY_NAMES: &[&str] = &["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
 const TZ_UNKNOWN: TimeZoneOffset = TimeZoneOffset {
   hours: 0,
   minutes: 0,
@@ -104,231 +121,679 @@ impl TimeZoneOffset {
 }
 
 /// Basic internet timestamp implementation based on [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339).
+///
+/// Stores signed seconds since the unix epoch, parsing supports the years `0000` to `9999` of the
+/// proleptic Gregorian calendar. Timestamps are compared by the instant they represent, the time
+/// zone offset is only used for formatting.
 #[derive(Copy, Clone)]
 pub struct TimeStamp {
-  unix_time: time::Duration,
+  seconds: i64,
+  nanos: u32,
   time_zone: TimeZoneOffset,
 }
 
 // Helper struct. It's inlined and struct size doesn't matter for now.
 #[derive(Debug)]
 struct DateTime {
-  year: u64,
-  day: u64,
-  month: u64,
-  hour: u64,
-  seconds: u64,
-  minute: u64,
+  year: i64,
+  day: i64,
+  month: i64,
+  hour: i64,
+  seconds: i64,
+  minute: i64,
+  weekday: i64,
 }
 
 impl Default for TimeStamp {
   fn default() -> Self {
     Self {
-      unix_time: time::Duration::new(0, 0),
+      seconds: 0,
+      nanos: 0,
       time_zone: TZ_Z,
     }
   }
 }
 
+impl PartialEq for TimeStamp {
+  fn eq(&self, other: &Self) -> bool {
+    self.seconds == other.seconds && self.nanos == other.nanos
+  }
+}
+
+impl Eq for TimeStamp {}
+
+impl PartialOrd for TimeStamp {
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for TimeStamp {
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    (self.seconds, self.nanos).cmp(&(other.seconds, other.nanos))
+  }
+}
+
+/// Formats as RFC3339 date time with the shortest seconds fraction that keeps the value, whole
+/// seconds are written without a fraction. See [TimeStamp::display] for the fixed precisions.
 impl Display for TimeStamp {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let date_time = self.as_datetime();
+    self.write_rfc3339(f, self.exact_precision())
+  }
+}
 
-    f.write_fmt(format_args!(
-      "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}{}",
-      date_time.year,
-      date_time.month,
-      date_time.day,
-      date_time.hour,
-      date_time.minute,
-      date_time.seconds,
-      self.time_zone
-    ))
+/// Fraction of seconds written by [TimeStamp::display]. Digits beyond the precision are
+/// truncated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SecondsPrecision {
+  /// Whole seconds, `2024-09-18T23:28:25Z`.
+  #[default]
+  Seconds,
+  /// `2024-09-18T23:28:25.542Z`
+  Millis,
+  /// `2024-09-18T23:28:25.542000Z`
+  Micros,
+  /// `2024-09-18T23:28:25.542000000Z`
+  Nanos,
+}
+
+/// RFC3339 formatter returned by [TimeStamp::display].
+pub struct TimeStampDisplay<'a> {
+  timestamp: &'a TimeStamp,
+  precision: SecondsPrecision,
+}
+
+impl Display for TimeStampDisplay<'_> {
+  #[inline]
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    self.timestamp.write_rfc3339(f, self.precision)
   }
 }
 
 impl TimeStamp {
+  /// Creates timestamp from the elapsed time since the unix epoch, durations beyond
+  /// `9999-12-31T23:59:59.999999999Z` are clamped to that instant.
   pub fn new(unix_epoch: time::Duration) -> Self {
-    Self {
-      unix_time: unix_epoch,
-      time_zone: TZ_Z,
+    match i64::try_from(unix_epoch.as_secs()) {
+      Ok(seconds) if seconds <= MAX_UNIX_SECONDS => Self {
+        seconds,
+        nanos: unix_epoch.subsec_nanos(),
+        time_zone: TZ_Z,
+      },
+      _ => Self {
+        seconds: MAX_UNIX_SECONDS,
+        nanos: NANOS_IN_SEC - 1,
+        time_zone: TZ_Z,
+      },
     }
   }
 
+  /// Creates timestamp from signed seconds since the unix epoch, negative for the earlier dates.
+  /// Nanoseconds beyond a second are carried over to the seconds. Returns `None` for the instants
+  /// outside of years 0000 - 9999, which can't be formatted as RFC3339 date time.
+  ///
+  /// ```
+  /// use syndication_format::common::TimeStamp;
+  ///
+  /// let timestamp = TimeStamp::from_unix_time(-3600, 0).unwrap();
+  /// assert_eq!("1969-12-31T23:00:00Z", timestamp.to_string());
+  /// assert!(timestamp == "1969-12-31T22:00:00-01:00".parse().unwrap());
+  ///
+  /// assert!(TimeStamp::from_unix_time(-62_200_000_000, 0).is_none());
+  /// ```
+  pub fn from_unix_time(seconds: i64, nanos: u32) -> Option<Self> {
+    let seconds = seconds.checked_add((nanos / NANOS_IN_SEC) as i64)?;
+
+    if !(MIN_UNIX_SECONDS..=MAX_UNIX_SECONDS).contains(&seconds) {
+      return None;
+    }
+
+    Some(Self {
+      seconds,
+      nanos: nanos % NANOS_IN_SEC,
+      time_zone: TZ_Z,
+    })
+  }
+
   pub fn set_tz(&mut self, time_zone: TimeZoneOffset) {
     self.time_zone = time_zone;
   }
 
+  /// Elapsed time since the unix epoch, regardless of the time zone offset. Dates before the epoch
+  /// are saturated to zero, see [TimeStamp::checked_unix_time] and [TimeStamp::unix_seconds].
+  pub fn unix_time(&self) -> Duration {
+    self.checked_unix_time().unwrap_or(Duration::ZERO)
+  }
+
+  /// Elapsed time since the unix epoch, regardless of the time zone offset. Returns `None` for the
+  /// dates before the epoch.
+  pub fn checked_unix_time(&self) -> Option<Duration> {
+    u64::try_from(self.seconds)
+      .ok()
+      .map(|seconds| Duration::new(seconds, self.nanos))
+  }
+
+  /// Signed whole seconds since the unix epoch.
   #[inline]
-  // Converts unix timestamp to 'biblically accurate' date time struct.
-  fn as_datetime(&self) -> DateTime {
-    let mut dt = DateTime {
-      day: 1,
-      year: 1970,
-      hour: 0,
-      month: 1,
-      seconds: 0,
-      minute: 0,
-    };
+  pub fn unix_seconds(&self) -> i64 {
+    self.seconds
+  }
 
-    let tz_offset: u64 =
-      ((self.time_zone.hours as u64) * 3600) + ((self.time_zone.minutes as u64) * 60);
-    let duration_secs: u64 = match self.time_zone.symbol {
-      OffsetSymbol::Minus => self.unix_time.as_secs().saturating_sub(tz_offset),
-      OffsetSymbol::Plus => self.unix_time.as_secs().saturating_add(tz_offset),
-    };
+  /// Fraction of the second in nanoseconds, always forward in time from [TimeStamp::unix_seconds].
+  #[inline]
+  pub fn subsec_nanos(&self) -> u32 {
+    self.nanos
+  }
 
-    let (year_blocks, remainder) = divide!(duration_secs, LEAP_YEAR_BLOCK);
-    let (trailing_years, mut remainder) = divide!(remainder, NON_LEAP_YEAR);
+  /// Formats as RFC3339 date time with the given seconds fraction precision.
+  ///
+  /// ```
+  /// use core::time::Duration;
+  /// use syndication_format::common::{SecondsPrecision, TimeStamp};
+  ///
+  /// let timestamp = TimeStamp::new(Duration::from_nanos(1726702105_542_000_100));
+  ///
+  /// assert_eq!("2024-09-18T23:28:25.542000100Z", timestamp.to_string());
+  /// assert_eq!(
+  ///   "2024-09-18T23:28:25Z",
+  ///   timestamp.display(SecondsPrecision::Seconds).to_string()
+  /// );
+  /// assert_eq!(
+  ///   "2024-09-18T23:28:25.542Z",
+  ///   timestamp.display(SecondsPrecision::Millis).to_string()
+  /// );
+  /// assert_eq!(
+  ///   "2024-09-18T23:28:25.542000100Z",
+  ///   timestamp.display(SecondsPrecision::Nanos).to_string()
+  /// );
+  /// ```
+  #[inline]
+  pub fn display(&self, precision: SecondsPrecision) -> TimeStampDisplay<'_> {
+    TimeStampDisplay {
+      timestamp: self,
+      precision,
+    }
+  }
 
-    dt.year = 1970 + (year_blocks * 4) + trailing_years;
-    let is_leap_year = is_leap_year!(dt.year);
+  /// Shortest precision that writes the seconds fraction without truncation.
+  fn exact_precision(&self) -> SecondsPrecision {
+    match self.nanos {
+      0 => SecondsPrecision::Seconds,
+      nanos if nanos % 1_000_000 == 0 => SecondsPrecision::Millis,
+      nanos if nanos % 1_000 == 0 => SecondsPrecision::Micros,
+      _ => SecondsPrecision::Nanos,
+    }
+  }
 
-    for (idx, day_count) in DAYS_IN_MONTHS.iter().enumerate() {
-      let dc = (idx == 1 && is_leap_year) as u64 + day_count;
+  fn write_rfc3339(
+    &self,
+    f: &mut core::fmt::Formatter<'_>,
+    precision: SecondsPrecision,
+  ) -> core::fmt::Result {
+    let date_time = self.as_datetime();
 
-      match remainder.checked_sub(dc * DAY_IN_SECS) {
-        Some(remaining) => {
-          dt.month += 1;
-          remainder = remaining;
-        }
-        None => break,
+    f.write_fmt(format_args!(
+      "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}",
+      date_time.year,
+      date_time.month,
+      date_time.day,
+      date_time.hour,
+      date_time.minute,
+      date_time.seconds,
+    ))?;
+
+    let nanos = self.nanos;
+
+    match precision {
+      SecondsPrecision::Seconds => (),
+      SecondsPrecision::Millis => f.write_fmt(format_args!(".{:0>3}", nanos / 1_000_000))?,
+      SecondsPrecision::Micros => f.write_fmt(format_args!(".{:0>6}", nanos / 1_000))?,
+      SecondsPrecision::Nanos => f.write_fmt(format_args!(".{:0>9}", nanos))?,
+    }
+
+    Display::fmt(&self.time_zone, f)
+  }
+
+  /// Creates timestamp from calendar date `[year, month, day]`, wall clock time
+  /// `[hours, minutes, seconds]` and the time zone offset of that wall clock.
+  fn from_date_time(
+    [year, month, day]: [u64; 3],
+    [hours, minutes, seconds]: [u8; 3],
+    tz: TimeZoneOffset,
+  ) -> Result<Self, TimeStampFormatErrors> {
+    if year > MAX_YEAR {
+      return Err(TimeStampFormatErrors::UnsupportedYear);
+    }
+
+    if !(1..=12).contains(&month) {
+      return Err(TimeStampFormatErrors::InvalidDate);
+    }
+
+    let max_day = {
+      if month == 2 && is_leap_year!(year) {
+        DAYS_IN_MONTHS[1] + 1
+      } else {
+        DAYS_IN_MONTHS[(month - 1) as usize]
       }
+    };
+
+    if !(1..=31).contains(&day) {
+      return Err(TimeStampFormatErrors::InvalidDate);
     }
 
-    let (days, remainder) = divide!(remainder, DAY_IN_SECS);
-    dt.day = days + 1; // There are no 0th day. Increments the day by one for formatting.
+    if max_day < day {
+      return Err(TimeStampFormatErrors::NonExistingDate);
+    }
 
-    let (hours, remainder) = divide!(remainder, HOUR_IN_SECS);
-    dt.hour = hours;
+    if hours > 23 || minutes > 59 || seconds > 59 {
+      return Err(TimeStampFormatErrors::InvalidTime);
+    }
 
-    let (minutes, remainder) = divide!(remainder, 60);
-    dt.minute = minutes;
-    dt.seconds = remainder;
+    let local_seconds = days_from_civil(year as i64, month as i64, day as i64) * DAY_IN_SECS
+      + (hours as i64) * HOUR_IN_SECS
+      + (minutes as i64) * 60
+      + (seconds as i64);
 
-    dt
+    Ok(Self {
+      seconds: local_seconds - tz.as_seconds(),
+      nanos: 0,
+      time_zone: tz,
+    })
   }
-}
 
-// FIX:
-// - [ ] Missing number character check.
-// - [ ] seconds fraction support (TBH, no one use that feature)
-// - [ ] It does not support edge cases like 1969-12-31T23:00:00-01:00
-// - [ ] It only works on little-endian systems (Big-endian systems are also very rare)
-//
-impl FromStr for TimeStamp {
-  type Err = TimeStampFormatErrors;
+  /// Parses [RFC2822](https://datatracker.ietf.org/doc/html/rfc2822#section-3.3) date time, e.g.
+  /// `Wed, 18 Sep 2024 23:28:25 +0000`, including the obsolete
+  /// [RFC822](https://datatracker.ietf.org/doc/html/rfc822#section-5) syntax used by RSS and
+  /// HTTP dates.
+  ///
+  /// - Day name and seconds are optional, names are case-insensitive.
+  /// - Two-digit years are `19xx` from `50` and `20xx` below, three-digit years are added to
+  ///   `1900`.
+  /// - Zone can be a numeric offset, `GMT`, `UT`, `UTC` or one of the US zones `EST`, `EDT`,
+  ///   `CST`, `CDT`, `MST`, `MDT`, `PST` and `PDT`. Military zones other than `Z` were defined
+  ///   with the wrong sign, so they're read as `-0000`, an unknown local offset.
+  /// - Leap seconds are counted as the first second of the next minute.
+  ///
+  /// ```
+  /// use syndication_format::common::TimeStamp;
+  ///
+  /// let timestamp = TimeStamp::parse_rfc822("Wed, 18 Sep 24 19:28:25 EDT").unwrap();
+  ///
+  /// assert_eq!("2024-09-18T19:28:25-04:00", timestamp.to_string());
+  /// assert_eq!("Wed, 18 Sep 2024 19:28:25 -0400", timestamp.format_rfc822());
+  /// ```
+  pub fn parse_rfc822(value: &str) -> Result<Self, TimeStampFormatErrors> {
+    let value = value.trim();
+    let value = match value.split_once(',') {
+      Some((weekday, rest)) => {
+        if !WEEKDAY_NAMES
+          .iter()
+          .any(|name| name.eq_ignore_ascii_case(weekday.trim()))
+        {
+          return Err(TimeStampFormatErrors::InvalidDate);
+        }
 
-  fn from_str(s: &str) -> Result<Self, Self::Err> {
-    const TIME_STR_PATTERN_MASK: u64 = 0xFF_0000_FF_0000;
-    const TIME_STR_PATTERN: u64 = 0x3A_0000_3A_0000;
-    const TIME_STR_VALUE: u64 = 0x3030_00_3030_00_3030;
-    const DATE_STR_PATTERN_MASK: u128 = 0xFF_0000_FF_00000000;
-    const DATE_STR_VALUE: u128 = 0x3030_00_3030_00_30303030;
-    const DATE_STR_PATTERN: u128 = 0x2D_0000_2D_00000000;
+        rest
+      }
+      None => value,
+    };
 
-    let bytes = s.as_bytes();
+    let mut parts = value.split_ascii_whitespace();
+    let mut next_part = || parts.next().ok_or(TimeStampFormatErrors::InvalidFormat);
+
+    let day = parse_digits(next_part()?, 1..=2).ok_or(TimeStampFormatErrors::InvalidDate)?;
+    let month_name = next_part()?;
+    let month = MONTH_NAMES
+      .iter()
+      .position(|name| name.eq_ignore_ascii_case(month_name))
+      .ok_or(TimeStampFormatErrors::InvalidDate)? as u64
+      + 1;
+
+    let year_part = next_part()?;
+    let year = parse_digits(year_part, 2..=4).ok_or(TimeStampFormatErrors::UnsupportedYear)?;
+    let year = expand_year(year, year_part.len());
+
+    let mut time = next_part()?.split(':');
+    let mut next_time = || {
+      time
+        .next()
+        .and_then(|value| parse_digits(value, 2..=2))
+        .ok_or(TimeStampFormatErrors::InvalidTime)
+    };
+    let hours = next_time()?;
+    let minutes = next_time()?;
+    let seconds = match time.next() {
+      Some(value) => parse_digits(value, 2..=2).ok_or(TimeStampFormatErrors::InvalidTime)?,
+      None => 0,
+    };
 
-    if bytes.len() > 27 && bytes.len() < 20 {
-      return Err(TimeStampFormatErrors::InvalidFormat);
+    if time.next().is_some() {
+      return Err(TimeStampFormatErrors::InvalidTime);
     }
 
-    if bytes[10] != b'T' {
-      return Err(TimeStampFormatErrors::InvalidFormat);
-    }
+    let zone = next_part()?;
+    let tz = match zone.as_bytes() {
+      [b'+' | b'-', ..] => {
+        let (symbol, offset) = zone.split_at(1);
+        let offset = parse_digits(offset, 4..=4).ok_or(TimeStampFormatErrors::InvalidTimeOffset)?;
+        let (hours, minutes) = (offset / 100, offset % 100);
 
-    let date_part: u128 = unsafe { bytes.as_ptr().cast::<u128>().read() };
-    if date_part & DATE_STR_PATTERN_MASK != DATE_STR_PATTERN {
+        if hours > 23 || minutes > 59 {
+          return Err(TimeStampFormatErrors::InvalidTimeOffset);
+        }
+
+        match (symbol, offset) {
+          ("-", 0) => TZ_UNKNOWN,
+          _ => TimeZoneOffset {
+            hours: hours as u8,
+            minutes: minutes as u8,
+            symbol: if symbol == "+" {
+              OffsetSymbol::Plus
+            } else {
+              OffsetSymbol::Minus
+            },
+          },
+        }
+      }
+      [zone] if zone.is_ascii_alphabetic() && !zone.eq_ignore_ascii_case(&b'J') => {
+        if zone.eq_ignore_ascii_case(&b'Z') {
+          TZ_Z
+        } else {
+          TZ_UNKNOWN
+        }
+      }
+      _ => obsolete_zone(zone).ok_or(TimeStampFormatErrors::InvalidTimeOffset)?,
+    };
+
+    if parts.next().is_some() {
       return Err(TimeStampFormatErrors::InvalidFormat);
     }
 
-    let date: [u8; 16] = ((date_part & !DATE_STR_PATTERN_MASK) ^ DATE_STR_VALUE).to_le_bytes();
+    let is_leap_second = seconds == 60;
+    let seconds = if is_leap_second { 59 } else { seconds };
 
-    let year: u64 =
-      (date[0] as u64) * 1000 + (date[1] as u64) * 100 + (date[2] as u64) * 10 + (date[3] as u64);
-    let month = date[5] * 10 + date[6];
-    let day = date[8] * 10 + date[9];
+    let mut timestamp = Self::from_date_time(
+      [year, month, day],
+      [hours as u8, minutes as u8, seconds as u8],
+      tz,
+    )?;
+    timestamp.seconds += is_leap_second as i64;
 
-    if year < 1970 {
-      return Err(TimeStampFormatErrors::UnsupportedYear);
+    Ok(timestamp)
+  }
+
+  /// Formats timestamp as [RFC2822](https://datatracker.ietf.org/doc/html/rfc2822#section-3.3)
+  /// date time, which is also valid RFC822 used by RSS. Example: `Wed, 18 Sep 2024 23:28:25 +0000`
+  ///
+  /// Seconds fraction is dropped.
+  pub fn format_rfc822(&self) -> String {
+    let date_time = self.as_datetime();
+
+    format!(
+      "{}, {:0>2} {} {:0>4} {:0>2}:{:0>2}:{:0>2} {}{:0>2}{:0>2}",
+      WEEKDAY_NAMES[date_time.weekday as usize],
+      date_time.day,
+      MONTH_NAMES[(date_time.month - 1) as usize],
+      date_time.year,
+      date_time.hour,
+      date_time.minute,
+      date_time.seconds,
+      self.time_zone.symbol,
+      self.time_zone.hours,
+      self.time_zone.minutes
+    )
+  }
+
+  #[inline]
+  // Converts unix timestamp to 'biblically accurate' date time struct.
+  fn as_datetime(&self) -> DateTime {
+    let local_seconds = self.seconds.saturating_add(self.time_zone.as_seconds());
+    let (days, remainder) = divide!(local_seconds, DAY_IN_SECS);
+    let (year, month, day) = civil_from_days(days);
+
+    let (hour, remainder) = divide!(remainder, HOUR_IN_SECS);
+    let (minute, seconds) = divide!(remainder, 60);
+
+    DateTime {
+      year,
+      month,
+      day,
+      hour,
+      minute,
+      seconds,
+      // 1970-01-01 is Thursday.
+      weekday: (days + 4).rem_euclid(7),
     }
+  }
+}
 
-    if month > 12 && month < 1 {
-      return Err(TimeStampFormatErrors::InvalidDate);
+impl TimeZoneOffset {
+  /// Signed offset from UTC in seconds.
+  #[inline]
+  fn as_seconds(&self) -> i64 {
+    let seconds = (self.hours as i64) * HOUR_IN_SECS + (self.minutes as i64) * 60;
+
+    match self.symbol {
+      OffsetSymbol::Minus => -seconds,
+      OffsetSymbol::Plus => seconds,
     }
+  }
+}
 
-    let is_leap_year = is_leap_year!(year);
+/// Days since the unix epoch for a proleptic Gregorian calendar date, see
+/// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+  // Years start in March, January and February belong to the previous year.
+  let year = if month <= 2 { year - 1 } else { year };
+  let (era, year_of_era) = divide!(year, 400);
+  let month_from_march = (month + 9) % 12;
+  let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+  era * DAYS_IN_ERA + day_of_era - EPOCH_DAY_OFFSET
+}
 
-    let max_day = {
-      if month == 2 && is_leap_year {
-        DAYS_IN_MONTHS[1] + 1
-      } else {
-        DAYS_IN_MONTHS[(month - 1) as usize]
-      }
-    } as u8;
+/// Inverse of [days_from_civil], returns `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+  let (era, day_of_era) = divide!(days + EPOCH_DAY_OFFSET, DAYS_IN_ERA);
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_from_march = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+  let month = if month_from_march < 10 {
+    month_from_march + 3
+  } else {
+    month_from_march - 9
+  };
+  let year = era * 400 + year_of_era + (month <= 2) as i64;
 
-    if max_day < day {
-      return Err(TimeStampFormatErrors::InvalidDate);
-    }
+  (year, month, day)
+}
+
+/// Obsolete RFC822 zone names with their offsets west of UTC in hours.
+const OBSOLETE_ZONES: &[(&str, u8)] = &[
+  ("GMT", 0),
+  ("UT", 0),
+  ("UTC", 0),
+  ("EST", 5),
+  ("EDT", 4),
+  ("CST", 6),
+  ("CDT", 5),
+  ("MST", 7),
+  ("MDT", 6),
+  ("PST", 8),
+  ("PDT", 7),
+];
+
+fn obsolete_zone(zone: &str) -> Option<TimeZoneOffset> {
+  let (_, hours) = OBSOLETE_ZONES
+    .iter()
+    .find(|(name, _)| name.eq_ignore_ascii_case(zone))?;
+
+  match hours {
+    0 => Some(TZ_Z),
+    hours => Some(TimeZoneOffset {
+      hours: *hours,
+      minutes: 0,
+      symbol: OffsetSymbol::Minus,
+    }),
+  }
+}
+
+/// Expands two-digit years to `19xx` from `50` and to `20xx` below, and three-digit years by
+/// adding `1900`, as RFC2822 section 4.3 does.
+fn expand_year(year: u64, digits: usize) -> u64 {
+  match digits {
+    2 if year < 50 => year + 2000,
+    2 | 3 => year + 1900,
+    _ => year,
+  }
+}
+
+/// Parses fixed-width ASCII digits.
+#[inline]
+fn fixed_digits(digits: &[u8]) -> Option<u64> {
+  digits.iter().try_fold(0, |value, c| {
+    c.is_ascii_digit().then(|| value * 10 + (c - b'0') as u64)
+  })
+}
 
-    // Read 8-byte time string as u64 (excludes time fraction and offset)
-    let time_str = &bytes[11..=18];
-    let time_part: u64 = unsafe { time_str.as_ptr().cast::<u64>().read() };
+/// Parses ASCII digit only string with the length in given range.
+fn parse_digits(value: &str, length: core::ops::RangeInclusive<usize>) -> Option<u64> {
+  if !length.contains(&value.len()) || !value.bytes().all(|c| c.is_ascii_digit()) {
+    return None;
+  }
+
+  value.parse().ok()
+}
+
+/// Parses [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) `date-time`,
+/// e.g. `2024-09-18T23:28:25.542+03:00`. `T` and `Z` are case-insensitive, digits of the seconds
+/// fraction beyond nanoseconds are truncated. Leap seconds (`23:59:60`) are counted as the first
+/// second of the next minute, since unix time doesn't have them.
+impl FromStr for TimeStamp {
+  type Err = TimeStampFormatErrors;
 
-    if time_part & TIME_STR_PATTERN_MASK != TIME_STR_PATTERN {
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let bytes = s.as_bytes();
+
+    // Shortest date-time is `YYYY-MM-DDTHH:MM:SSZ`.
+    let (Some(date), Some(time)) = (bytes.get(..10), bytes.get(11..19)) else {
+      return Err(TimeStampFormatErrors::InvalidFormat);
+    };
+
+    if !matches!(bytes[10], b'T' | b't') {
       return Err(TimeStampFormatErrors::InvalidFormat);
     }
 
-    let time: [u8; 8] = ((time_part & !TIME_STR_PATTERN_MASK) ^ TIME_STR_VALUE).to_le_bytes();
-    let hours = time[0] * 10 + time[1];
-    let minutes = time[3] * 10 + time[4];
-    let seconds = time[6] * 10 + time[7];
+    let (year, month, day) = match date {
+      [y0, y1, y2, y3, b'-', m0, m1, b'-', d0, d1] => (
+        fixed_digits(&[*y0, *y1, *y2, *y3]),
+        fixed_digits(&[*m0, *m1]),
+        fixed_digits(&[*d0, *d1]),
+      ),
+      _ => return Err(TimeStampFormatErrors::InvalidFormat),
+    };
 
-    if hours > 23 || minutes > 59 || seconds > 59 {
-      return Err(TimeStampFormatErrors::InvalidTime);
-    }
+    let (hours, minutes, seconds) = match time {
+      [h0, h1, b':', m0, m1, b':', s0, s1] => (
+        fixed_digits(&[*h0, *h1]),
+        fixed_digits(&[*m0, *m1]),
+        fixed_digits(&[*s0, *s1]),
+      ),
+      _ => return Err(TimeStampFormatErrors::InvalidFormat),
+    };
+
+    let (Some(year), Some(month), Some(day), Some(hours), Some(minutes), Some(seconds)) =
+      (year, month, day, hours, minutes, seconds)
+    else {
+      return Err(TimeStampFormatErrors::InvalidFormat);
+    };
 
-    let tz_part = &bytes[19..];
+    let (nanos, tz_part) = match &bytes[19..] {
+      [b'.', fraction @ ..] => {
+        let length = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+
+        if length == 0 {
+          return Err(TimeStampFormatErrors::InvalidFormat);
+        }
+
+        let nanos = fraction[..length]
+          .iter()
+          .chain(core::iter::repeat(&b'0'))
+          .take(9)
+          .fold(0u32, |nanos, c| nanos * 10 + (c - b'0') as u32);
+
+        (nanos, &fraction[length..])
+      }
+      tz_part => (0, tz_part),
+    };
 
     let tz = match tz_part {
-      [b'Z'] => TZ_Z,
-      [b'-', b'0', b'0', b':', b'0', b'0'] => TZ_UNKNOWN,
-      [b'-', h0, h1, b':', m0, m1] => TimeZoneOffset {
-        symbol: OffsetSymbol::Minus,
-        minutes: (m0 - 0x30) * 10 + (m1 - 0x30),
-        hours: (h0 - 0x30) * 10 + (h1 - 0x30),
-      },
-      [b'+', h0, h1, b':', m0, m1] => TimeZoneOffset {
-        symbol: OffsetSymbol::Plus,
-        minutes: (m0 - 0x30) * 10 + (m1 - 0x30),
-        hours: (h0 - 0x30) * 10 + (h1 - 0x30),
-      },
+      [b'Z' | b'z'] => TZ_Z,
+      [symbol @ (b'+' | b'-'), h0, h1, b':', m0, m1] => {
+        let (Some(hours), Some(minutes)) = (fixed_digits(&[*h0, *h1]), fixed_digits(&[*m0, *m1]))
+        else {
+          return Err(TimeStampFormatErrors::InvalidTimeOffset);
+        };
+
+        if hours > 23 || minutes > 59 {
+          return Err(TimeStampFormatErrors::InvalidTimeOffset);
+        }
+
+        match (symbol, hours, minutes) {
+          (b'-', 0, 0) => TZ_UNKNOWN,
+          _ => TimeZoneOffset {
+            hours: hours as u8,
+            minutes: minutes as u8,
+            symbol: if *symbol == b'+' {
+              OffsetSymbol::Plus
+            } else {
+              OffsetSymbol::Minus
+            },
+          },
+        }
+      }
       _ => return Err(TimeStampFormatErrors::InvalidTimeOffset),
     };
 
-    let mut durat_secs: u64 = (seconds as u64)
-      + ((minutes as u64) * 60)
-      + ((hours as u64) * HOUR_IN_SECS)
-      + (((day - 1) as u64) * DAY_IN_SECS);
+    let is_leap_second = seconds == 60;
+    let seconds = if is_leap_second { 59 } else { seconds };
 
-    for idx in 0..(month - 1) {
-      durat_secs +=
-        (DAYS_IN_MONTHS[idx as usize] + (is_leap_year && idx == 1) as u64) * DAY_IN_SECS;
-    }
+    let mut timestamp = Self::from_date_time(
+      [year, month, day],
+      [hours as u8, minutes as u8, seconds as u8],
+      tz,
+    )?;
+    timestamp.seconds += is_leap_second as i64;
+    timestamp.nanos = nanos;
 
-    let (leap_year_blocks, remaining_years) = divide!(year - 1970, 4);
-    durat_secs += LEAP_YEAR_BLOCK * leap_year_blocks;
-    durat_secs += NON_LEAP_YEAR * remaining_years;
-    let offset_secs = ((tz.hours as u64) * 3600) + (tz.minutes as u64) * 60;
+    Ok(timestamp)
+  }
+}
 
-    durat_secs = match tz.symbol {
-      OffsetSymbol::Minus => durat_secs + offset_secs,
-      OffsetSymbol::Plus => durat_secs - offset_secs,
-    };
+/// Serialized as an RFC3339 date-time string, same as the [Display] output.
+#[cfg(feature = "serde")]
+impl serde::Serialize for TimeStamp {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
 
-    Ok(Self {
-      time_zone: tz,
-      unix_time: Duration::from_secs(durat_secs),
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TimeStamp {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let value = String::deserialize(deserializer)?;
+
+    value.parse().map_err(|_| {
+      serde::de::Error::invalid_value(serde::de::Unexpected::Str(&value), &"an RFC3339 date-time")
     })
   }
 }
@@ -349,23 +814,20 @@ mod test {
       symbol: timestamp::OffsetSymbol::Plus,
     });
     let text = timestamp.to_string();
-
-    println!("first {}", text);
+    assert_eq!("2024-09-19T02:28:25+03:00", text);
 
     let ts: TimeStamp = text.parse().unwrap();
+    assert_eq!(text, ts.to_string());
 
-    println!("second as u64 {}", ts.unix_time.as_secs());
-    println!("second as str {}", ts.to_string());
-
-    let mut timestamp2 = TimeStamp::new(ts.unix_time);
+    let mut timestamp2 = TimeStamp::from_unix_time(ts.seconds, ts.nanos).unwrap();
+    assert_eq!("2024-09-18T23:28:25Z", timestamp2.to_string());
 
-    println!("final {}", timestamp2.to_string());
     timestamp2.set_tz(timestamp::TimeZoneOffset {
       hours: 5,
       minutes: 0,
       symbol: timestamp::OffsetSymbol::Minus,
     });
-    println!("final {}", timestamp2.to_string());
-    assert_eq!(1726702105, ts.unix_time.as_secs());
+    assert_eq!("2024-09-18T18:28:25-05:00", timestamp2.to_string());
+    assert_eq!(1726702105, ts.seconds);
   }
 }
@@ -0,0 +1,809 @@
+use core::iter::Peekable;
+use core::str::FromStr;
+
+use super::{
+  expand_year, obsolete_zone, OffsetSymbol, TimeStamp, TimeZoneOffset, TZ_UNKNOWN, TZ_Z,
+};
+use crate::error::TimeStampFormatErrors;
+
+/// Non-standard date formats recognised by [TimeStamp::parse_lenient].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DateHeuristic {
+  /// RFC3339 like date and time with a space or `T` separator. Date can use `/` separators and
+  /// one-digit months and days, seconds are optional and the zone can be a name or an offset
+  /// without colon, e.g. `2024-09-18 23:28`, `2024/9/18 23:28:25 +0300`.
+  IsoDateTime,
+  /// Calendar date without time, read as midnight, e.g. `2024-09-18`.
+  IsoDate,
+  /// Date with an English month name, e.g. `Sept 18, 2024`, `18th September 2024 11:28 PM GMT`
+  /// or `Wed Sep 18 23:28:25 UTC 2024`.
+  MonthName,
+  /// Same as [DateHeuristic::MonthName], with the month and day names of
+  /// [LenientDateOptions::set_names], e.g. `Mittwoch, 18. September 2024`.
+  LocalizedMonthName,
+}
+
+impl DateHeuristic {
+  /// All heuristics, in the order they're tried by default.
+  pub const ALL: &'static [DateHeuristic] = &[
+    DateHeuristic::IsoDateTime,
+    DateHeuristic::IsoDate,
+    DateHeuristic::MonthName,
+    DateHeuristic::LocalizedMonthName,
+  ];
+}
+
+/// Month and day names of a language. Names are ASCII case-insensitive, and any unique prefix of
+/// at least three characters is accepted as abbreviation, e.g. `Sept` or `janv.`.
+#[derive(Clone, Copy, Debug)]
+pub struct DateNames<'a> {
+  months: [&'a str; 12],
+  days: [&'a str; 7],
+}
+
+impl<'a> DateNames<'a> {
+  /// Creates names from the months starting with January and the days starting with Sunday.
+  pub const fn new(months: [&'a str; 12], days: [&'a str; 7]) -> Self {
+    Self { months, days }
+  }
+
+  /// Looks up the month number, from 1 to 12.
+  fn month(&self, word: &str) -> Option<u64> {
+    find_name(&self.months, word).map(|index| index as u64 + 1)
+  }
+
+  fn is_day(&self, word: &str) -> bool {
+    find_name(&self.days, word).is_some()
+  }
+}
+
+impl DateNames<'static> {
+  pub const ENGLISH: Self = Self::new(
+    [
+      "January",
+      "February",
+      "March",
+      "April",
+      "May",
+      "June",
+      "July",
+      "August",
+      "September",
+      "October",
+      "November",
+      "December",
+    ],
+    [
+      "Sunday",
+      "Monday",
+      "Tuesday",
+      "Wednesday",
+      "Thursday",
+      "Friday",
+      "Saturday",
+    ],
+  );
+
+  pub const GERMAN: Self = Self::new(
+    [
+      "Januar",
+      "Februar",
+      "März",
+      "April",
+      "Mai",
+      "Juni",
+      "Juli",
+      "August",
+      "September",
+      "Oktober",
+      "November",
+      "Dezember",
+    ],
+    [
+      "Sonntag",
+      "Montag",
+      "Dienstag",
+      "Mittwoch",
+      "Donnerstag",
+      "Freitag",
+      "Samstag",
+    ],
+  );
+
+  pub const FRENCH: Self = Self::new(
+    [
+      "janvier",
+      "février",
+      "mars",
+      "avril",
+      "mai",
+      "juin",
+      "juillet",
+      "août",
+      "septembre",
+      "octobre",
+      "novembre",
+      "décembre",
+    ],
+    [
+      "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+    ],
+  );
+
+  pub const SPANISH: Self = Self::new(
+    [
+      "enero",
+      "febrero",
+      "marzo",
+      "abril",
+      "mayo",
+      "junio",
+      "julio",
+      "agosto",
+      "septiembre",
+      "octubre",
+      "noviembre",
+      "diciembre",
+    ],
+    [
+      "domingo",
+      "lunes",
+      "martes",
+      "miércoles",
+      "jueves",
+      "viernes",
+      "sábado",
+    ],
+  );
+
+  pub const ITALIAN: Self = Self::new(
+    [
+      "gennaio",
+      "febbraio",
+      "marzo",
+      "aprile",
+      "maggio",
+      "giugno",
+      "luglio",
+      "agosto",
+      "settembre",
+      "ottobre",
+      "novembre",
+      "dicembre",
+    ],
+    [
+      "domenica",
+      "lunedì",
+      "martedì",
+      "mercoledì",
+      "giovedì",
+      "venerdì",
+      "sabato",
+    ],
+  );
+
+  pub const PORTUGUESE: Self = Self::new(
+    [
+      "janeiro",
+      "fevereiro",
+      "março",
+      "abril",
+      "maio",
+      "junho",
+      "julho",
+      "agosto",
+      "setembro",
+      "outubro",
+      "novembro",
+      "dezembro",
+    ],
+    [
+      "domingo",
+      "segunda-feira",
+      "terça-feira",
+      "quarta-feira",
+      "quinta-feira",
+      "sexta-feira",
+      "sábado",
+    ],
+  );
+
+  pub const DUTCH: Self = Self::new(
+    [
+      "januari",
+      "februari",
+      "maart",
+      "april",
+      "mei",
+      "juni",
+      "juli",
+      "augustus",
+      "september",
+      "oktober",
+      "november",
+      "december",
+    ],
+    [
+      "zondag",
+      "maandag",
+      "dinsdag",
+      "woensdag",
+      "donderdag",
+      "vrijdag",
+      "zaterdag",
+    ],
+  );
+
+  /// Default names of [DateHeuristic::LocalizedMonthName].
+  pub const LOCALIZED: &'static [DateNames<'static>] = &[
+    Self::GERMAN,
+    Self::FRENCH,
+    Self::SPANISH,
+    Self::ITALIAN,
+    Self::PORTUGUESE,
+    Self::DUTCH,
+  ];
+}
+
+/// Settings of [TimeStamp::parse_lenient].
+#[derive(Clone, Copy)]
+pub struct LenientDateOptions<'a> {
+  heuristics: &'a [DateHeuristic],
+  names: &'a [DateNames<'a>],
+  default_time_zone: Option<TimeZoneOffset>,
+}
+
+impl Default for LenientDateOptions<'_> {
+  /// Tries all heuristics with [DateNames::LOCALIZED], dates without time zone are read as UTC
+  /// with unknown local offset (`-00:00`).
+  fn default() -> Self {
+    Self {
+      heuristics: DateHeuristic::ALL,
+      names: DateNames::LOCALIZED,
+      default_time_zone: Some(TZ_UNKNOWN),
+    }
+  }
+}
+
+impl<'a> LenientDateOptions<'a> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the heuristics to try, in order.
+  pub fn set_heuristics(&mut self, heuristics: &'a [DateHeuristic]) -> &mut Self {
+    self.heuristics = heuristics;
+    self
+  }
+
+  /// Sets the names of [DateHeuristic::LocalizedMonthName], tried in order.
+  pub fn set_names(&mut self, names: &'a [DateNames<'a>]) -> &mut Self {
+    self.names = names;
+    self
+  }
+
+  /// Sets the time zone of the dates without one, `None` rejects them.
+  pub fn set_default_time_zone(&mut self, time_zone: Option<TimeZoneOffset>) -> &mut Self {
+    self.default_time_zone = time_zone;
+    self
+  }
+}
+
+/// Result of [TimeStamp::parse_lenient].
+#[derive(Clone, Copy)]
+pub struct LenientTimeStamp {
+  pub timestamp: TimeStamp,
+  /// Heuristic that recognised the date, `None` for RFC3339 and RFC2822 dates.
+  pub heuristic: Option<DateHeuristic>,
+  /// Date had no time zone, the default time zone of the options is used.
+  pub assumed_time_zone: bool,
+}
+
+impl TimeStamp {
+  /// Parses RFC3339 and RFC2822 dates, then tries the heuristics of the options in order for the
+  /// non-standard dates found in the wild.
+  ///
+  /// Errors of dates recognised by a heuristic, like [TimeStampFormatErrors::NonExistingDate],
+  /// take precedence over the RFC3339 errors.
+  ///
+  /// ```
+  /// use syndication_format::common::{DateHeuristic, LenientDateOptions, TimeStamp};
+  ///
+  /// let options = LenientDateOptions::default();
+  /// let parsed = TimeStamp::parse_lenient("Sept 18, 2024 23:28 GMT", &options).unwrap();
+  ///
+  /// assert_eq!(Some(DateHeuristic::MonthName), parsed.heuristic);
+  /// assert_eq!("2024-09-18T23:28:00Z", parsed.timestamp.to_string());
+  ///
+  /// let parsed = TimeStamp::parse_lenient("2024-09-18", &options).unwrap();
+  ///
+  /// assert_eq!(Some(DateHeuristic::IsoDate), parsed.heuristic);
+  /// assert!(parsed.assumed_time_zone);
+  /// ```
+  pub fn parse_lenient(
+    value: &str,
+    options: &LenientDateOptions<'_>,
+  ) -> Result<LenientTimeStamp, TimeStampFormatErrors> {
+    let value = value.trim();
+    let rfc3339_error = match TimeStamp::from_str(value)
+      .or_else(|error| TimeStamp::parse_rfc822(value).map_err(|_| error))
+    {
+      Ok(timestamp) => {
+        return Ok(LenientTimeStamp {
+          timestamp,
+          heuristic: None,
+          assumed_time_zone: false,
+        })
+      }
+      Err(error) => error,
+    };
+
+    let mut error = None;
+
+    for heuristic in options.heuristics {
+      let parts = match heuristic {
+        DateHeuristic::IsoDateTime => iso(value, true),
+        DateHeuristic::IsoDate => iso(value, false),
+        DateHeuristic::MonthName => month_name(value, &DateNames::ENGLISH),
+        DateHeuristic::LocalizedMonthName => options
+          .names
+          .iter()
+          .map(|names| month_name(value, names))
+          .find(|parts| !matches!(parts, Err(TimeStampFormatErrors::InvalidFormat)))
+          .unwrap_or(Err(TimeStampFormatErrors::InvalidFormat)),
+      };
+
+      match parts.and_then(|parts| parts.timestamp(options)) {
+        Ok((timestamp, assumed_time_zone)) => {
+          return Ok(LenientTimeStamp {
+            timestamp,
+            heuristic: Some(*heuristic),
+            assumed_time_zone,
+          })
+        }
+        Err(TimeStampFormatErrors::InvalidFormat) => (),
+        Err(inner) => {
+          error.get_or_insert(inner);
+        }
+      }
+    }
+
+    Err(error.unwrap_or(rfc3339_error))
+  }
+}
+
+/// Date and time recognised by a heuristic, before validation.
+struct DateParts {
+  date: [u64; 3],
+  time: [u64; 3],
+  nanos: u32,
+  time_zone: Option<TimeZoneOffset>,
+}
+
+impl DateParts {
+  /// Validates the parts, returns the timestamp and whether the default time zone was used.
+  fn timestamp(
+    self,
+    options: &LenientDateOptions<'_>,
+  ) -> Result<(TimeStamp, bool), TimeStampFormatErrors> {
+    let (time_zone, assumed_time_zone) = match self.time_zone {
+      Some(time_zone) => (time_zone, false),
+      None => (
+        options
+          .default_time_zone
+          .ok_or(TimeStampFormatErrors::InvalidTimeOffset)?,
+        true,
+      ),
+    };
+
+    let [hours, minutes, seconds] = self.time;
+
+    if hours > 23 || minutes > 59 || seconds > 60 {
+      return Err(TimeStampFormatErrors::InvalidTime);
+    }
+
+    let is_leap_second = seconds == 60;
+    let seconds = if is_leap_second { 59 } else { seconds };
+
+    let mut timestamp = TimeStamp::from_date_time(
+      self.date,
+      [hours as u8, minutes as u8, seconds as u8],
+      time_zone,
+    )?;
+    timestamp.seconds += is_leap_second as i64;
+    timestamp.nanos = self.nanos;
+
+    Ok((timestamp, assumed_time_zone))
+  }
+}
+
+/// [DateHeuristic::IsoDateTime] and [DateHeuristic::IsoDate].
+fn iso(value: &str, with_time: bool) -> Result<DateParts, TimeStampFormatErrors> {
+  let (date, rest) = iso_date(value).ok_or(TimeStampFormatErrors::InvalidFormat)?;
+
+  if !with_time {
+    return match rest {
+      "" => Ok(DateParts {
+        date,
+        time: [0; 3],
+        nanos: 0,
+        time_zone: None,
+      }),
+      _ => Err(TimeStampFormatErrors::InvalidFormat),
+    };
+  }
+
+  let rest = rest
+    .strip_prefix(['T', 't'])
+    .or_else(|| rest.strip_prefix(char::is_whitespace))
+    .ok_or(TimeStampFormatErrors::InvalidFormat)?;
+
+  let mut tokens = Tokens { rest }.peekable();
+  let Some(Token::Time { time, nanos }) = tokens.next() else {
+    return Err(TimeStampFormatErrors::InvalidFormat);
+  };
+  let time_zone = zone(&mut tokens)?;
+
+  if tokens.next().is_some() {
+    return Err(TimeStampFormatErrors::InvalidFormat);
+  }
+
+  Ok(DateParts {
+    date,
+    time,
+    nanos,
+    time_zone,
+  })
+}
+
+/// Parses `YYYY-M[M]-D[D]` or `YYYY/M[M]/D[D]`, returns the date and the rest of the value.
+fn iso_date(value: &str) -> Option<([u64; 3], &str)> {
+  let (year, 4, rest) = leading_digits(value)? else {
+    return None;
+  };
+
+  let separator = rest.chars().next().filter(|c| matches!(c, '-' | '/'))?;
+  let (month, 1..=2, rest) = leading_digits(&rest[1..])? else {
+    return None;
+  };
+  let (day, 1..=2, rest) = leading_digits(rest.strip_prefix(separator)?)? else {
+    return None;
+  };
+
+  Some(([year, month, day], rest))
+}
+
+/// [DateHeuristic::MonthName] and [DateHeuristic::LocalizedMonthName]. Accepts an optional day
+/// name, the day and month in either order, then the year, with the time either after the year
+/// or before it like in C `asctime` output.
+fn month_name(value: &str, names: &DateNames<'_>) -> Result<DateParts, TimeStampFormatErrors> {
+  let mut tokens = Tokens { rest: value }.peekable();
+
+  if let Some(Token::Word(word)) = tokens.peek() {
+    if names.is_day(word) {
+      tokens.next();
+    }
+  }
+
+  let (month, day) = match tokens.next() {
+    Some(Token::Word(word)) => {
+      let month = names.month(word);
+      let day = match tokens.next() {
+        Some(Token::Number {
+          value,
+          digits: 1..=2,
+        }) => value,
+        _ => return Err(TimeStampFormatErrors::InvalidFormat),
+      };
+
+      skip_ordinal(&mut tokens);
+      (month, day)
+    }
+    Some(Token::Number {
+      value,
+      digits: 1..=2,
+    }) => {
+      skip_ordinal(&mut tokens);
+
+      match tokens.next() {
+        Some(Token::Word(word)) => (names.month(word), value),
+        _ => return Err(TimeStampFormatErrors::InvalidFormat),
+      }
+    }
+    _ => return Err(TimeStampFormatErrors::InvalidFormat),
+  };
+
+  let month = month.ok_or(TimeStampFormatErrors::InvalidFormat)?;
+
+  let mut time = time_of_day(&mut tokens)?;
+  let mut time_zone = match time {
+    Some(_) => zone(&mut tokens)?,
+    None => None,
+  };
+
+  let year = match tokens.next() {
+    Some(Token::Number {
+      value,
+      digits: digits @ 2..=4,
+    }) => expand_year(value, digits),
+    _ => return Err(TimeStampFormatErrors::InvalidFormat),
+  };
+
+  if time.is_none() {
+    time = time_of_day(&mut tokens)?;
+
+    if time.is_some() {
+      time_zone = zone(&mut tokens)?;
+    }
+  }
+
+  if tokens.next().is_some() {
+    return Err(TimeStampFormatErrors::InvalidFormat);
+  }
+
+  let (time, nanos) = time.unwrap_or(([0; 3], 0));
+
+  Ok(DateParts {
+    date: [year, month, day],
+    time,
+    nanos,
+    time_zone,
+  })
+}
+
+/// Skips the English ordinal suffix of a day, e.g. `18th`.
+fn skip_ordinal(tokens: &mut Peekable<Tokens<'_>>) {
+  if let Some(Token::Word(word)) = tokens.peek() {
+    if ["st", "nd", "rd", "th"]
+      .iter()
+      .any(|suffix| suffix.eq_ignore_ascii_case(word))
+    {
+      tokens.next();
+    }
+  }
+}
+
+/// Reads an optional time followed by an optional `AM` or `PM`.
+fn time_of_day(
+  tokens: &mut Peekable<Tokens<'_>>,
+) -> Result<Option<([u64; 3], u32)>, TimeStampFormatErrors> {
+  let Some(&Token::Time { time, nanos }) = tokens.peek() else {
+    return Ok(None);
+  };
+  tokens.next();
+
+  let [hours, minutes, seconds] = time;
+  let is_pm = match tokens.peek() {
+    Some(Token::Word(word)) if word.eq_ignore_ascii_case("am") => false,
+    Some(Token::Word(word)) if word.eq_ignore_ascii_case("pm") => true,
+    _ => return Ok(Some((time, nanos))),
+  };
+  tokens.next();
+
+  if !(1..=12).contains(&hours) {
+    return Err(TimeStampFormatErrors::InvalidTime);
+  }
+
+  let hours = hours % 12 + if is_pm { 12 } else { 0 };
+
+  Ok(Some(([hours, minutes, seconds], nanos)))
+}
+
+/// Reads an optional zone, `Z`, an RFC822 zone name or a `+HHMM` or `+HH:MM` offset.
+fn zone(
+  tokens: &mut Peekable<Tokens<'_>>,
+) -> Result<Option<TimeZoneOffset>, TimeStampFormatErrors> {
+  let time_zone = match tokens.peek() {
+    Some(Token::Word(word)) if word.eq_ignore_ascii_case("z") => TZ_Z,
+    Some(Token::Word(word)) => {
+      obsolete_zone(word).ok_or(TimeStampFormatErrors::InvalidTimeOffset)?
+    }
+    Some(&Token::Offset {
+      symbol,
+      hours,
+      minutes,
+    }) => {
+      if hours > 23 || minutes > 59 {
+        return Err(TimeStampFormatErrors::InvalidTimeOffset);
+      }
+
+      match (symbol, hours, minutes) {
+        (OffsetSymbol::Minus, 0, 0) => TZ_UNKNOWN,
+        _ => TimeZoneOffset {
+          hours,
+          minutes,
+          symbol,
+        },
+      }
+    }
+    _ => return Ok(None),
+  };
+  tokens.next();
+
+  Ok(Some(time_zone))
+}
+
+#[derive(Clone, Copy)]
+enum Token<'a> {
+  Number {
+    value: u64,
+    digits: usize,
+  },
+  /// Letters, with the inner hyphens and without the trailing dot.
+  Word(&'a str),
+  /// `H[H]:MM[:SS[.fraction]]`
+  Time {
+    time: [u64; 3],
+    nanos: u32,
+  },
+  /// `+HHMM` or `+HH:MM`
+  Offset {
+    symbol: OffsetSymbol,
+    hours: u8,
+    minutes: u8,
+  },
+  Invalid,
+}
+
+/// Splits a date into tokens. Whitespace and commas separate tokens, a dot after a number or a
+/// word is skipped.
+struct Tokens<'a> {
+  rest: &'a str,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+  type Item = Token<'a>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let rest = self
+      .rest
+      .trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    let first = rest.chars().next()?;
+
+    let (token, rest) = match first {
+      '0'..='9' => number_or_time(rest),
+      '+' | '-' => offset(first, &rest[1..]),
+      _ if first.is_alphabetic() => {
+        let mut length = 0;
+
+        for (index, c) in rest.char_indices() {
+          if c.is_alphabetic() {
+            length = index + c.len_utf8();
+          } else if c != '-' || !rest[index + 1..].starts_with(char::is_alphabetic) {
+            break;
+          }
+        }
+
+        let (word, rest) = rest.split_at(length);
+        (Token::Word(word), rest.strip_prefix('.').unwrap_or(rest))
+      }
+      _ => (Token::Invalid, ""),
+    };
+
+    self.rest = rest;
+    Some(token)
+  }
+}
+
+fn number_or_time(value: &str) -> (Token<'_>, &str) {
+  let invalid = (Token::Invalid, "");
+
+  let Some((number, digits, rest)) = leading_digits(value) else {
+    return invalid;
+  };
+
+  let Some(rest) = rest.strip_prefix(':') else {
+    return (
+      Token::Number {
+        value: number,
+        digits,
+      },
+      rest.strip_prefix('.').unwrap_or(rest),
+    );
+  };
+
+  let Some((minutes, 2, rest)) = (digits <= 2).then(|| leading_digits(rest)).flatten() else {
+    return invalid;
+  };
+
+  let (seconds, rest) = match rest.strip_prefix(':').map(leading_digits) {
+    Some(Some((seconds, 2, rest))) => (seconds, rest),
+    Some(_) => return invalid,
+    None => (0, rest),
+  };
+
+  let (nanos, rest) = match rest.strip_prefix('.') {
+    Some(fraction) => {
+      let length = fraction.bytes().take_while(u8::is_ascii_digit).count();
+
+      if length == 0 {
+        return invalid;
+      }
+
+      let nanos = fraction.as_bytes()[..length]
+        .iter()
+        .chain(core::iter::repeat(&b'0'))
+        .take(9)
+        .fold(0u32, |nanos, c| nanos * 10 + (c - b'0') as u32);
+
+      (nanos, &fraction[length..])
+    }
+    None => (0, rest),
+  };
+
+  (
+    Token::Time {
+      time: [number, minutes, seconds],
+      nanos,
+    },
+    rest,
+  )
+}
+
+fn offset(symbol: char, value: &str) -> (Token<'_>, &str) {
+  let symbol = if symbol == '+' {
+    OffsetSymbol::Plus
+  } else {
+    OffsetSymbol::Minus
+  };
+
+  let (hours, minutes, rest) = match leading_digits(value) {
+    Some((offset, 4, rest)) => (offset / 100, offset % 100, rest),
+    Some((hours, 2, rest)) => match rest.strip_prefix(':').and_then(leading_digits) {
+      Some((minutes, 2, rest)) => (hours, minutes, rest),
+      _ => return (Token::Invalid, ""),
+    },
+    _ => return (Token::Invalid, ""),
+  };
+
+  (
+    Token::Offset {
+      symbol,
+      hours: hours as u8,
+      minutes: minutes as u8,
+    },
+    rest,
+  )
+}
+
+/// Parses the leading ASCII digits, returns the number, the count of digits and the rest. At
+/// most 9 digits are accepted.
+fn leading_digits(value: &str) -> Option<(u64, usize, &str)> {
+  let digits = value.bytes().take_while(u8::is_ascii_digit).count();
+
+  if !(1..=9).contains(&digits) {
+    return None;
+  }
+
+  Some((value[..digits].parse().ok()?, digits, &value[digits..]))
+}
+
+/// Finds a name by exact match or by a unique prefix of at least three characters, ignoring
+/// ASCII case.
+fn find_name(names: &[&str], word: &str) -> Option<usize> {
+  if let Some(index) = names
+    .iter()
+    .position(|name| name.eq_ignore_ascii_case(word))
+  {
+    return Some(index);
+  }
+
+  if word.chars().count() < 3 {
+    return None;
+  }
+
+  let mut matches = names
+    .iter()
+    .enumerate()
+    .filter(|(_, name)| {
+      name
+        .get(..word.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(word))
+    })
+    .map(|(index, _)| index);
+
+  match (matches.next(), matches.next()) {
+    (Some(index), None) => Some(index),
+    _ => None,
+  }
+}
@@ -0,0 +1,120 @@
+//! Conversions between [crate::atom::Feed], [crate::rss::Channel] and
+//! [crate::json_feed::JsonFeed].
+//!
+//! Every conversion is a [TryFrom] from a borrowed source document into [Converted], which holds
+//! the target document and the source fields that the target format has no place for. Strings
+//! are borrowed from the source, nothing is copied.
+//!
+//! ```
+//! use core::time::Duration;
+//!
+//! use syndication_format::{
+//!   atom::{Contributor, Entry, Feed, Link},
+//!   common::{TimeStamp, XmlText},
+//!   convert::{Converted, UnmappedField},
+//!   rss::Channel,
+//! };
+//!
+//! let updated = TimeStamp::new(Duration::from_secs(1726702105));
+//! let mut feed = Feed::new("https://example.org/", XmlText::PlainText("Example"), updated);
+//! feed.generator = None;
+//! feed.links.push(Link::new("https://example.org/"));
+//! feed.contributors.push(Contributor::new("Jane"));
+//! feed
+//!   .entries
+//!   .push(Entry::new("urn:example:1", XmlText::PlainText("First"), updated));
+//!
+//! let Converted { value, unmapped } = Converted::<Channel>::try_from(&feed).unwrap();
+//!
+//! assert_eq!("Example", value.title);
+//! assert_eq!(Some("urn:example:1"), value.items[0].guid.as_ref().map(|guid| guid.value));
+//! assert_eq!(
+//!   vec![UnmappedField {
+//!     name: "contributors",
+//!     item: None
+//!   }],
+//!   unmapped
+//! );
+//! ```
+
+mod atom;
+#[cfg(feature = "json_feed")]
+mod json_feed;
+mod rss;
+
+use crate::common::{AttributeMap, AttributeName, XmlText};
+
+/// Converted document and the source fields which could not be represented in it.
+pub struct Converted<T> {
+  pub value: T,
+  pub unmapped: Vec<UnmappedField>,
+}
+
+/// Source field whose value was dropped, completely or partially, during the conversion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnmappedField {
+  /// Field name in the source model, e.g. `contributors`.
+  pub name: &'static str,
+  /// Index of the entry or item the field belongs to, `None` for the feed level fields.
+  pub item: Option<usize>,
+}
+
+/// Collects the unmapped fields while converting.
+#[derive(Default)]
+struct Report {
+  item: Option<usize>,
+  unmapped: Vec<UnmappedField>,
+}
+
+impl Report {
+  /// Sets the entry or item index for the following fields.
+  #[inline]
+  fn set_item(&mut self, item: Option<usize>) {
+    self.item = item;
+  }
+
+  fn unmapped(&mut self, name: &'static str) {
+    let field = UnmappedField {
+      name,
+      item: self.item,
+    };
+
+    if !self.unmapped.contains(&field) {
+      self.unmapped.push(field);
+    }
+  }
+
+  #[inline]
+  fn unmapped_if(&mut self, condition: bool, name: &'static str) {
+    if condition {
+      self.unmapped(name);
+    }
+  }
+
+  fn finish<T>(self, value: T) -> Converted<T> {
+    Converted {
+      value,
+      unmapped: self.unmapped,
+    }
+  }
+}
+
+/// Returns the text as is, already escaped text is not representable outside of XML.
+fn text_value<'a>(text: &XmlText<'a>) -> Option<&'a str> {
+  match *text {
+    XmlText::Html(value) | XmlText::Xhtml(value) | XmlText::PlainText(value) => Some(value),
+    XmlText::HtmlUnchecked(_) | XmlText::PlainTextUnchecked(_) => None,
+  }
+}
+
+const XML_LANG: &str = "xml:lang";
+
+fn get_language(attributes: &AttributeMap) -> Option<&str> {
+  attributes.get(XML_LANG).map(|value| value.as_ref())
+}
+
+fn set_language(attributes: &mut AttributeMap, language: Option<&str>) {
+  if let Some(language) = language {
+    attributes.set(AttributeName::unchecked_new(XML_LANG), language.into());
+  }
+}
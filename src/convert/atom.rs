@@ -0,0 +1,297 @@
+use crate::{
+  atom::{
+    Author, Category, Contributor, Entry, Feed, Generator, Link, Logo, Published, Rights, SubTitle,
+    Summary,
+  },
+  common::{LinkRelation, TimeStamp, XmlText},
+  error::ConvertError,
+  rss::{self, Channel, RSS_DOCS},
+};
+
+#[cfg(feature = "json_feed")]
+use crate::{
+  atom::{Content, Icon},
+  json_feed::{self, JsonFeed},
+};
+
+use super::{set_language, Converted, Report};
+
+fn link(href: &str, rel: LinkRelation) -> Link<'_> {
+  let mut link = Link::new(href);
+  link.rel = Some(rel);
+  link
+}
+
+/// Latest of the given timestamps.
+fn latest(timestamps: impl Iterator<Item = TimeStamp>) -> Option<TimeStamp> {
+  timestamps.max()
+}
+
+impl<'a: 'b, 'b> TryFrom<&'b Channel<'a>> for Converted<Feed<'b>> {
+  type Error = ConvertError;
+
+  /// Channel link becomes the feed id and alternate link. `updated` is taken from the last
+  /// build date, publication date or the latest item, in that order. Item ids are taken from
+  /// the guid or the item link.
+  fn try_from(channel: &'b Channel<'a>) -> Result<Self, Self::Error> {
+    let mut report = Report::default();
+
+    let updated = channel
+      .last_build_date
+      .or(channel.pub_date)
+      .or_else(|| latest(channel.items.iter().filter_map(|item| item.pub_date)))
+      .ok_or(ConvertError::MissingField {
+        name: "last_build_date",
+      })?;
+
+    report.unmapped_if(
+      channel.last_build_date.is_some() && channel.pub_date.is_some(),
+      "pub_date",
+    );
+
+    let mut feed = Feed::new(channel.link, XmlText::PlainText(channel.title), updated);
+    feed.links.push(link(channel.link, LinkRelation::Alternate));
+    set_language(&mut feed.attributes, channel.language);
+
+    if !channel.description.is_empty() {
+      feed.subtitle = Some(SubTitle::new(XmlText::PlainText(channel.description)));
+    }
+
+    if let Some(generator) = channel.generator {
+      feed.generator = Some(Generator::new(generator));
+    }
+
+    feed.rights = channel
+      .copyright
+      .map(|copyright| Rights::new(XmlText::PlainText(copyright)));
+    feed
+      .authors
+      .extend(channel.managing_editor.map(Author::new));
+    feed
+      .contributors
+      .extend(channel.web_master.map(Contributor::new));
+    feed.categories = categories(&channel.categories);
+
+    if let Some(image) = channel.image.as_ref() {
+      feed.logo = Some(Logo::new(image.url));
+      report.unmapped_if(
+        image.description.is_some() || image.height.is_some() || image.width.is_some(),
+        "image",
+      );
+    }
+
+    report.unmapped_if(channel.cloud.is_some(), "cloud");
+    report.unmapped_if(channel.docs.is_some_and(|docs| docs != RSS_DOCS), "docs");
+    report.unmapped_if(channel.rating.is_some(), "rating");
+    report.unmapped_if(!channel.skip_days.is_empty(), "skip_days");
+    report.unmapped_if(!channel.skip_hours.is_empty(), "skip_hours");
+    report.unmapped_if(channel.text_input.is_some(), "text_input");
+    report.unmapped_if(channel.ttl.is_some(), "ttl");
+
+    for (index, item) in channel.items.iter().enumerate() {
+      report.set_item(Some(index));
+      feed.entries.push(rss_entry(item, updated, &mut report)?);
+    }
+
+    Ok(report.finish(feed))
+  }
+}
+
+fn rss_entry<'b>(
+  item: &'b rss::Item<'_>,
+  updated: TimeStamp,
+  report: &mut Report,
+) -> Result<Entry<'b>, ConvertError> {
+  let id = item
+    .guid
+    .as_ref()
+    .map(|guid| guid.value)
+    .or(item.link)
+    .ok_or(ConvertError::MissingField { name: "guid" })?;
+
+  let title = XmlText::PlainText(item.title.unwrap_or_default());
+  let mut entry = Entry::new(id, title, item.pub_date.unwrap_or(updated));
+  entry.published = item.pub_date.map(Published::new);
+
+  let permalink = item
+    .guid
+    .as_ref()
+    .filter(|guid| guid.is_permalink)
+    .map(|guid| guid.value);
+
+  if let Some(href) = item.link.or(permalink) {
+    entry.links.push(link(href, LinkRelation::Alternate));
+  }
+
+  if let Some(enclosure) = item.enclosure.as_ref() {
+    let mut link = link(enclosure.url, LinkRelation::Enclosure);
+    link.link_type = Some(enclosure.media_type);
+    link.length = usize::try_from(enclosure.length).ok();
+    entry.links.push(link);
+  }
+
+  if let Some(source) = item.source.as_ref() {
+    let mut link = link(source.url, LinkRelation::Via);
+    link.title = Some(source.value);
+    entry.links.push(link);
+  }
+
+  entry.summary = item
+    .description
+    .map(|description| Summary::new(XmlText::Html(description)));
+  entry.authors.extend(item.author.map(Author::new));
+  entry.categories = categories(&item.categories);
+
+  report.unmapped_if(item.comments.is_some(), "comments");
+
+  Ok(entry)
+}
+
+fn categories<'b>(categories: &'b [rss::Category<'_>]) -> Vec<Category<'b>> {
+  categories
+    .iter()
+    .map(|category| {
+      let mut value = Category::new(category.value);
+      value.scheme = category.domain;
+      value
+    })
+    .collect()
+}
+
+#[cfg(feature = "json_feed")]
+impl<'a: 'b, 'b> TryFrom<&'b JsonFeed<'a>> for Converted<Feed<'b>> {
+  type Error = ConvertError;
+
+  /// Feed url, or the home page when it's missing, becomes the feed id. `updated` is the latest
+  /// item modification or publication date. Icon becomes the logo and favicon the icon.
+  fn try_from(source: &'b JsonFeed<'a>) -> Result<Self, Self::Error> {
+    let mut report = Report::default();
+
+    let id = source
+      .feed_url
+      .or(source.home_page_url)
+      .ok_or(ConvertError::MissingField { name: "feed_url" })?;
+
+    let updated = latest(
+      source
+        .items
+        .iter()
+        .filter_map(|item| item.date_modified.or(item.date_published)),
+    )
+    .ok_or(ConvertError::MissingField {
+      name: "date_modified",
+    })?;
+
+    let mut feed = Feed::new(id, XmlText::PlainText(source.title), updated);
+    set_language(&mut feed.attributes, source.language);
+
+    if let Some(home_page_url) = source.home_page_url {
+      feed
+        .links
+        .push(link(home_page_url, LinkRelation::Alternate));
+    }
+
+    if let Some(feed_url) = source.feed_url {
+      let mut link = link(feed_url, LinkRelation::SelfRel);
+      link.link_type = Some("application/feed+json");
+      feed.links.push(link);
+    }
+
+    feed.subtitle = source
+      .description
+      .map(|description| SubTitle::new(XmlText::PlainText(description)));
+    feed.logo = source.icon.map(Logo::new);
+    feed.icon = source.favicon.map(Icon::new);
+    feed.authors = authors(&source.authors, &mut report);
+
+    report.unmapped_if(source.expired.is_some(), "expired");
+    report.unmapped_if(!source.extensions.is_empty(), "extensions");
+    report.unmapped_if(!source.hubs.is_empty(), "hubs");
+    report.unmapped_if(source.next_url.is_some(), "next_url");
+    report.unmapped_if(source.user_comment.is_some(), "user_comment");
+
+    for (index, item) in source.items.iter().enumerate() {
+      report.set_item(Some(index));
+      feed.entries.push(json_entry(item, updated, &mut report));
+    }
+
+    Ok(report.finish(feed))
+  }
+}
+
+#[cfg(feature = "json_feed")]
+fn json_entry<'b>(
+  item: &'b json_feed::Item<'_>,
+  updated: TimeStamp,
+  report: &mut Report,
+) -> Entry<'b> {
+  let title = XmlText::PlainText(item.title.unwrap_or_default());
+  let updated = item
+    .date_modified
+    .or(item.date_published)
+    .unwrap_or(updated);
+
+  let mut entry = Entry::new(item.id, title, updated);
+  set_language(&mut entry.attributes, item.language);
+
+  if let Some(url) = item.url {
+    entry.links.push(link(url, LinkRelation::Alternate));
+  }
+
+  if let Some(external_url) = item.external_url {
+    entry.links.push(link(external_url, LinkRelation::Related));
+  }
+
+  for attachment in item.attachments.iter() {
+    let mut link = link(attachment.url, LinkRelation::Enclosure);
+    link.link_type = Some(attachment.mime_type);
+    link.title = attachment.title;
+    link.length = attachment
+      .size_in_bytes
+      .and_then(|size| usize::try_from(size).ok());
+    entry.links.push(link);
+
+    report.unmapped_if(attachment.duration_in_seconds.is_some(), "attachments");
+  }
+
+  let content = match (item.content_html, item.content_text) {
+    (Some(html), _) => Some(XmlText::Html(html)),
+    (None, Some(text)) => Some(XmlText::PlainText(text)),
+    (None, None) => None,
+  };
+
+  entry.content = content.map(|text| Content::new(text.into()));
+  entry.summary = item
+    .summary
+    .map(|summary| Summary::new(XmlText::PlainText(summary)));
+  entry.published = item.date_published.map(Published::new);
+  entry.authors = authors(&item.authors, report);
+  entry.categories = item.tags.iter().map(|tag| Category::new(tag)).collect();
+
+  report.unmapped_if(
+    item.content_html.is_some() && item.content_text.is_some(),
+    "content_text",
+  );
+  report.unmapped_if(item.banner_image.is_some(), "banner_image");
+  report.unmapped_if(!item.extensions.is_empty(), "extensions");
+  report.unmapped_if(item.image.is_some(), "image");
+
+  entry
+}
+
+/// Atom requires author names, authors without one are dropped.
+#[cfg(feature = "json_feed")]
+fn authors<'b>(authors: &'b [json_feed::Author<'_>], report: &mut Report) -> Vec<Author<'b>> {
+  authors
+    .iter()
+    .filter_map(|author| {
+      report.unmapped_if(author.name.is_none() || author.avatar.is_some(), "authors");
+
+      author.name.map(|name| {
+        let mut value = Author::new(name);
+        value.uri = author.url;
+        value
+      })
+    })
+    .collect()
+}
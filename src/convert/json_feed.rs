@@ -0,0 +1,235 @@
+use crate::{
+  atom::{self, ContentValue, Entry},
+  common::{LinkRelation, XmlText},
+  error::ConvertError,
+  json_feed::{Attachment, Author, Item, JsonFeed},
+  rss::{self, Channel, RSS_DOCS},
+};
+
+use super::{get_language, text_value, Converted, Report};
+
+impl<'a: 'b, 'b> TryFrom<&'b atom::Feed<'a>> for Converted<JsonFeed<'b>> {
+  type Error = ConvertError;
+
+  /// Alternate link becomes the home page and `self` link the feed url. Logo becomes the icon
+  /// and icon the favicon. JSON Feed has no feed level id, date or categories.
+  fn try_from(source: &'b atom::Feed<'a>) -> Result<Self, Self::Error> {
+    let mut report = Report::default();
+
+    let title =
+      text_value(&source.title.value).ok_or(ConvertError::MissingField { name: "title" })?;
+
+    let mut feed = JsonFeed::new(title);
+    feed.language = get_language(&source.attributes);
+    feed.icon = source.logo.as_ref().map(|logo| logo.uri);
+    feed.favicon = source.icon.as_ref().map(|icon| icon.uri);
+    feed.authors = authors(&source.authors, &mut report);
+
+    for link in source.links.iter() {
+      match &link.rel {
+        None | Some(LinkRelation::Alternate) if feed.home_page_url.is_none() => {
+          feed.home_page_url = Some(link.href);
+        }
+        Some(LinkRelation::SelfRel) if feed.feed_url.is_none() => feed.feed_url = Some(link.href),
+        _ => report.unmapped("links"),
+      }
+    }
+
+    if let Some(subtitle) = source.subtitle.as_ref() {
+      feed.description = text_value(&subtitle.value);
+      report.unmapped_if(feed.description.is_none(), "subtitle");
+    }
+
+    let id = Some(source.id.value);
+    report.unmapped_if(id != feed.feed_url && id != feed.home_page_url, "id");
+    report.unmapped("updated");
+    report.unmapped_if(!source.categories.is_empty(), "categories");
+    report.unmapped_if(!source.contributors.is_empty(), "contributors");
+    report.unmapped_if(source.generator.is_some(), "generator");
+    report.unmapped_if(source.rights.is_some(), "rights");
+
+    for (index, entry) in source.entries.iter().enumerate() {
+      report.set_item(Some(index));
+      feed.items.push(atom_item(entry, &mut report));
+    }
+
+    Ok(report.finish(feed))
+  }
+}
+
+fn atom_item<'b>(entry: &'b Entry<'_>, report: &mut Report) -> Item<'b> {
+  let mut item = Item {
+    authors: authors(&entry.authors, report),
+    date_modified: Some(entry.updated.value),
+    date_published: entry.published.as_ref().map(|published| published.value),
+    language: get_language(&entry.attributes),
+    title: text_value(&entry.title.value),
+    ..Item::new(entry.id.value)
+  };
+
+  report.unmapped_if(item.title.is_none(), "title");
+
+  for link in entry.links.iter() {
+    match (&link.rel, link.link_type) {
+      (None | Some(LinkRelation::Alternate), _) if item.url.is_none() => {
+        item.url = Some(link.href);
+      }
+      (Some(LinkRelation::Related), _) if item.external_url.is_none() => {
+        item.external_url = Some(link.href);
+      }
+      (Some(LinkRelation::Enclosure), Some(media_type)) => {
+        let mut attachment = Attachment::new(link.href, media_type);
+        attachment.size_in_bytes = link.length.map(|length| length as u64);
+        attachment.title = link.title;
+        item.attachments.push(attachment);
+      }
+      _ => report.unmapped("links"),
+    }
+  }
+
+  match entry.content.as_ref().map(|content| &content.value) {
+    Some(ContentValue::TextContent { text }) => match *text {
+      XmlText::Html(value) | XmlText::Xhtml(value) => item.content_html = Some(value),
+      XmlText::PlainText(value) => item.content_text = Some(value),
+      XmlText::HtmlUnchecked(_) | XmlText::PlainTextUnchecked(_) => report.unmapped("content"),
+    },
+    Some(_) => report.unmapped("content"),
+    None => (),
+  }
+
+  if let Some(summary) = entry.summary.as_ref() {
+    item.summary = text_value(&summary.value);
+    report.unmapped_if(item.summary.is_none(), "summary");
+  }
+
+  item.tags = entry
+    .categories
+    .iter()
+    .map(|category| {
+      report.unmapped_if(
+        category.scheme.is_some() || category.label.is_some(),
+        "categories",
+      );
+      category.term
+    })
+    .collect();
+
+  report.unmapped_if(!entry.contributors.is_empty(), "contributors");
+  report.unmapped_if(entry.rights.is_some(), "rights");
+  report.unmapped_if(entry.source.is_some(), "source");
+
+  item
+}
+
+/// JSON Feed authors have no e-mail addresses.
+fn authors<'b>(authors: &'b [atom::Author<'_>], report: &mut Report) -> Vec<Author<'b>> {
+  authors
+    .iter()
+    .map(|author| {
+      report.unmapped_if(author.email.is_some(), "authors");
+
+      let mut value = Author::new(author.name);
+      value.url = author.uri;
+      value
+    })
+    .collect()
+}
+
+impl<'a: 'b, 'b> TryFrom<&'b Channel<'a>> for Converted<JsonFeed<'b>> {
+  type Error = ConvertError;
+
+  /// Channel link becomes the home page, managing editor the author and image the icon. Item
+  /// ids are taken from the guid or the item link.
+  fn try_from(channel: &'b Channel<'a>) -> Result<Self, Self::Error> {
+    let mut report = Report::default();
+
+    let mut feed = JsonFeed::new(channel.title);
+    feed.home_page_url = Some(channel.link);
+    feed.language = channel.language;
+    feed
+      .authors
+      .extend(channel.managing_editor.map(Author::new));
+
+    if !channel.description.is_empty() {
+      feed.description = Some(channel.description);
+    }
+
+    if let Some(image) = channel.image.as_ref() {
+      feed.icon = Some(image.url);
+      report.unmapped_if(
+        image.description.is_some() || image.height.is_some() || image.width.is_some(),
+        "image",
+      );
+    }
+
+    report.unmapped_if(!channel.categories.is_empty(), "categories");
+    report.unmapped_if(channel.cloud.is_some(), "cloud");
+    report.unmapped_if(channel.copyright.is_some(), "copyright");
+    report.unmapped_if(channel.docs.is_some_and(|docs| docs != RSS_DOCS), "docs");
+    report.unmapped_if(channel.generator.is_some(), "generator");
+    report.unmapped_if(channel.last_build_date.is_some(), "last_build_date");
+    report.unmapped_if(channel.pub_date.is_some(), "pub_date");
+    report.unmapped_if(channel.rating.is_some(), "rating");
+    report.unmapped_if(!channel.skip_days.is_empty(), "skip_days");
+    report.unmapped_if(!channel.skip_hours.is_empty(), "skip_hours");
+    report.unmapped_if(channel.text_input.is_some(), "text_input");
+    report.unmapped_if(channel.ttl.is_some(), "ttl");
+    report.unmapped_if(channel.web_master.is_some(), "web_master");
+
+    for (index, item) in channel.items.iter().enumerate() {
+      report.set_item(Some(index));
+      feed.items.push(rss_item(item, &mut report)?);
+    }
+
+    Ok(report.finish(feed))
+  }
+}
+
+fn rss_item<'b>(source: &'b rss::Item<'_>, report: &mut Report) -> Result<Item<'b>, ConvertError> {
+  let id = source
+    .guid
+    .as_ref()
+    .map(|guid| guid.value)
+    .or(source.link)
+    .ok_or(ConvertError::MissingField { name: "guid" })?;
+
+  let permalink = source
+    .guid
+    .as_ref()
+    .filter(|guid| guid.is_permalink)
+    .map(|guid| guid.value);
+
+  let mut item = Item {
+    content_html: source.description,
+    date_published: source.pub_date,
+    title: source.title,
+    url: source.link.or(permalink),
+    ..Item::new(id)
+  };
+
+  item.authors.extend(source.author.map(Author::new));
+  item.tags = source
+    .categories
+    .iter()
+    .map(|category| {
+      report.unmapped_if(category.domain.is_some(), "categories");
+      category.value
+    })
+    .collect();
+
+  if let Some(enclosure) = source.enclosure.as_ref() {
+    let mut attachment = Attachment::new(enclosure.url, enclosure.media_type);
+
+    // Zero length is commonly used for the unknown sizes.
+    if enclosure.length > 0 {
+      attachment.size_in_bytes = Some(enclosure.length);
+    }
+
+    item.attachments.push(attachment);
+  }
+
+  report.unmapped_if(source.comments.is_some(), "comments");
+  report.unmapped_if(source.source.is_some(), "source");
+
+  Ok(item)
+}
@@ -0,0 +1,238 @@
+use crate::{
+  atom::{self, ContentValue, Entry},
+  common::LinkRelation,
+  error::ConvertError,
+  rss::{Category, Channel, Enclosure, Guid, Image, Item},
+};
+
+#[cfg(feature = "json_feed")]
+use crate::json_feed::{self, JsonFeed};
+
+use super::{get_language, text_value, Converted, Report};
+
+impl<'a: 'b, 'b> TryFrom<&'b atom::Feed<'a>> for Converted<Channel<'b>> {
+  type Error = ConvertError;
+
+  /// Alternate link becomes the channel link and `updated` the last build date. Entry ids are
+  /// kept as guids, which are permalinks only when they match the entry link. Item publication
+  /// dates are taken from `published`, or from `updated` when it's missing.
+  fn try_from(feed: &'b atom::Feed<'a>) -> Result<Self, Self::Error> {
+    let mut report = Report::default();
+
+    let title =
+      text_value(&feed.title.value).ok_or(ConvertError::MissingField { name: "title" })?;
+    let link = feed
+      .links
+      .iter()
+      .find(|link| matches!(link.rel, None | Some(LinkRelation::Alternate)))
+      .ok_or(ConvertError::MissingField { name: "links" })?;
+
+    let description = match feed.subtitle.as_ref() {
+      Some(subtitle) => text_value(&subtitle.value),
+      None => Some(""),
+    };
+
+    report.unmapped_if(description.is_none(), "subtitle");
+
+    let mut channel = Channel::new(title, link.href, description.unwrap_or_default());
+    channel.categories = categories(&feed.categories, &mut report);
+    channel.language = get_language(&feed.attributes);
+    channel.last_build_date = Some(feed.updated.value);
+    channel.managing_editor = author_email(&feed.authors, &mut report);
+
+    if let Some(generator) = feed.generator.as_ref() {
+      channel.generator = Some(generator.value);
+      report.unmapped_if(
+        generator.uri.is_some() || generator.version.is_some(),
+        "generator",
+      );
+    }
+
+    if let Some(rights) = feed.rights.as_ref() {
+      channel.copyright = text_value(&rights.value);
+      report.unmapped_if(channel.copyright.is_none(), "rights");
+    }
+
+    if let Some(logo) = feed.logo.as_ref() {
+      channel.image = Some(Image::new(logo.uri, title, link.href));
+    }
+
+    report.unmapped_if(feed.id.value != link.href, "id");
+    report.unmapped_if(feed.icon.is_some(), "icon");
+    report.unmapped_if(!feed.contributors.is_empty(), "contributors");
+    report.unmapped_if(feed.links.len() > 1, "links");
+
+    for (index, entry) in feed.entries.iter().enumerate() {
+      report.set_item(Some(index));
+      channel.items.push(atom_item(entry, &mut report));
+    }
+
+    Ok(report.finish(channel))
+  }
+}
+
+fn atom_item<'b>(entry: &'b Entry<'_>, report: &mut Report) -> Item<'b> {
+  let mut item = Item {
+    author: author_email(&entry.authors, report),
+    categories: categories(&entry.categories, report),
+    pub_date: Some(
+      entry
+        .published
+        .as_ref()
+        .map_or(entry.updated.value, |published| published.value),
+    ),
+    title: text_value(&entry.title.value),
+    ..Item::default()
+  };
+
+  report.unmapped_if(item.title.is_none(), "title");
+
+  for link in entry.links.iter() {
+    match (&link.rel, link.link_type) {
+      (None | Some(LinkRelation::Alternate), _) if item.link.is_none() => {
+        item.link = Some(link.href);
+      }
+      (Some(LinkRelation::Enclosure), Some(media_type)) if item.enclosure.is_none() => {
+        let length = link.length.unwrap_or_default() as u64;
+        item.enclosure = Some(Enclosure::new(link.href, length, media_type));
+      }
+      _ => report.unmapped("links"),
+    }
+  }
+
+  let mut guid = Guid::new(entry.id.value);
+  guid.is_permalink = item.link == Some(entry.id.value);
+  item.guid = Some(guid);
+
+  // RSS has a single description, summary is preferred over the content.
+  let summary = entry
+    .summary
+    .as_ref()
+    .map(|summary| text_value(&summary.value));
+  let content = match entry.content.as_ref().map(|content| &content.value) {
+    Some(ContentValue::TextContent { text }) => text_value(text),
+    _ => None,
+  };
+
+  report.unmapped_if(summary == Some(None), "summary");
+  item.description = summary.flatten().or(content);
+  report.unmapped_if(
+    entry.content.is_some() && (content.is_none() || item.description != content),
+    "content",
+  );
+
+  report.unmapped_if(entry.published.is_some(), "updated");
+  report.unmapped_if(!entry.contributors.is_empty(), "contributors");
+  report.unmapped_if(entry.rights.is_some(), "rights");
+  report.unmapped_if(entry.source.is_some(), "source");
+
+  item
+}
+
+/// RSS keeps a single e-mail address, the author names are always lost.
+fn author_email<'b>(authors: &'b [atom::Author<'_>], report: &mut Report) -> Option<&'b str> {
+  report.unmapped_if(!authors.is_empty(), "authors");
+  authors.first().and_then(|author| author.email)
+}
+
+fn categories<'b>(categories: &'b [atom::Category<'_>], report: &mut Report) -> Vec<Category<'b>> {
+  categories
+    .iter()
+    .map(|category| {
+      report.unmapped_if(category.label.is_some(), "categories");
+
+      let mut value = Category::new(category.term);
+      value.domain = category.scheme;
+      value
+    })
+    .collect()
+}
+
+#[cfg(feature = "json_feed")]
+impl<'a: 'b, 'b> TryFrom<&'b JsonFeed<'a>> for Converted<Channel<'b>> {
+  type Error = ConvertError;
+
+  /// Home page becomes the channel link and icon the channel image. Item ids are kept as
+  /// guids, which are permalinks only when they match the item url.
+  fn try_from(feed: &'b JsonFeed<'a>) -> Result<Self, Self::Error> {
+    let mut report = Report::default();
+
+    let link = feed.home_page_url.ok_or(ConvertError::MissingField {
+      name: "home_page_url",
+    })?;
+
+    let mut channel = Channel::new(feed.title, link, feed.description.unwrap_or_default());
+    channel.language = feed.language;
+    channel.image = feed.icon.map(|icon| Image::new(icon, feed.title, link));
+
+    report.unmapped_if(!feed.authors.is_empty(), "authors");
+    report.unmapped_if(feed.expired.is_some(), "expired");
+    report.unmapped_if(!feed.extensions.is_empty(), "extensions");
+    report.unmapped_if(feed.favicon.is_some(), "favicon");
+    report.unmapped_if(feed.feed_url.is_some(), "feed_url");
+    report.unmapped_if(!feed.hubs.is_empty(), "hubs");
+    report.unmapped_if(feed.next_url.is_some(), "next_url");
+    report.unmapped_if(feed.user_comment.is_some(), "user_comment");
+
+    for (index, item) in feed.items.iter().enumerate() {
+      report.set_item(Some(index));
+      channel.items.push(json_item(item, &mut report));
+    }
+
+    Ok(report.finish(channel))
+  }
+}
+
+#[cfg(feature = "json_feed")]
+fn json_item<'b>(source: &'b json_feed::Item<'_>, report: &mut Report) -> Item<'b> {
+  let mut guid = Guid::new(source.id);
+  guid.is_permalink = source.url == Some(source.id);
+
+  let mut item = Item {
+    categories: source.tags.iter().map(|tag| Category::new(tag)).collect(),
+    guid: Some(guid),
+    link: source.url,
+    pub_date: source.date_published.or(source.date_modified),
+    title: source.title,
+    ..Item::default()
+  };
+
+  // RSS has a single description, HTML content is preferred over the others.
+  item.description = source
+    .content_html
+    .or(source.content_text)
+    .or(source.summary);
+
+  report.unmapped_if(
+    source.content_text.is_some() && item.description != source.content_text,
+    "content_text",
+  );
+  report.unmapped_if(
+    source.summary.is_some() && item.description != source.summary,
+    "summary",
+  );
+  report.unmapped_if(
+    source.date_published.is_some() && source.date_modified.is_some(),
+    "date_modified",
+  );
+
+  if let Some(attachment) = source.attachments.first() {
+    let length = attachment.size_in_bytes.unwrap_or_default();
+    item.enclosure = Some(Enclosure::new(attachment.url, length, attachment.mime_type));
+    report.unmapped_if(
+      source.attachments.len() > 1
+        || attachment.title.is_some()
+        || attachment.duration_in_seconds.is_some(),
+      "attachments",
+    );
+  }
+
+  report.unmapped_if(!source.authors.is_empty(), "authors");
+  report.unmapped_if(source.banner_image.is_some(), "banner_image");
+  report.unmapped_if(!source.extensions.is_empty(), "extensions");
+  report.unmapped_if(source.external_url.is_some(), "external_url");
+  report.unmapped_if(source.image.is_some(), "image");
+  report.unmapped_if(source.language.is_some(), "language");
+
+  item
+}
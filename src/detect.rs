@@ -0,0 +1,249 @@
+//! Feed format detection from raw document bytes.
+
+use crate::{
+  atom::ATOM_NAMESPACE,
+  rss1::{RDF_NAMESPACE, RSS090_NAMESPACE, RSS1_NAMESPACE},
+};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Feed format of a document, see [detect].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedFormat {
+  /// Atom 1.0, read with [crate::atom::parse].
+  Atom,
+  /// RSS 0.90 to 0.94. RSS 0.90 is RDF based and read with [crate::rss1::parse], the later
+  /// versions with [crate::rss::parse].
+  Rss09x,
+  /// RSS 1.0 (RDF Site Summary), read with [crate::rss1::parse].
+  Rss1,
+  /// RSS 2.0, read with [crate::rss::parse]. `rss` documents without a version are reported as
+  /// RSS 2.0 as well.
+  Rss2,
+  /// JSON Feed 1.0 or 1.1, read with `json_feed::parse`. Only detected with the `json_feed`
+  /// feature.
+  JsonFeed,
+  Unknown,
+}
+
+/// Detects the feed format from the root element name, its namespace and `version` attribute,
+/// or from the `version` member of a JSON object.
+///
+/// Input is not validated beyond the root element, a detected format doesn't mean that the
+/// document parses. XML documents must use UTF-8 or an ASCII compatible encoding, JSON
+/// documents must be UTF-8.
+///
+/// ```
+/// use syndication_format::detect::{detect, FeedFormat};
+///
+/// let input = br#"<?xml version="1.0" encoding="utf-8"?>
+/// <!-- Served as text/html -->
+/// <feed xmlns="http://www.w3.org/2005/Atom"><title>Example</title></feed>"#;
+///
+/// assert_eq!(FeedFormat::Atom, detect(input));
+/// assert_eq!(FeedFormat::Unknown, detect(b"<!DOCTYPE html><html></html>"));
+/// ```
+pub fn detect(input: &[u8]) -> FeedFormat {
+  let input = input.strip_prefix(UTF8_BOM).unwrap_or(input);
+
+  match input.iter().find(|c| !c.is_ascii_whitespace()) {
+    Some(b'<') => detect_xml(input),
+    Some(b'{') => detect_json(input),
+    _ => FeedFormat::Unknown,
+  }
+}
+
+/// Root element name and the namespace declarations relevant to the detection.
+#[derive(Default)]
+struct RootElement<'a> {
+  prefix: &'a [u8],
+  local_name: &'a [u8],
+  namespace: Option<&'a [u8]>,
+  version: Option<&'a [u8]>,
+  has_rss090: bool,
+  has_rss1: bool,
+}
+
+impl RootElement<'_> {
+  fn format(&self) -> FeedFormat {
+    match (self.local_name, self.namespace) {
+      (b"feed", Some(namespace)) if namespace == ATOM_NAMESPACE.as_bytes() => FeedFormat::Atom,
+      (b"rss", None) => match self.version.map(|version| version.trim_ascii()) {
+        Some(version) if version.starts_with(b"0.9") => FeedFormat::Rss09x,
+        _ => FeedFormat::Rss2,
+      },
+      (b"RDF", Some(namespace)) if namespace == RDF_NAMESPACE.as_bytes() => {
+        if self.has_rss1 {
+          FeedFormat::Rss1
+        } else if self.has_rss090 {
+          FeedFormat::Rss09x
+        } else {
+          FeedFormat::Unknown
+        }
+      }
+      _ => FeedFormat::Unknown,
+    }
+  }
+}
+
+fn detect_xml(input: &[u8]) -> FeedFormat {
+  let mut position = 0;
+
+  // Skips the XML declaration, processing instructions, comments and the doctype.
+  loop {
+    position += input[position..]
+      .iter()
+      .take_while(|c| c.is_ascii_whitespace())
+      .count();
+
+    let remaining = &input[position..];
+
+    let skipped = if remaining.starts_with(b"<?") {
+      find(remaining, b"?>").map(|end| end + 2)
+    } else if remaining.starts_with(b"<!--") {
+      find(remaining, b"-->").map(|end| end + 3)
+    } else if remaining.starts_with(b"<!") {
+      doctype_length(remaining)
+    } else if remaining.starts_with(b"<") {
+      break;
+    } else {
+      None
+    };
+
+    match skipped {
+      Some(length) => position += length,
+      None => return FeedFormat::Unknown,
+    }
+  }
+
+  match root_element(&input[position + 1..]) {
+    Some(root) => root.format(),
+    None => FeedFormat::Unknown,
+  }
+}
+
+#[inline]
+fn find(input: &[u8], value: &[u8]) -> Option<usize> {
+  input
+    .windows(value.len())
+    .position(|window| window == value)
+}
+
+/// Length of the doctype declaration, including the internal subset.
+fn doctype_length(input: &[u8]) -> Option<usize> {
+  let mut quote = None;
+  let mut depth = 0usize;
+
+  for (idx, c) in input.iter().enumerate() {
+    match (quote, c) {
+      (Some(quote_char), c) if quote_char == *c => quote = None,
+      (Some(_), _) => (),
+      (None, b'"' | b'\'') => quote = Some(*c),
+      (None, b'[') => depth += 1,
+      (None, b']') => depth = depth.saturating_sub(1),
+      (None, b'>') if depth == 0 => return Some(idx + 1),
+      _ => (),
+    }
+  }
+
+  None
+}
+
+/// Reads the root element name and attributes, input starts after `<`.
+fn root_element(input: &[u8]) -> Option<RootElement<'_>> {
+  let is_delimiter = |c: &u8| c.is_ascii_whitespace() || matches!(c, b'/' | b'>' | b'=');
+
+  let name_length = input.iter().position(is_delimiter)?;
+  let (name, mut remaining) = input.split_at(name_length);
+
+  let mut root = RootElement::default();
+
+  match name.iter().position(|c| *c == b':') {
+    Some(idx) => {
+      root.prefix = &name[..idx];
+      root.local_name = &name[idx + 1..];
+    }
+    None => root.local_name = name,
+  }
+
+  loop {
+    remaining = remaining.trim_ascii_start();
+
+    match remaining.first()? {
+      b'/' | b'>' => break,
+      _ => (),
+    }
+
+    let name_length = remaining.iter().position(is_delimiter)?;
+    let (name, rest) = remaining.split_at(name_length);
+    let rest = rest
+      .trim_ascii_start()
+      .strip_prefix(b"=")?
+      .trim_ascii_start();
+
+    let quote = *rest.first()?;
+
+    if !matches!(quote, b'"' | b'\'') {
+      return None;
+    }
+
+    let value_length = rest[1..].iter().position(|c| *c == quote)?;
+    let value = &rest[1..value_length + 1];
+    remaining = &rest[value_length + 2..];
+
+    let declared_prefix = match name {
+      b"xmlns" => Some(&b""[..]),
+      _ => name.strip_prefix(b"xmlns:"),
+    };
+
+    match declared_prefix {
+      Some(prefix) => {
+        if prefix == root.prefix {
+          root.namespace = Some(value);
+        }
+
+        root.has_rss1 |= value == RSS1_NAMESPACE.as_bytes();
+        root.has_rss090 |= value == RSS090_NAMESPACE.as_bytes();
+      }
+      None if name == b"version" => root.version = Some(value),
+      None => (),
+    }
+  }
+
+  Some(root)
+}
+
+#[cfg(feature = "json_feed")]
+fn detect_json(input: &[u8]) -> FeedFormat {
+  use crate::json_feed::{reader::JsonReader, VERSION_PREFIX};
+
+  let Ok(input) = core::str::from_utf8(input) else {
+    return FeedFormat::Unknown;
+  };
+
+  let mut reader = JsonReader::new(input);
+
+  let Ok(mut object) = reader.begin_object("feed") else {
+    return FeedFormat::Unknown;
+  };
+
+  while let Ok(Some(key)) = reader.next_key(&mut object) {
+    if key == "version" {
+      return match reader.read_string("version") {
+        Ok(version) if version.starts_with(VERSION_PREFIX) => FeedFormat::JsonFeed,
+        _ => FeedFormat::Unknown,
+      };
+    }
+
+    if reader.skip_value().is_err() {
+      break;
+    }
+  }
+
+  FeedFormat::Unknown
+}
+
+#[cfg(not(feature = "json_feed"))]
+fn detect_json(_input: &[u8]) -> FeedFormat {
+  FeedFormat::Unknown
+}
@@ -1,8 +1,16 @@
 #[derive(Debug)]
 pub enum XmlSerializeError {
-  IOError { inner: Box<std::io::Error> },
+  IOError {
+    inner: Box<std::io::Error>,
+  },
   InvalidAttributeName,
   InvalidNamespace,
+  /// Namespace prefix is bound to different URIs.
+  ConflictingNamespace {
+    prefix: Box<str>,
+  },
+  /// Inlined XML or `text/*` content is not valid UTF-8.
+  InvalidContent,
 }
 
 impl From<std::io::Error> for XmlSerializeError {
@@ -22,6 +30,10 @@ impl From<InvalidAttributeName> for XmlSerializeError {
   }
 }
 
+/// Link relation is neither a registered relation name nor an absolute IRI.
+#[derive(Debug)]
+pub struct InvalidLinkRelation;
+
 #[derive(Debug)]
 pub enum TimeStampFormatErrors {
   InvalidFormat,
@@ -31,3 +43,177 @@ pub enum TimeStampFormatErrors {
   InvalidDate,
   InvalidTimeOffset,
 }
+
+#[derive(Debug)]
+pub enum XmlParseError {
+  IOError {
+    inner: Box<std::io::Error>,
+  },
+  InvalidEncoding,
+  UnexpectedEof,
+  InvalidSyntax {
+    position: usize,
+  },
+  InvalidName {
+    position: usize,
+  },
+  InvalidEntity,
+  MismatchedEndTag {
+    position: usize,
+  },
+  UnboundPrefix {
+    position: usize,
+  },
+  UnexpectedElement {
+    position: usize,
+  },
+  MissingElement {
+    name: &'static str,
+  },
+  MissingAttribute {
+    name: &'static str,
+  },
+  InvalidAttributeName,
+  InvalidTimeStamp {
+    inner: TimeStampFormatErrors,
+  },
+  /// Content with a non-textual media type is not valid Base64.
+  InvalidBase64 {
+    position: usize,
+  },
+}
+
+impl From<std::io::Error> for XmlParseError {
+  fn from(value: std::io::Error) -> Self {
+    Self::IOError {
+      inner: Box::new(value),
+    }
+  }
+}
+
+impl From<core::str::Utf8Error> for XmlParseError {
+  fn from(_: core::str::Utf8Error) -> Self {
+    Self::InvalidEncoding
+  }
+}
+
+impl From<InvalidAttributeName> for XmlParseError {
+  fn from(_: InvalidAttributeName) -> Self {
+    Self::InvalidAttributeName
+  }
+}
+
+impl From<TimeStampFormatErrors> for XmlParseError {
+  fn from(value: TimeStampFormatErrors) -> Self {
+    Self::InvalidTimeStamp { inner: value }
+  }
+}
+
+/// Recoverable problems found by the lenient parsers. Positions are byte offsets in the input.
+#[derive(Debug)]
+pub enum ParseWarning {
+  /// Required element is missing, an empty value is used instead. Position points to the parent.
+  MissingElement { name: &'static str, position: usize },
+  /// Required attribute is missing, an empty value is used instead.
+  MissingAttribute { name: &'static str, position: usize },
+  /// Value cannot be parsed (e.g. a number or a day name). The value is ignored.
+  InvalidValue { name: &'static str, position: usize },
+  /// Date is in neither RFC 822 nor RFC 3339 format and no lenient date heuristic recognised it.
+  /// The value is ignored.
+  InvalidTimeStamp {
+    name: &'static str,
+    position: usize,
+    inner: TimeStampFormatErrors,
+  },
+  /// Date is in neither RFC 822 nor RFC 3339 format, but it was recognised by a lenient date
+  /// heuristic. The value is kept, with the default time zone when `assumed_time_zone` is set.
+  RecoveredTimeStamp {
+    name: &'static str,
+    position: usize,
+    heuristic: crate::common::DateHeuristic,
+    assumed_time_zone: bool,
+  },
+  /// Text contains an undefined or malformed entity reference, which is kept as is.
+  InvalidEntity { position: usize },
+  /// Text contains unescaped markup, which is kept as is.
+  MarkupInText { name: &'static str, position: usize },
+  /// Document version is missing or unknown.
+  UnsupportedVersion { position: usize },
+}
+
+#[derive(Debug)]
+pub enum JsonSerializeError {
+  IOError {
+    inner: Box<std::io::Error>,
+  },
+  /// Extension name doesn't start with `_` or its value is not a valid JSON value.
+  InvalidExtension,
+  /// Number cannot be represented in JSON, e.g. NaN or infinity.
+  InvalidNumber,
+}
+
+impl From<std::io::Error> for JsonSerializeError {
+  fn from(value: std::io::Error) -> Self {
+    Self::IOError {
+      inner: Box::new(value),
+    }
+  }
+}
+
+impl From<XmlSerializeError> for JsonSerializeError {
+  fn from(value: XmlSerializeError) -> Self {
+    match value {
+      XmlSerializeError::IOError { inner } => Self::IOError { inner },
+      other => Self::IOError {
+        inner: Box::new(std::io::Error::other(format!("{:?}", other))),
+      },
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum JsonParseError {
+  InvalidEncoding,
+  UnexpectedEof,
+  InvalidSyntax {
+    position: usize,
+  },
+  InvalidEscape {
+    position: usize,
+  },
+  /// Field value has an unexpected JSON type.
+  InvalidType {
+    name: &'static str,
+    position: usize,
+  },
+  InvalidNumber {
+    name: &'static str,
+    position: usize,
+  },
+  MissingField {
+    name: &'static str,
+  },
+  UnsupportedVersion,
+  InvalidTimeStamp {
+    inner: TimeStampFormatErrors,
+  },
+}
+
+impl From<core::str::Utf8Error> for JsonParseError {
+  fn from(_: core::str::Utf8Error) -> Self {
+    Self::InvalidEncoding
+  }
+}
+
+impl From<TimeStampFormatErrors> for JsonParseError {
+  fn from(value: TimeStampFormatErrors) -> Self {
+    Self::InvalidTimeStamp { inner: value }
+  }
+}
+
+#[derive(Debug)]
+pub enum ConvertError {
+  /// Target format requires a value which the source document doesn't have. Text stored as
+  /// already escaped markup (`*Unchecked` [crate::common::XmlText] variants) counts as missing.
+  MissingField { name: &'static str },
+}
@@ -1,11 +1,11 @@
 use crate::{
-  error::XmlSerializeError,
+  error::{XmlParseError, XmlSerializeError},
   serializer::Write,
   utils::byte_search::{swar_search::SwarByteSearchIter, BasicByteSearch, BytePosition},
 };
 
-pub const XML_ESCAPE_PATTERNS: &[u8; 5] = &[b'<', b'>', b'&', b'\'', b'"'];
-pub const XML_ATTR_ESCAPE_PATTERNS: &[u8; 4] = &[b'<', b'>', b'&', b'"'];
+pub const XML_ESCAPE_PATTERNS: &[u8; 5] = b"<>&'\"";
+pub const XML_ATTR_ESCAPE_PATTERNS: &[u8; 4] = b"<>&\"";
 
 static ESCAPE_LOOKUP_TABLE: [Option<&&str>; 256] = {
   let mut table = [None; 256];
@@ -52,7 +52,7 @@ where
       last = index + 1;
     }
 
-    if last < (input.len() - 1) {
+    if last < input.len() {
       escaped_input.push_str(&input[last..]);
     }
 
@@ -99,7 +99,7 @@ where
       last = index + 1;
     }
 
-    if last < (input.len() - 1) {
+    if last < input.len() {
       writer.write(&input[last..])?;
     }
 
@@ -271,6 +271,112 @@ pub fn escape<'a>(input: &'a str, search_bytes: &'a [u8]) -> std::borrow::Cow<'a
   }
 }
 
+/// Replaces predefined XML entities and character references with their actual characters.
+/// Returns the input as is when there is nothing to decode.
+#[cfg(feature = "std")]
+pub fn unescape(input: &str) -> Result<std::borrow::Cow<'_, str>, XmlParseError> {
+  unescape_with(input, true)
+}
+
+/// Same as [unescape], but keeps unknown or malformed entity references (e.g. HTML entities like
+/// `&nbsp;` or a bare `&`) verbatim instead of failing.
+#[cfg(feature = "std")]
+pub fn unescape_lenient(input: &str) -> std::borrow::Cow<'_, str> {
+  match unescape_with(input, false) {
+    Ok(value) => value,
+    Err(_) => unreachable!("lenient unescape never fails"),
+  }
+}
+
+#[cfg(feature = "std")]
+fn unescape_with(input: &str, strict: bool) -> Result<std::borrow::Cow<'_, str>, XmlParseError> {
+  let Some(first) = input.find('&') else {
+    return Ok(std::borrow::Cow::Borrowed(input));
+  };
+
+  let mut unescaped = String::with_capacity(input.len());
+  let mut remaining = &input[first..];
+  unescaped.push_str(&input[..first]);
+
+  while let Some(start) = remaining.find('&') {
+    unescaped.push_str(&remaining[..start]);
+
+    let value = remaining[start..].find(';').and_then(|end| {
+      let entity = &remaining[(start + 1)..(start + end)];
+      let value = match entity {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "apos" => '\'',
+        "quot" => '"',
+        _ => {
+          let code_point = match entity.as_bytes() {
+            // Digits are checked upfront, since parsing accepts a leading `+` sign.
+            [b'#', b'x', hex @ ..] if !hex.is_empty() && hex.iter().all(u8::is_ascii_hexdigit) => {
+              u32::from_str_radix(&entity[2..], 16)
+            }
+            [b'#', dec @ ..] if !dec.is_empty() && dec.iter().all(u8::is_ascii_digit) => {
+              entity[1..].parse::<u32>()
+            }
+            _ => return None,
+          };
+
+          code_point.ok().and_then(char::from_u32)?
+        }
+      };
+
+      Some((value, end))
+    });
+
+    match value {
+      Some((value, end)) => {
+        unescaped.push(value);
+        remaining = &remaining[(start + end + 1)..];
+      }
+      None if strict => return Err(XmlParseError::InvalidEntity),
+      None => {
+        unescaped.push('&');
+        remaining = &remaining[(start + 1)..];
+      }
+    }
+  }
+
+  unescaped.push_str(remaining);
+
+  Ok(std::borrow::Cow::Owned(unescaped))
+}
+
+/// Escapes quotation marks, reverse solidus and control characters for JSON strings. Returns the
+/// input as is when there is nothing to escape.
+#[cfg(feature = "std")]
+pub fn escape_json(input: &str) -> std::borrow::Cow<'_, str> {
+  let Some(first) = input
+    .bytes()
+    .position(|c| c < 0x20 || c == b'"' || c == b'\\')
+  else {
+    return std::borrow::Cow::Borrowed(input);
+  };
+
+  let mut escaped = String::with_capacity(input.len() + 8);
+  escaped.push_str(&input[..first]);
+
+  for c in input[first..].chars() {
+    match c {
+      '"' => escaped.push_str("\\\""),
+      '\\' => escaped.push_str("\\\\"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      '\u{08}' => escaped.push_str("\\b"),
+      '\u{0C}' => escaped.push_str("\\f"),
+      c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+      c => escaped.push(c),
+    }
+  }
+
+  std::borrow::Cow::Owned(escaped)
+}
+
 #[cfg(feature = "std")]
 #[macro_export]
 macro_rules! escape_xml {
@@ -0,0 +1,12 @@
+mod feed;
+mod item;
+mod metadata;
+mod parser;
+pub(crate) mod reader;
+mod serializer;
+
+pub use feed::*;
+pub use item::*;
+pub use metadata::*;
+pub use parser::*;
+pub use serializer::*;
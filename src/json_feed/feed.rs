@@ -0,0 +1,45 @@
+use super::{Author, Extension, Hub, Item};
+
+pub const JSON_FEED_VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+/// [JSON Feed 1.1](https://www.jsonfeed.org/version/1.1/) top-level object. Serialize it with
+/// [super::JsonSerializer], the version field is always written as 1.1.
+pub struct JsonFeed<'a> {
+  pub authors: Vec<Author<'a>>,
+  pub description: Option<&'a str>,
+  pub expired: Option<bool>,
+  /// Custom objects, keys must start with `_`.
+  pub extensions: Vec<Extension<'a>>,
+  pub favicon: Option<&'a str>,
+  pub feed_url: Option<&'a str>,
+  pub home_page_url: Option<&'a str>,
+  pub hubs: Vec<Hub<'a>>,
+  pub icon: Option<&'a str>,
+  pub items: Vec<Item<'a>>,
+  pub language: Option<&'a str>,
+  /// URL of the next page for paginated feeds.
+  pub next_url: Option<&'a str>,
+  pub title: &'a str,
+  pub user_comment: Option<&'a str>,
+}
+
+impl<'a> JsonFeed<'a> {
+  pub fn new(title: &'a str) -> Self {
+    Self {
+      authors: Vec::default(),
+      description: None,
+      expired: None,
+      extensions: Vec::default(),
+      favicon: None,
+      feed_url: None,
+      home_page_url: None,
+      hubs: Vec::default(),
+      icon: None,
+      items: Vec::default(),
+      language: None,
+      next_url: None,
+      title,
+      user_comment: None,
+    }
+  }
+}
@@ -0,0 +1,35 @@
+use crate::common::TimeStamp;
+
+use super::{Attachment, Author, Extension};
+
+/// JSON Feed item. Only `id` is required, but at least one of `content_html` or `content_text`
+/// should be present.
+#[derive(Default)]
+pub struct Item<'a> {
+  pub attachments: Vec<Attachment<'a>>,
+  pub authors: Vec<Author<'a>>,
+  pub banner_image: Option<&'a str>,
+  pub content_html: Option<&'a str>,
+  pub content_text: Option<&'a str>,
+  pub date_modified: Option<TimeStamp>,
+  pub date_published: Option<TimeStamp>,
+  /// Custom objects, keys must start with `_`.
+  pub extensions: Vec<Extension<'a>>,
+  pub external_url: Option<&'a str>,
+  pub id: &'a str,
+  pub image: Option<&'a str>,
+  pub language: Option<&'a str>,
+  pub summary: Option<&'a str>,
+  pub tags: Vec<&'a str>,
+  pub title: Option<&'a str>,
+  pub url: Option<&'a str>,
+}
+
+impl<'a> Item<'a> {
+  pub fn new(id: &'a str) -> Self {
+    Self {
+      id,
+      ..Self::default()
+    }
+  }
+}
@@ -0,0 +1,61 @@
+/// Author object. All fields are optional, but at least one of them should be present.
+#[derive(Default)]
+pub struct Author<'a> {
+  pub avatar: Option<&'a str>,
+  pub name: Option<&'a str>,
+  pub url: Option<&'a str>,
+}
+
+impl<'a> Author<'a> {
+  pub fn new(name: &'a str) -> Self {
+    Self {
+      name: Some(name),
+      ..Self::default()
+    }
+  }
+}
+
+pub struct Attachment<'a> {
+  pub duration_in_seconds: Option<f64>,
+  pub mime_type: &'a str,
+  pub size_in_bytes: Option<u64>,
+  pub title: Option<&'a str>,
+  pub url: &'a str,
+}
+
+impl<'a> Attachment<'a> {
+  pub fn new(url: &'a str, mime_type: &'a str) -> Self {
+    Self {
+      duration_in_seconds: None,
+      mime_type,
+      size_in_bytes: None,
+      title: None,
+      url,
+    }
+  }
+}
+
+/// Real-time notification endpoint, e.g. WebSub.
+pub struct Hub<'a> {
+  pub hub_type: &'a str,
+  pub url: &'a str,
+}
+
+impl<'a> Hub<'a> {
+  pub fn new(hub_type: &'a str, url: &'a str) -> Self {
+    Self { hub_type, url }
+  }
+}
+
+/// Custom object like `"_blue_shed": { "about": "..." }`. Name includes the leading `_` and value
+/// is the raw JSON text of the object, which is written as is.
+pub struct Extension<'a> {
+  pub name: &'a str,
+  pub value: &'a str,
+}
+
+impl<'a> Extension<'a> {
+  pub fn new(name: &'a str, value: &'a str) -> Self {
+    Self { name, value }
+  }
+}
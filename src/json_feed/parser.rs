@@ -0,0 +1,300 @@
+use core::str::FromStr;
+
+use crate::{common::TimeStamp, error::JsonParseError, reader::StringArena};
+
+use super::{
+  reader::JsonReader, Attachment, Author, Extension, Hub, Item, JsonFeed, JSON_FEED_VERSION,
+};
+
+/// Prefix shared by the JSON Feed 1.0 and 1.1 version URLs.
+pub(crate) const VERSION_PREFIX: &str = "https://jsonfeed.org/version/1";
+
+/// Parses a JSON Feed 1.0 or 1.1 document.
+///
+/// Strings are borrowed from `input` unless they contain escape sequences, those are decoded
+/// into `arena`. Unknown members are ignored and `_` prefixed members are kept as
+/// [Extension]s with their raw JSON text. JSON Feed 1.0 `author` objects are mapped to
+/// `authors`.
+///
+/// ```
+/// use syndication_format::{json_feed, reader::StringArena};
+///
+/// let input = r#"{
+///   "version": "https://jsonfeed.org/version/1.1",
+///   "title": "My Example Feed",
+///   "items": [{ "id": "2", "content_text": "This is a second item." }]
+/// }"#;
+///
+/// let arena = StringArena::new();
+/// let feed = json_feed::parse(input, &arena).unwrap();
+///
+/// assert_eq!("My Example Feed", feed.title);
+/// assert_eq!("2", feed.items[0].id);
+/// ```
+pub fn parse<'a>(input: &'a str, arena: &'a StringArena) -> Result<JsonFeed<'a>, JsonParseError> {
+  let mut parser = JsonFeedParser {
+    reader: JsonReader::new(input),
+    arena,
+  };
+
+  let feed = parser.feed()?;
+  parser.reader.finish()?;
+
+  Ok(feed)
+}
+
+struct JsonFeedParser<'a> {
+  reader: JsonReader<'a>,
+  arena: &'a StringArena,
+}
+
+impl<'a> JsonFeedParser<'a> {
+  /// Reads a string member, `null` is treated as a missing value.
+  fn string(&mut self, name: &'static str) -> Result<Option<&'a str>, JsonParseError> {
+    if self.reader.read_null()? {
+      return Ok(None);
+    }
+
+    let value = self.reader.read_string(name)?;
+    Ok(Some(self.arena.store(value)))
+  }
+
+  fn timestamp(&mut self, name: &'static str) -> Result<Option<TimeStamp>, JsonParseError> {
+    match self.string(name)? {
+      Some(value) => Ok(Some(TimeStamp::from_str(value.trim())?)),
+      None => Ok(None),
+    }
+  }
+
+  fn number<T: FromStr>(&mut self, name: &'static str) -> Result<Option<T>, JsonParseError> {
+    if self.reader.read_null()? {
+      return Ok(None);
+    }
+
+    let position = self.reader.position();
+    let value = self.reader.read_number(name)?;
+
+    match value.parse() {
+      Ok(value) => Ok(Some(value)),
+      Err(_) => Err(JsonParseError::InvalidNumber { name, position }),
+    }
+  }
+
+  fn bool(&mut self, name: &'static str) -> Result<Option<bool>, JsonParseError> {
+    if self.reader.read_null()? {
+      return Ok(None);
+    }
+
+    Ok(Some(self.reader.read_bool(name)?))
+  }
+
+  /// Reads an array with the given element reader, `null` is treated as an empty array.
+  fn list<T, F>(&mut self, name: &'static str, mut read: F) -> Result<Vec<T>, JsonParseError>
+  where
+    F: FnMut(&mut Self) -> Result<T, JsonParseError>,
+  {
+    let mut values = Vec::new();
+
+    if self.reader.read_null()? {
+      return Ok(values);
+    }
+
+    let mut array = self.reader.begin_array(name)?;
+
+    while self.reader.next_element(&mut array)? {
+      values.push(read(self)?);
+    }
+
+    Ok(values)
+  }
+
+  fn extension(&mut self, name: &str) -> Result<Extension<'a>, JsonParseError> {
+    let name = self.arena.alloc(name.to_owned());
+    let value = self.reader.skip_value()?;
+
+    Ok(Extension::new(name, value))
+  }
+
+  fn feed(&mut self) -> Result<JsonFeed<'a>, JsonParseError> {
+    let mut feed = JsonFeed::new("");
+    let mut legacy_author = None;
+    let mut items = None;
+    let mut title = None;
+    let mut version = None;
+
+    let mut object = self.reader.begin_object("feed")?;
+
+    while let Some(key) = self.reader.next_key(&mut object)? {
+      match key.as_ref() {
+        "version" => version = self.string("version")?,
+        "title" => title = self.string("title")?,
+        "home_page_url" => feed.home_page_url = self.string("home_page_url")?,
+        "feed_url" => feed.feed_url = self.string("feed_url")?,
+        "description" => feed.description = self.string("description")?,
+        "user_comment" => feed.user_comment = self.string("user_comment")?,
+        "next_url" => feed.next_url = self.string("next_url")?,
+        "icon" => feed.icon = self.string("icon")?,
+        "favicon" => feed.favicon = self.string("favicon")?,
+        "author" => legacy_author = self.author()?,
+        "authors" => feed.authors = self.authors()?,
+        "language" => feed.language = self.string("language")?,
+        "expired" => feed.expired = self.bool("expired")?,
+        "hubs" => feed.hubs = self.list("hubs", Self::hub)?,
+        "items" => items = Some(self.list("items", Self::item)?),
+        name if name.starts_with('_') => feed.extensions.push(self.extension(name)?),
+        _ => {
+          self.reader.skip_value()?;
+        }
+      }
+    }
+
+    let version = version.ok_or(JsonParseError::MissingField { name: "version" })?;
+
+    if version != JSON_FEED_VERSION && !version.starts_with(VERSION_PREFIX) {
+      return Err(JsonParseError::UnsupportedVersion);
+    }
+
+    feed.title = title.ok_or(JsonParseError::MissingField { name: "title" })?;
+    feed.items = items.ok_or(JsonParseError::MissingField { name: "items" })?;
+
+    if feed.authors.is_empty() {
+      feed.authors.extend(legacy_author);
+    }
+
+    Ok(feed)
+  }
+
+  fn authors(&mut self) -> Result<Vec<Author<'a>>, JsonParseError> {
+    let authors = self.list("authors", Self::author)?;
+    Ok(authors.into_iter().flatten().collect())
+  }
+
+  fn author(&mut self) -> Result<Option<Author<'a>>, JsonParseError> {
+    if self.reader.read_null()? {
+      return Ok(None);
+    }
+
+    let mut author = Author::default();
+    let mut object = self.reader.begin_object("author")?;
+
+    while let Some(key) = self.reader.next_key(&mut object)? {
+      match key.as_ref() {
+        "name" => author.name = self.string("name")?,
+        "url" => author.url = self.string("url")?,
+        "avatar" => author.avatar = self.string("avatar")?,
+        _ => {
+          self.reader.skip_value()?;
+        }
+      }
+    }
+
+    Ok(Some(author))
+  }
+
+  fn hub(&mut self) -> Result<Hub<'a>, JsonParseError> {
+    let mut hub_type = None;
+    let mut url = None;
+    let mut object = self.reader.begin_object("hubs")?;
+
+    while let Some(key) = self.reader.next_key(&mut object)? {
+      match key.as_ref() {
+        "type" => hub_type = self.string("type")?,
+        "url" => url = self.string("url")?,
+        _ => {
+          self.reader.skip_value()?;
+        }
+      }
+    }
+
+    Ok(Hub::new(
+      hub_type.ok_or(JsonParseError::MissingField { name: "type" })?,
+      url.ok_or(JsonParseError::MissingField { name: "url" })?,
+    ))
+  }
+
+  /// Reads the item id. Readers must coerce the numeric ids to strings.
+  fn id(&mut self) -> Result<Option<&'a str>, JsonParseError> {
+    if self.reader.read_null()? {
+      Ok(None)
+    } else if let Ok(number) = self.reader.read_number("id") {
+      Ok(Some(number))
+    } else {
+      self.string("id")
+    }
+  }
+
+  fn item(&mut self) -> Result<Item<'a>, JsonParseError> {
+    let mut item = Item::default();
+    let mut id = None;
+    let mut legacy_author = None;
+    let mut object = self.reader.begin_object("items")?;
+
+    while let Some(key) = self.reader.next_key(&mut object)? {
+      match key.as_ref() {
+        "id" => id = self.id()?,
+        "url" => item.url = self.string("url")?,
+        "external_url" => item.external_url = self.string("external_url")?,
+        "title" => item.title = self.string("title")?,
+        "content_html" => item.content_html = self.string("content_html")?,
+        "content_text" => item.content_text = self.string("content_text")?,
+        "summary" => item.summary = self.string("summary")?,
+        "image" => item.image = self.string("image")?,
+        "banner_image" => item.banner_image = self.string("banner_image")?,
+        "date_published" => item.date_published = self.timestamp("date_published")?,
+        "date_modified" => item.date_modified = self.timestamp("date_modified")?,
+        "author" => legacy_author = self.author()?,
+        "authors" => item.authors = self.authors()?,
+        "tags" => {
+          let tags = self.list("tags", |parser| parser.string("tags"))?;
+          item.tags = tags.into_iter().flatten().collect();
+        }
+        "language" => item.language = self.string("language")?,
+        "attachments" => item.attachments = self.list("attachments", Self::attachment)?,
+        name if name.starts_with('_') => item.extensions.push(self.extension(name)?),
+        _ => {
+          self.reader.skip_value()?;
+        }
+      }
+    }
+
+    item.id = id.ok_or(JsonParseError::MissingField { name: "id" })?;
+
+    if item.authors.is_empty() {
+      item.authors.extend(legacy_author);
+    }
+
+    Ok(item)
+  }
+
+  fn attachment(&mut self) -> Result<Attachment<'a>, JsonParseError> {
+    let mut duration_in_seconds = None;
+    let mut mime_type = None;
+    let mut size_in_bytes = None;
+    let mut title = None;
+    let mut url = None;
+    let mut object = self.reader.begin_object("attachments")?;
+
+    while let Some(key) = self.reader.next_key(&mut object)? {
+      match key.as_ref() {
+        "url" => url = self.string("url")?,
+        "mime_type" => mime_type = self.string("mime_type")?,
+        "title" => title = self.string("title")?,
+        "size_in_bytes" => size_in_bytes = self.number("size_in_bytes")?,
+        "duration_in_seconds" => duration_in_seconds = self.number("duration_in_seconds")?,
+        _ => {
+          self.reader.skip_value()?;
+        }
+      }
+    }
+
+    let mut attachment = Attachment::new(
+      url.ok_or(JsonParseError::MissingField { name: "url" })?,
+      mime_type.ok_or(JsonParseError::MissingField { name: "mime_type" })?,
+    );
+    attachment.duration_in_seconds = duration_in_seconds;
+    attachment.size_in_bytes = size_in_bytes;
+    attachment.title = title;
+
+    Ok(attachment)
+  }
+}
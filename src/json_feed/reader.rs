@@ -0,0 +1,390 @@
+use std::borrow::Cow;
+
+use crate::error::JsonParseError;
+
+/// Nesting limit for skipped values, protects the recursive skip from stack overflows.
+const MAX_DEPTH: usize = 128;
+
+/// Iteration state of an object or an array.
+pub(crate) struct Sequence {
+  is_first: bool,
+}
+
+/// Minimal pull based JSON reader over a borrowed string. Strings are borrowed from the input
+/// unless they contain escape sequences.
+pub(crate) struct JsonReader<'a> {
+  input: &'a str,
+  position: usize,
+}
+
+impl<'a> JsonReader<'a> {
+  pub(crate) fn new(input: &'a str) -> Self {
+    let position = if input.starts_with('\u{FEFF}') { 3 } else { 0 };
+    Self { input, position }
+  }
+
+  #[inline]
+  pub(crate) fn position(&self) -> usize {
+    self.position
+  }
+
+  #[inline]
+  fn skip_whitespace(&mut self) {
+    let remaining = &self.input.as_bytes()[self.position..];
+    self.position += remaining
+      .iter()
+      .position(|c| !matches!(c, b' ' | b'\t' | b'\n' | b'\r'))
+      .unwrap_or(remaining.len());
+  }
+
+  /// Returns the first byte of the next token.
+  #[inline]
+  fn peek(&mut self) -> Result<u8, JsonParseError> {
+    self.skip_whitespace();
+    self
+      .input
+      .as_bytes()
+      .get(self.position)
+      .copied()
+      .ok_or(JsonParseError::UnexpectedEof)
+  }
+
+  #[inline]
+  fn expect(&mut self, value: u8) -> Result<(), JsonParseError> {
+    if self.peek()? == value {
+      self.position += 1;
+      Ok(())
+    } else {
+      Err(JsonParseError::InvalidSyntax {
+        position: self.position,
+      })
+    }
+  }
+
+  #[inline]
+  fn expect_literal(&mut self, literal: &str) -> Result<(), JsonParseError> {
+    self.skip_whitespace();
+
+    if self.input[self.position..].starts_with(literal) {
+      self.position += literal.len();
+      Ok(())
+    } else {
+      Err(JsonParseError::InvalidSyntax {
+        position: self.position,
+      })
+    }
+  }
+
+  #[inline]
+  fn invalid_type(&self, name: &'static str) -> JsonParseError {
+    JsonParseError::InvalidType {
+      name,
+      position: self.position,
+    }
+  }
+
+  /// Consumes `null` and returns `true` when the next value is null.
+  pub(crate) fn read_null(&mut self) -> Result<bool, JsonParseError> {
+    if self.peek()? == b'n' {
+      self.expect_literal("null")?;
+      Ok(true)
+    } else {
+      Ok(false)
+    }
+  }
+
+  pub(crate) fn begin_object(&mut self, name: &'static str) -> Result<Sequence, JsonParseError> {
+    if self.peek()? != b'{' {
+      return Err(self.invalid_type(name));
+    }
+
+    self.position += 1;
+    Ok(Sequence { is_first: true })
+  }
+
+  /// Returns the next member name of the object, or `None` when the object ends.
+  pub(crate) fn next_key(
+    &mut self,
+    sequence: &mut Sequence,
+  ) -> Result<Option<Cow<'a, str>>, JsonParseError> {
+    if self.peek()? == b'}' {
+      self.position += 1;
+      return Ok(None);
+    }
+
+    if !sequence.is_first {
+      self.expect(b',')?;
+    }
+
+    sequence.is_first = false;
+
+    if self.peek()? != b'"' {
+      return Err(JsonParseError::InvalidSyntax {
+        position: self.position,
+      });
+    }
+
+    let key = self.read_string_token()?;
+    self.expect(b':')?;
+
+    Ok(Some(key))
+  }
+
+  pub(crate) fn begin_array(&mut self, name: &'static str) -> Result<Sequence, JsonParseError> {
+    if self.peek()? != b'[' {
+      return Err(self.invalid_type(name));
+    }
+
+    self.position += 1;
+    Ok(Sequence { is_first: true })
+  }
+
+  /// Returns `true` when the array has another element.
+  pub(crate) fn next_element(&mut self, sequence: &mut Sequence) -> Result<bool, JsonParseError> {
+    if self.peek()? == b']' {
+      self.position += 1;
+      return Ok(false);
+    }
+
+    if !sequence.is_first {
+      self.expect(b',')?;
+    }
+
+    sequence.is_first = false;
+    Ok(true)
+  }
+
+  pub(crate) fn read_string(&mut self, name: &'static str) -> Result<Cow<'a, str>, JsonParseError> {
+    if self.peek()? != b'"' {
+      return Err(self.invalid_type(name));
+    }
+
+    self.read_string_token()
+  }
+
+  pub(crate) fn read_bool(&mut self, name: &'static str) -> Result<bool, JsonParseError> {
+    match self.peek()? {
+      b't' => self.expect_literal("true").map(|_| true),
+      b'f' => self.expect_literal("false").map(|_| false),
+      _ => Err(self.invalid_type(name)),
+    }
+  }
+
+  /// Returns the number token as is.
+  pub(crate) fn read_number(&mut self, name: &'static str) -> Result<&'a str, JsonParseError> {
+    if !matches!(self.peek()?, b'-' | b'0'..=b'9') {
+      return Err(self.invalid_type(name));
+    }
+
+    self.read_number_token()
+  }
+
+  /// Skips the next value and returns its raw text.
+  pub(crate) fn skip_value(&mut self) -> Result<&'a str, JsonParseError> {
+    self.skip_whitespace();
+    let start = self.position;
+    self.skip_value_with_depth(0)?;
+
+    Ok(&self.input[start..self.position])
+  }
+
+  /// Checks that only whitespace remains after the root value.
+  pub(crate) fn finish(&mut self) -> Result<(), JsonParseError> {
+    self.skip_whitespace();
+
+    if self.position < self.input.len() {
+      Err(JsonParseError::InvalidSyntax {
+        position: self.position,
+      })
+    } else {
+      Ok(())
+    }
+  }
+
+  fn skip_value_with_depth(&mut self, depth: usize) -> Result<(), JsonParseError> {
+    if depth > MAX_DEPTH {
+      return Err(JsonParseError::InvalidSyntax {
+        position: self.position,
+      });
+    }
+
+    match self.peek()? {
+      b'{' => {
+        let mut sequence = self.begin_object("")?;
+
+        while self.next_key(&mut sequence)?.is_some() {
+          self.skip_value_with_depth(depth + 1)?;
+        }
+      }
+      b'[' => {
+        let mut sequence = self.begin_array("")?;
+
+        while self.next_element(&mut sequence)? {
+          self.skip_value_with_depth(depth + 1)?;
+        }
+      }
+      b'"' => {
+        self.read_string_token()?;
+      }
+      b't' => self.expect_literal("true")?,
+      b'f' => self.expect_literal("false")?,
+      b'n' => self.expect_literal("null")?,
+      b'-' | b'0'..=b'9' => {
+        self.read_number_token()?;
+      }
+      _ => {
+        return Err(JsonParseError::InvalidSyntax {
+          position: self.position,
+        })
+      }
+    }
+
+    Ok(())
+  }
+
+  fn read_number_token(&mut self) -> Result<&'a str, JsonParseError> {
+    let bytes = self.input.as_bytes();
+    let start = self.position;
+    let mut position = start;
+
+    let digits = |position: &mut usize| {
+      let count = bytes[*position..]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+      *position += count;
+      count
+    };
+
+    if bytes.get(position) == Some(&b'-') {
+      position += 1;
+    }
+
+    match bytes.get(position) {
+      Some(b'0') => position += 1,
+      Some(b'1'..=b'9') => {
+        digits(&mut position);
+      }
+      _ => return Err(JsonParseError::InvalidSyntax { position }),
+    }
+
+    if bytes.get(position) == Some(&b'.') {
+      position += 1;
+
+      if digits(&mut position) == 0 {
+        return Err(JsonParseError::InvalidSyntax { position });
+      }
+    }
+
+    if matches!(bytes.get(position), Some(b'e' | b'E')) {
+      position += 1;
+
+      if matches!(bytes.get(position), Some(b'+' | b'-')) {
+        position += 1;
+      }
+
+      if digits(&mut position) == 0 {
+        return Err(JsonParseError::InvalidSyntax { position });
+      }
+    }
+
+    self.position = position;
+    Ok(&self.input[start..position])
+  }
+
+  fn read_string_token(&mut self) -> Result<Cow<'a, str>, JsonParseError> {
+    // Skips the opening quotation mark.
+    let start = self.position + 1;
+    let bytes = self.input.as_bytes();
+    let mut position = start;
+    let mut has_escape = false;
+
+    loop {
+      match bytes.get(position) {
+        Some(b'"') => break,
+        Some(b'\\') => {
+          has_escape = true;
+          position += 2;
+        }
+        Some(c) if *c < 0x20 => return Err(JsonParseError::InvalidSyntax { position }),
+        Some(_) => position += 1,
+        None => return Err(JsonParseError::UnexpectedEof),
+      }
+    }
+
+    self.position = position + 1;
+    let value = &self.input[start..position];
+
+    if has_escape {
+      unescape_json(value, start).map(Cow::Owned)
+    } else {
+      Ok(Cow::Borrowed(value))
+    }
+  }
+}
+
+fn unescape_json(value: &str, offset: usize) -> Result<String, JsonParseError> {
+  let mut unescaped = String::with_capacity(value.len());
+  let mut chars = value.char_indices();
+
+  while let Some((idx, c)) = chars.next() {
+    if c != '\\' {
+      unescaped.push(c);
+      continue;
+    }
+
+    let invalid_escape = || JsonParseError::InvalidEscape {
+      position: offset + idx,
+    };
+
+    let value = match chars.next().ok_or_else(invalid_escape)?.1 {
+      '"' => '"',
+      '\\' => '\\',
+      '/' => '/',
+      'b' => '\u{08}',
+      'f' => '\u{0C}',
+      'n' => '\n',
+      'r' => '\r',
+      't' => '\t',
+      'u' => {
+        let high = read_code_unit(&mut chars).ok_or_else(invalid_escape)?;
+
+        let code_point = if (0xD800..0xDC00).contains(&high) {
+          // Surrogate pair, the low surrogate must follow as another `\u` escape.
+          match (chars.next(), chars.next()) {
+            (Some((_, '\\')), Some((_, 'u'))) => (),
+            _ => return Err(invalid_escape()),
+          }
+
+          let low = read_code_unit(&mut chars)
+            .filter(|low| (0xDC00..0xE000).contains(low))
+            .ok_or_else(invalid_escape)?;
+
+          0x10000 + (((high as u32) - 0xD800) << 10) + ((low as u32) - 0xDC00)
+        } else {
+          high as u32
+        };
+
+        char::from_u32(code_point).ok_or_else(invalid_escape)?
+      }
+      _ => return Err(invalid_escape()),
+    };
+
+    unescaped.push(value);
+  }
+
+  Ok(unescaped)
+}
+
+/// Reads the four hex digits of a `\u` escape.
+#[inline]
+fn read_code_unit(chars: &mut core::str::CharIndices<'_>) -> Option<u16> {
+  let mut code_unit: u16 = 0;
+
+  for _ in 0..4 {
+    let digit = chars.next()?.1.to_digit(16)?;
+    code_unit = (code_unit << 4) | (digit as u16);
+  }
+
+  Some(code_unit)
+}
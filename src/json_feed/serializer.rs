@@ -0,0 +1,347 @@
+use crate::{common::TimeStamp, error::JsonSerializeError, escape::escape_json, serializer::Write};
+
+use super::{reader::JsonReader, Attachment, Author, Extension, Hub, Item, JsonFeed};
+
+use super::JSON_FEED_VERSION;
+
+/// Writes [JsonFeed] documents. Output layout is controlled by the writer, use
+/// [crate::serializer::formatter::DefaultWriter] for compact and
+/// [crate::serializer::formatter::IndentedWriter] for indented output.
+///
+/// ```
+/// use syndication_format::{
+///   json_feed::{Item, JsonFeed, JsonSerializer},
+///   serializer::formatter::DefaultWriter,
+/// };
+///
+/// let mut feed = JsonFeed::new("My Example Feed");
+/// let mut item = Item::new("2");
+/// item.content_text = Some("This is a second item.");
+/// feed.items.push(item);
+///
+/// let mut bytes: Vec<u8> = Vec::new();
+/// let mut writer = DefaultWriter::new(&mut bytes);
+/// JsonSerializer::new(&mut writer).serialize(&feed).unwrap();
+///
+/// assert_eq!(
+///   r#"{"version":"https://jsonfeed.org/version/1.1","title":"My Example Feed","items":[{"id":"2","content_text":"This is a second item."}]}"#,
+///   String::from_utf8(bytes).unwrap()
+/// );
+/// ```
+pub struct JsonSerializer<'a, W>
+where
+  W: Write + ?Sized,
+{
+  writer: &'a mut W,
+}
+
+impl<'a, W> JsonSerializer<'a, W>
+where
+  W: Write + ?Sized,
+{
+  pub fn new(writer: &'a mut W) -> Self {
+    Self { writer }
+  }
+
+  pub fn serialize(&mut self, feed: &JsonFeed<'_>) -> Result<(), JsonSerializeError> {
+    feed.write_json(self.writer)
+  }
+}
+
+/// Types written as JSON values.
+trait WriteJson {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized;
+}
+
+/// Helper for writing the comma separated object members and array elements.
+struct JsonSequence<'a, W>
+where
+  W: Write + ?Sized,
+{
+  writer: &'a mut W,
+  is_empty: bool,
+  end: &'static str,
+}
+
+impl<'a, W> JsonSequence<'a, W>
+where
+  W: Write + ?Sized,
+{
+  fn begin(writer: &'a mut W, start: &str, end: &'static str) -> Result<Self, JsonSerializeError> {
+    writer.write_line(start)?;
+    writer.increment_level();
+
+    Ok(Self {
+      writer,
+      is_empty: true,
+      end,
+    })
+  }
+
+  #[inline]
+  fn object(writer: &'a mut W) -> Result<Self, JsonSerializeError> {
+    Self::begin(writer, "{", "}")
+  }
+
+  #[inline]
+  fn array(writer: &'a mut W) -> Result<Self, JsonSerializeError> {
+    Self::begin(writer, "[", "]")
+  }
+
+  /// Starts the next element and returns the writer for its value.
+  fn element(&mut self) -> Result<&mut W, JsonSerializeError> {
+    if !self.is_empty {
+      self.writer.write_line(",")?;
+    }
+
+    self.is_empty = false;
+    Ok(self.writer)
+  }
+
+  /// Starts the next member and returns the writer for its value.
+  fn key(&mut self, name: &str) -> Result<&mut W, JsonSerializeError> {
+    let writer = self.element()?;
+    writer.write_fmt(format_args!("\"{}\":", escape_json(name)))?;
+    Ok(writer)
+  }
+
+  fn value<V>(&mut self, name: &str, value: &V) -> Result<(), JsonSerializeError>
+  where
+    V: WriteJson + ?Sized,
+  {
+    let writer = self.key(name)?;
+    value.write_json(writer)
+  }
+
+  fn optional<V>(&mut self, name: &str, value: Option<&V>) -> Result<(), JsonSerializeError>
+  where
+    V: WriteJson + ?Sized,
+  {
+    match value {
+      Some(value) => self.value(name, value),
+      None => Ok(()),
+    }
+  }
+
+  /// Writes non-empty lists, empty ones are omitted.
+  fn list<V>(&mut self, name: &str, values: &[V]) -> Result<(), JsonSerializeError>
+  where
+    V: WriteJson,
+  {
+    if values.is_empty() {
+      return Ok(());
+    }
+
+    let mut array = JsonSequence::array(self.key(name)?)?;
+
+    for value in values.iter() {
+      value.write_json(array.element()?)?;
+    }
+
+    array.end()
+  }
+
+  fn extensions(&mut self, extensions: &[Extension<'_>]) -> Result<(), JsonSerializeError> {
+    for extension in extensions.iter() {
+      if !extension.name.starts_with('_') {
+        return Err(JsonSerializeError::InvalidExtension);
+      }
+
+      self.value(extension.name, extension)?;
+    }
+
+    Ok(())
+  }
+
+  fn end(self) -> Result<(), JsonSerializeError> {
+    if !self.is_empty {
+      self.writer.write_line("")?;
+    }
+
+    self.writer.decrement_level();
+    self.writer.write(self.end)?;
+    Ok(())
+  }
+}
+
+impl WriteJson for str {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    writer.write_fmt(format_args!("\"{}\"", escape_json(self)))?;
+    Ok(())
+  }
+}
+
+impl WriteJson for &str {
+  #[inline]
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    (*self).write_json(writer)
+  }
+}
+
+impl WriteJson for bool {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    writer.write(if *self { "true" } else { "false" })?;
+    Ok(())
+  }
+}
+
+impl WriteJson for u64 {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    writer.write_fmt(format_args!("{}", self))?;
+    Ok(())
+  }
+}
+
+impl WriteJson for f64 {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    if !self.is_finite() {
+      return Err(JsonSerializeError::InvalidNumber);
+    }
+
+    writer.write_fmt(format_args!("{}", self))?;
+    Ok(())
+  }
+}
+
+impl WriteJson for TimeStamp {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    writer.write_fmt(format_args!("\"{}\"", self))?;
+    Ok(())
+  }
+}
+
+impl WriteJson for Extension<'_> {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    let mut reader = JsonReader::new(self.value);
+    let is_valid = reader.skip_value().and_then(|_| reader.finish()).is_ok();
+
+    if !is_valid {
+      return Err(JsonSerializeError::InvalidExtension);
+    }
+
+    writer.write(self.value.trim())?;
+    Ok(())
+  }
+}
+
+impl WriteJson for Author<'_> {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    let mut author = JsonSequence::object(writer)?;
+    author.optional("name", self.name)?;
+    author.optional("url", self.url)?;
+    author.optional("avatar", self.avatar)?;
+    author.end()
+  }
+}
+
+impl WriteJson for Hub<'_> {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    let mut hub = JsonSequence::object(writer)?;
+    hub.value("type", self.hub_type)?;
+    hub.value("url", self.url)?;
+    hub.end()
+  }
+}
+
+impl WriteJson for Attachment<'_> {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    let mut attachment = JsonSequence::object(writer)?;
+    attachment.value("url", self.url)?;
+    attachment.value("mime_type", self.mime_type)?;
+    attachment.optional("title", self.title)?;
+    attachment.optional("size_in_bytes", self.size_in_bytes.as_ref())?;
+    attachment.optional("duration_in_seconds", self.duration_in_seconds.as_ref())?;
+    attachment.end()
+  }
+}
+
+impl WriteJson for Item<'_> {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    let mut item = JsonSequence::object(writer)?;
+    item.value("id", self.id)?;
+    item.optional("url", self.url)?;
+    item.optional("external_url", self.external_url)?;
+    item.optional("title", self.title)?;
+    item.optional("content_html", self.content_html)?;
+    item.optional("content_text", self.content_text)?;
+    item.optional("summary", self.summary)?;
+    item.optional("image", self.image)?;
+    item.optional("banner_image", self.banner_image)?;
+    item.optional("date_published", self.date_published.as_ref())?;
+    item.optional("date_modified", self.date_modified.as_ref())?;
+    item.list("authors", &self.authors)?;
+    item.list("tags", &self.tags)?;
+    item.optional("language", self.language)?;
+    item.list("attachments", &self.attachments)?;
+    item.extensions(&self.extensions)?;
+    item.end()
+  }
+}
+
+impl WriteJson for JsonFeed<'_> {
+  fn write_json<W>(&self, writer: &mut W) -> Result<(), JsonSerializeError>
+  where
+    W: Write + ?Sized,
+  {
+    let mut feed = JsonSequence::object(writer)?;
+    feed.value("version", JSON_FEED_VERSION)?;
+    feed.value("title", self.title)?;
+    feed.optional("home_page_url", self.home_page_url)?;
+    feed.optional("feed_url", self.feed_url)?;
+    feed.optional("description", self.description)?;
+    feed.optional("user_comment", self.user_comment)?;
+    feed.optional("next_url", self.next_url)?;
+    feed.optional("icon", self.icon)?;
+    feed.optional("favicon", self.favicon)?;
+    feed.list("authors", &self.authors)?;
+    feed.optional("language", self.language)?;
+    feed.optional("expired", self.expired.as_ref())?;
+    feed.list("hubs", &self.hubs)?;
+
+    // Items are required, even when empty.
+    let mut items = JsonSequence::array(feed.key("items")?)?;
+
+    for item in self.items.iter() {
+      item.write_json(items.element()?)?;
+    }
+
+    items.end()?;
+    feed.extensions(&self.extensions)?;
+    feed.end()
+  }
+}
@@ -1,9 +1,21 @@
 #![cfg_attr(feature = "avx512", feature(stdarch_x86_avx512))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[macro_use]
+mod macros;
+
 pub mod atom;
 pub mod common;
+pub mod convert;
+#[cfg(feature = "std")]
+pub mod detect;
 pub mod error;
 pub mod escape;
+#[cfg(feature = "json_feed")]
+pub mod json_feed;
+#[cfg(feature = "std")]
+pub mod reader;
+pub mod rss;
+pub mod rss1;
 pub mod serializer;
 pub mod utils;
@@ -0,0 +1,39 @@
+/// Implements attribute accessors for the types with `attributes: AttributeMap` field.
+macro_rules! impl_attribute_fns {
+  () => {
+    #[inline]
+    pub fn set_attribute(
+      &mut self,
+      name: $crate::common::AttributeName,
+      value: $crate::common::AttributeValue,
+    ) -> &mut Self {
+      self.attributes.set(name, value);
+      self
+    }
+
+    #[inline]
+    pub fn get_mut_attributes(&mut self) -> &mut $crate::common::AttributeMap {
+      &mut self.attributes
+    }
+  };
+}
+
+macro_rules! set_from_option {
+  ($map:expr, $name:expr, $value:expr) => {
+    if let Some(value) = $value {
+      $map.set(
+        $crate::common::AttributeName::unchecked_new($name),
+        value.into(),
+      );
+    }
+  };
+}
+
+macro_rules! set_from_value {
+  ($map:expr, $name:expr, $value:expr) => {
+    $map.set(
+      $crate::common::AttributeName::unchecked_new($name),
+      $value.into(),
+    );
+  };
+}
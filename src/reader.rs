@@ -0,0 +1,730 @@
+mod arena;
+pub(crate) mod chunk;
+
+pub use arena::*;
+
+use std::borrow::Cow;
+
+use crate::{
+  error::{ParseWarning, XmlParseError},
+  escape::unescape,
+  utils::xml_name_token::is_valid_name,
+};
+
+pub const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+pub const XMLNS_NAMESPACE: &str = "http://www.w3.org/2000/xmlns/";
+
+pub enum XmlEvent<'a> {
+  /// XML declaration without the surrounding `<?xml` and `?>` markers.
+  Declaration(&'a str),
+
+  /// Processing instruction without the surrounding `<?` and `?>` markers.
+  ProcessingInstruction(&'a str),
+
+  /// Document type declaration without the surrounding `<!DOCTYPE` and `>` markers.
+  DocType(&'a str),
+
+  /// Comment text without the surrounding `<!--` and `-->` markers.
+  Comment(&'a str),
+
+  /// Start tag. Empty elements are reported as a start tag immediately followed by an end tag.
+  Start(StartElement<'a>),
+
+  End(EndElement<'a>),
+
+  /// Raw character data. Entities are not decoded, see [`crate::escape::unescape`].
+  Text(&'a str),
+
+  /// Content of a CDATA section.
+  CData(&'a str),
+}
+
+#[derive(Clone, Copy)]
+pub struct StartElement<'a> {
+  name: &'a str,
+  namespace: Option<&'a str>,
+  attributes: &'a str,
+  is_empty: bool,
+  position: usize,
+  end_position: usize,
+}
+
+#[derive(Clone, Copy)]
+pub struct EndElement<'a> {
+  name: &'a str,
+  namespace: Option<&'a str>,
+  position: usize,
+  end_position: usize,
+}
+
+#[derive(Clone, Copy)]
+pub struct XmlAttribute<'a> {
+  pub name: &'a str,
+
+  /// Raw attribute value. Entities are not decoded, see [`crate::escape::unescape`].
+  pub value: &'a str,
+}
+
+pub struct XmlAttributes<'a> {
+  input: &'a str,
+}
+
+#[derive(Clone, Copy)]
+pub struct NamespaceBinding<'a> {
+  /// Bound prefix. Default namespace declarations use an empty prefix.
+  pub prefix: &'a str,
+  pub uri: &'a str,
+}
+
+/// Result of the lenient parsers, the parsed value and the problems recovered from.
+pub struct Parsed<T> {
+  pub value: T,
+  pub warnings: Vec<ParseWarning>,
+}
+
+struct OpenElement<'a> {
+  name: &'a str,
+  namespace: Option<&'a str>,
+  namespace_scope: usize,
+}
+
+/// Pull based XML reader over a borrowed string. Every event borrows directly from the input.
+///
+/// Reader checks the document structure (tag nesting, single root element, namespace prefixes),
+/// but it does not validate DTDs and does not expand entities.
+pub struct XmlReader<'a> {
+  input: &'a str,
+  position: usize,
+  elements: Vec<OpenElement<'a>>,
+  namespaces: Vec<NamespaceBinding<'a>>,
+  pending_end: Option<EndElement<'a>>,
+  has_root: bool,
+}
+
+impl<'a> StartElement<'a> {
+  /// Qualified element name, including the prefix if there is any.
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    self.name
+  }
+
+  #[inline]
+  pub fn local_name(&self) -> &'a str {
+    local_name(self.name)
+  }
+
+  #[inline]
+  pub fn prefix(&self) -> Option<&'a str> {
+    prefix(self.name)
+  }
+
+  /// Resolved namespace URI of the element.
+  #[inline]
+  pub fn namespace(&self) -> Option<&'a str> {
+    self.namespace
+  }
+
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.is_empty
+  }
+
+  #[inline]
+  pub fn attributes(&self) -> XmlAttributes<'a> {
+    XmlAttributes {
+      input: self.attributes,
+    }
+  }
+
+  /// Returns the raw value of the first attribute with the given qualified name.
+  pub fn attribute(&self, name: &str) -> Option<&'a str> {
+    self
+      .attributes()
+      .find(|attr| attr.name == name)
+      .map(|attr| attr.value)
+  }
+
+  /// Byte offset of the tag's `<` character.
+  #[inline]
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  /// Byte offset right after the tag's `>` character.
+  #[inline]
+  pub fn end_position(&self) -> usize {
+    self.end_position
+  }
+}
+
+impl<'a> EndElement<'a> {
+  #[inline]
+  pub fn name(&self) -> &'a str {
+    self.name
+  }
+
+  #[inline]
+  pub fn local_name(&self) -> &'a str {
+    local_name(self.name)
+  }
+
+  #[inline]
+  pub fn namespace(&self) -> Option<&'a str> {
+    self.namespace
+  }
+
+  /// Byte offset of the tag's `<` character. Empty elements report their start tag's end position.
+  #[inline]
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  #[inline]
+  pub fn end_position(&self) -> usize {
+    self.end_position
+  }
+}
+
+impl<'a> XmlAttribute<'a> {
+  #[inline]
+  pub fn local_name(&self) -> &'a str {
+    local_name(self.name)
+  }
+
+  #[inline]
+  pub fn prefix(&self) -> Option<&'a str> {
+    prefix(self.name)
+  }
+
+  /// Returns the declared prefix when the attribute is a namespace declaration.
+  /// Default namespace declarations return an empty prefix.
+  #[inline]
+  pub fn namespace_declaration(&self) -> Option<&'a str> {
+    if self.name == "xmlns" {
+      Some("")
+    } else {
+      self.name.strip_prefix("xmlns:")
+    }
+  }
+}
+
+impl<'a> Iterator for XmlAttributes<'a> {
+  type Item = XmlAttribute<'a>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    // Attribute list is already validated by the reader, the only job here is splitting it.
+    let input = self.input.trim_start_matches(is_whitespace);
+    let name_end = input.find(|c: char| c == '=' || is_whitespace(c))?;
+    let name = &input[..name_end];
+    let remaining = input[name_end..].trim_start_matches(|c: char| c == '=' || is_whitespace(c));
+    let quote = remaining.chars().next()?;
+    let value_end = remaining[1..].find(quote)? + 1;
+    let value = &remaining[1..value_end];
+
+    self.input = &remaining[(value_end + 1)..];
+
+    Some(XmlAttribute { name, value })
+  }
+}
+
+impl<'a> XmlReader<'a> {
+  pub fn new(input: &'a str) -> Self {
+    let position = if input.starts_with('\u{FEFF}') { 3 } else { 0 };
+
+    Self {
+      input,
+      position,
+      elements: Vec::new(),
+      namespaces: Vec::new(),
+      pending_end: None,
+      has_root: false,
+    }
+  }
+
+  /// Creates a reader for a document fragment with already known namespace bindings, e.g. a
+  /// single entry element cut out from a larger feed document.
+  pub fn with_namespaces<I>(input: &'a str, namespaces: I) -> Self
+  where
+    I: IntoIterator<Item = NamespaceBinding<'a>>,
+  {
+    let mut reader = Self::new(input);
+    reader.namespaces.extend(namespaces);
+    reader
+  }
+
+  #[inline]
+  pub fn input(&self) -> &'a str {
+    self.input
+  }
+
+  /// Current byte offset in the input.
+  #[inline]
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  /// Number of currently open elements.
+  #[inline]
+  pub fn depth(&self) -> usize {
+    self.elements.len()
+  }
+
+  /// Namespace bindings visible at the current position, innermost declarations last.
+  #[inline]
+  pub fn namespaces(&self) -> &[NamespaceBinding<'a>] {
+    &self.namespaces
+  }
+
+  /// Resolves a prefix to its namespace URI. Empty prefix resolves the default namespace.
+  pub fn resolve_prefix(&self, prefix: &str) -> Option<&'a str> {
+    resolve(&self.namespaces, prefix)
+  }
+
+  /// Reads the next event. Returns `None` when the document is complete.
+  pub fn next_event(&mut self) -> Result<Option<XmlEvent<'a>>, XmlParseError> {
+    if let Some(end) = self.pending_end.take() {
+      self.close_scope();
+      return Ok(Some(XmlEvent::End(end)));
+    }
+
+    let remaining = &self.input[self.position..];
+
+    if remaining.is_empty() {
+      return if self.elements.is_empty() && self.has_root {
+        Ok(None)
+      } else {
+        Err(XmlParseError::UnexpectedEof)
+      };
+    }
+
+    if !remaining.starts_with('<') {
+      return self.read_character_data();
+    }
+
+    match remaining.as_bytes().get(1) {
+      Some(b'?') => self.read_processing_instruction(),
+      Some(b'!') => {
+        if remaining.starts_with("<!--") {
+          self.read_comment()
+        } else if remaining.starts_with("<![CDATA[") {
+          self.read_cdata()
+        } else if remaining.starts_with("<!DOCTYPE") {
+          self.read_doctype()
+        } else {
+          Err(XmlParseError::InvalidSyntax {
+            position: self.position,
+          })
+        }
+      }
+      Some(b'/') => self.read_end_tag(),
+      Some(_) => self.read_start_tag(),
+      None => Err(XmlParseError::UnexpectedEof),
+    }
+  }
+
+  /// Reads the character data until the end of the most recently opened element. Child elements
+  /// are skipped.
+  ///
+  /// Text is borrowed from the input, unless it contains entities or it's split into multiple
+  /// parts by comments and CDATA sections.
+  pub fn read_text(&mut self) -> Result<Cow<'a, str>, XmlParseError> {
+    let mut text: Option<Cow<'a, str>> = None;
+
+    loop {
+      let part = match self.next_event()? {
+        Some(XmlEvent::Text(value)) => unescape(value)?,
+        Some(XmlEvent::CData(value)) => Cow::Borrowed(value),
+        Some(XmlEvent::Start(_)) => {
+          self.skip_element()?;
+          continue;
+        }
+        Some(XmlEvent::End(_)) => break,
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      };
+
+      text = match text {
+        Some(previous) => {
+          let mut joined = previous.into_owned();
+          joined.push_str(&part);
+          Some(Cow::Owned(joined))
+        }
+        None => Some(part),
+      };
+    }
+
+    Ok(text.unwrap_or(Cow::Borrowed("")))
+  }
+
+  /// Skips everything until the end of the most recently opened element and returns its end tag.
+  pub fn skip_element(&mut self) -> Result<EndElement<'a>, XmlParseError> {
+    let depth = self.depth();
+
+    loop {
+      match self.next_event()? {
+        Some(XmlEvent::End(end)) if self.depth() < depth => return Ok(end),
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    }
+  }
+
+  /// Skips the raw input until the end tag of the element open at `depth`, without parsing the
+  /// markup in between, and returns that end tag. Elements opened after `depth` are discarded.
+  ///
+  /// Recovers from malformed content such as unescaped and unclosed HTML. CDATA sections and
+  /// comments are skipped as a whole, so end tags inside them are ignored.
+  pub fn skip_unparsed(&mut self, depth: usize) -> Result<EndElement<'a>, XmlParseError> {
+    let (name, namespace) = match self.elements.get(depth.wrapping_sub(1)) {
+      Some(element) => (element.name, element.namespace),
+      None => {
+        return Err(XmlParseError::InvalidSyntax {
+          position: self.position,
+        })
+      }
+    };
+
+    let mut cursor = self.position;
+
+    let (position, end_position) = loop {
+      let offset = self.input[cursor..]
+        .find('<')
+        .ok_or(XmlParseError::UnexpectedEof)?;
+      let remaining = &self.input[(cursor + offset)..];
+
+      let skip = if remaining.starts_with("<![CDATA[") {
+        remaining.find("]]>").map(|end| end + 3)
+      } else if let Some(comment) = remaining.strip_prefix("<!--") {
+        comment.find("-->").map(|end| end + 7)
+      } else if let Some(tail) = remaining
+        .strip_prefix("</")
+        .and_then(|tail| tail.strip_prefix(name))
+      {
+        let tail = tail.trim_start_matches(is_whitespace);
+
+        if tail.starts_with('>') {
+          let end_position = self.input.len() - tail.len() + 1;
+          break (cursor + offset, end_position);
+        }
+
+        Some(1)
+      } else {
+        Some(1)
+      };
+
+      cursor += offset + skip.ok_or(XmlParseError::UnexpectedEof)?;
+    };
+
+    self.pending_end = None;
+
+    while self.elements.len() >= depth {
+      self.close_scope();
+    }
+
+    self.position = end_position;
+
+    Ok(EndElement {
+      name,
+      namespace,
+      position,
+      end_position,
+    })
+  }
+
+  #[inline]
+  fn close_scope(&mut self) {
+    if let Some(element) = self.elements.pop() {
+      self.namespaces.truncate(element.namespace_scope);
+    }
+  }
+
+  fn read_character_data(&mut self) -> Result<Option<XmlEvent<'a>>, XmlParseError> {
+    let remaining = &self.input[self.position..];
+    let length = remaining.find('<').unwrap_or(remaining.len());
+    let text = &remaining[..length];
+
+    if self.elements.is_empty() && !text.chars().all(is_whitespace) {
+      return Err(XmlParseError::InvalidSyntax {
+        position: self.position,
+      });
+    }
+
+    self.position += length;
+    Ok(Some(XmlEvent::Text(text)))
+  }
+
+  fn read_delimited(
+    &mut self,
+    start_marker: &str,
+    end_marker: &str,
+  ) -> Result<&'a str, XmlParseError> {
+    let content_start = self.position + start_marker.len();
+    let content_length = self.input[content_start..]
+      .find(end_marker)
+      .ok_or(XmlParseError::UnexpectedEof)?;
+
+    self.position = content_start + content_length + end_marker.len();
+    Ok(&self.input[content_start..(content_start + content_length)])
+  }
+
+  fn read_processing_instruction(&mut self) -> Result<Option<XmlEvent<'a>>, XmlParseError> {
+    let position = self.position;
+    let content = self.read_delimited("<?", "?>")?;
+
+    if content.starts_with("xml") && content[3..].starts_with(is_whitespace) {
+      if position != 0 && !(position == 3 && self.input.starts_with('\u{FEFF}')) {
+        return Err(XmlParseError::InvalidSyntax { position });
+      }
+
+      Ok(Some(XmlEvent::Declaration(content[3..].trim())))
+    } else {
+      Ok(Some(XmlEvent::ProcessingInstruction(content)))
+    }
+  }
+
+  fn read_comment(&mut self) -> Result<Option<XmlEvent<'a>>, XmlParseError> {
+    let content = self.read_delimited("<!--", "-->")?;
+    Ok(Some(XmlEvent::Comment(content)))
+  }
+
+  fn read_cdata(&mut self) -> Result<Option<XmlEvent<'a>>, XmlParseError> {
+    if self.elements.is_empty() {
+      return Err(XmlParseError::InvalidSyntax {
+        position: self.position,
+      });
+    }
+
+    let content = self.read_delimited("<![CDATA[", "]]>")?;
+    Ok(Some(XmlEvent::CData(content)))
+  }
+
+  fn read_doctype(&mut self) -> Result<Option<XmlEvent<'a>>, XmlParseError> {
+    const MARKER: &str = "<!DOCTYPE";
+
+    if self.has_root {
+      return Err(XmlParseError::InvalidSyntax {
+        position: self.position,
+      });
+    }
+
+    let content_start = self.position + MARKER.len();
+    let mut in_subset = false;
+    let mut quote: Option<u8> = None;
+
+    for (offset, byte) in self.input.as_bytes()[content_start..].iter().enumerate() {
+      match (quote, *byte) {
+        (Some(q), b) if q == b => quote = None,
+        (Some(_), _) => {}
+        (None, b'"' | b'\'') => quote = Some(*byte),
+        (None, b'[') => in_subset = true,
+        (None, b']') => in_subset = false,
+        (None, b'>') if !in_subset => {
+          self.position = content_start + offset + 1;
+          let content = &self.input[content_start..(content_start + offset)];
+          return Ok(Some(XmlEvent::DocType(content.trim())));
+        }
+        _ => {}
+      }
+    }
+
+    Err(XmlParseError::UnexpectedEof)
+  }
+
+  fn read_name(&self, start: usize) -> Result<&'a str, XmlParseError> {
+    let remaining = &self.input[start..];
+    let length = remaining
+      .find(|c: char| is_whitespace(c) || matches!(c, '/' | '>' | '='))
+      .ok_or(XmlParseError::UnexpectedEof)?;
+    let name = &remaining[..length];
+
+    if is_valid_name(name) {
+      Ok(name)
+    } else {
+      Err(XmlParseError::InvalidName { position: start })
+    }
+  }
+
+  fn read_end_tag(&mut self) -> Result<Option<XmlEvent<'a>>, XmlParseError> {
+    let position = self.position;
+    let name = self.read_name(position + 2)?;
+    let tag_end = position + 2 + name.len();
+    let remaining = self.input[tag_end..].trim_start_matches(is_whitespace);
+
+    if !remaining.starts_with('>') {
+      return Err(XmlParseError::InvalidSyntax { position: tag_end });
+    }
+
+    let end_position = self.input.len() - remaining.len() + 1;
+
+    match self.elements.last() {
+      Some(element) if element.name == name => {
+        let end = EndElement {
+          name,
+          namespace: element.namespace,
+          position,
+          end_position,
+        };
+
+        self.position = end_position;
+        self.close_scope();
+        Ok(Some(XmlEvent::End(end)))
+      }
+      _ => Err(XmlParseError::MismatchedEndTag { position }),
+    }
+  }
+
+  fn read_start_tag(&mut self) -> Result<Option<XmlEvent<'a>>, XmlParseError> {
+    let position = self.position;
+
+    if self.has_root && self.elements.is_empty() {
+      return Err(XmlParseError::InvalidSyntax { position });
+    }
+
+    let name = self.read_name(position + 1)?;
+    let attributes_start = position + 1 + name.len();
+    let namespace_scope = self.namespaces.len();
+    let bytes = self.input.as_bytes();
+    let mut cursor = attributes_start;
+
+    let (attributes_end, is_empty) = loop {
+      let attribute_start = cursor;
+
+      while bytes.get(cursor).is_some_and(|b| is_whitespace(*b as char)) {
+        cursor += 1;
+      }
+
+      match bytes.get(cursor) {
+        Some(b'>') => break (cursor, false),
+        Some(b'/') if bytes.get(cursor + 1) == Some(&b'>') => break (cursor, true),
+        Some(_) if cursor == attribute_start => {
+          return Err(XmlParseError::InvalidSyntax { position: cursor });
+        }
+        Some(_) => {}
+        None => return Err(XmlParseError::UnexpectedEof),
+      };
+
+      let attribute_name = self.read_name(cursor)?;
+      cursor += attribute_name.len();
+
+      while bytes.get(cursor).is_some_and(|b| is_whitespace(*b as char)) {
+        cursor += 1;
+      }
+
+      if bytes.get(cursor) != Some(&b'=') {
+        return Err(XmlParseError::InvalidSyntax { position: cursor });
+      }
+
+      cursor += 1;
+
+      while bytes.get(cursor).is_some_and(|b| is_whitespace(*b as char)) {
+        cursor += 1;
+      }
+
+      let quote = match bytes.get(cursor) {
+        Some(quote @ (b'"' | b'\'')) => *quote,
+        Some(_) => return Err(XmlParseError::InvalidSyntax { position: cursor }),
+        None => return Err(XmlParseError::UnexpectedEof),
+      };
+
+      let value_start = cursor + 1;
+      let value_length = bytes[value_start..]
+        .iter()
+        .position(|b| *b == quote)
+        .ok_or(XmlParseError::UnexpectedEof)?;
+      let value = &self.input[value_start..(value_start + value_length)];
+
+      if value.contains('<') {
+        return Err(XmlParseError::InvalidSyntax {
+          position: value_start,
+        });
+      }
+
+      let attribute = XmlAttribute {
+        name: attribute_name,
+        value,
+      };
+
+      if let Some(prefix) = attribute.namespace_declaration() {
+        self
+          .namespaces
+          .push(NamespaceBinding { prefix, uri: value });
+      }
+
+      cursor = value_start + value_length + 1;
+    };
+
+    let namespace = match prefix(name) {
+      Some(prefix) => match resolve(&self.namespaces, prefix) {
+        Some(uri) => Some(uri),
+        None => {
+          self.namespaces.truncate(namespace_scope);
+          return Err(XmlParseError::UnboundPrefix { position });
+        }
+      },
+      None => resolve(&self.namespaces, ""),
+    };
+
+    let end_position = if is_empty {
+      attributes_end + 2
+    } else {
+      attributes_end + 1
+    };
+
+    self.position = end_position;
+    self.has_root = true;
+    self.elements.push(OpenElement {
+      name,
+      namespace,
+      namespace_scope,
+    });
+
+    if is_empty {
+      self.pending_end = Some(EndElement {
+        name,
+        namespace,
+        position: end_position,
+        end_position,
+      });
+    }
+
+    Ok(Some(XmlEvent::Start(StartElement {
+      name,
+      namespace,
+      attributes: &self.input[attributes_start..attributes_end],
+      is_empty,
+      position,
+      end_position,
+    })))
+  }
+}
+
+#[inline]
+fn is_whitespace(value: char) -> bool {
+  matches!(value, ' ' | '\t' | '\r' | '\n')
+}
+
+#[inline]
+fn local_name(name: &str) -> &str {
+  match name.split_once(':') {
+    Some((_, local)) => local,
+    None => name,
+  }
+}
+
+#[inline]
+fn prefix(name: &str) -> Option<&str> {
+  name.split_once(':').map(|(prefix, _)| prefix)
+}
+
+fn resolve<'a>(namespaces: &[NamespaceBinding<'a>], prefix: &str) -> Option<&'a str> {
+  match prefix {
+    "xml" => Some(XML_NAMESPACE),
+    "xmlns" => Some(XMLNS_NAMESPACE),
+    _ => namespaces
+      .iter()
+      .rev()
+      .find(|binding| binding.prefix == prefix)
+      .map(|binding| binding.uri)
+      .filter(|uri| !uri.is_empty()),
+  }
+}
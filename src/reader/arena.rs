@@ -0,0 +1,69 @@
+use core::cell::RefCell;
+use std::borrow::Cow;
+
+/// Append-only string storage for the values that can't be borrowed from the parsed input, such as
+/// text with decoded entities or Base64 decoded content.
+///
+/// Stored strings are never moved or dropped until the arena itself is cleared or dropped, so they
+/// can be handed out with the arena's lifetime.
+#[derive(Default)]
+pub struct StringArena {
+  // Raw pointers from `Box::into_raw`, so handed out borrows aren't invalidated by moving the
+  // boxes when the Vec grows. Freed by `clear` and `Drop`.
+  inner: RefCell<Vec<*mut [u8]>>,
+}
+
+impl StringArena {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn alloc(&self, value: String) -> &str {
+    let value = self.alloc_bytes(value.into_bytes());
+
+    // SAFETY: Bytes are moved from a String, so they're valid UTF-8.
+    unsafe { core::str::from_utf8_unchecked(value) }
+  }
+
+  pub fn alloc_bytes(&self, value: Vec<u8>) -> &[u8] {
+    let value_ptr = Box::into_raw(value.into_boxed_slice());
+
+    self.inner.borrow_mut().push(value_ptr);
+
+    // SAFETY: Allocation is owned by the arena and only released via `clear` or drop, both of
+    // which require there are no outstanding borrows.
+    unsafe { &*value_ptr }
+  }
+
+  /// Returns borrowed values as is and moves owned values into the arena.
+  pub fn store<'a>(&'a self, value: Cow<'a, str>) -> &'a str {
+    match value {
+      Cow::Borrowed(value) => value,
+      Cow::Owned(value) => self.alloc(value),
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.inner.borrow().len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.inner.borrow().is_empty()
+  }
+
+  pub fn clear(&mut self) {
+    for value_ptr in self.inner.get_mut().drain(..) {
+      // SAFETY: Pointer is created by `Box::into_raw` in `alloc_bytes` and freed only once here.
+      drop(unsafe { Box::from_raw(value_ptr) });
+    }
+  }
+}
+
+// SAFETY: Arena exclusively owns the allocations behind the pointers, same as `Box<[u8]>`.
+unsafe impl Send for StringArena {}
+
+impl Drop for StringArena {
+  fn drop(&mut self) {
+    self.clear();
+  }
+}
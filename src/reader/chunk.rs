@@ -0,0 +1,215 @@
+use std::io::BufRead;
+
+use crate::error::XmlParseError;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NodeKind {
+  Text,
+
+  /// Comments, CDATA sections, processing instructions and document type declarations.
+  Misc,
+  Start {
+    is_empty: bool,
+  },
+  End,
+}
+
+/// Splits a byte stream into raw XML nodes without parsing them. It only tracks the markup
+/// boundaries, so elements can be cut out of large documents and parsed one by one.
+pub(crate) struct ChunkReader<R>
+where
+  R: BufRead,
+{
+  inner: R,
+}
+
+impl<R> ChunkReader<R>
+where
+  R: BufRead,
+{
+  pub fn new(inner: R) -> Self {
+    Self { inner }
+  }
+
+  /// Appends the next node to the buffer. Returns `None` at the end of the stream.
+  pub fn read_node(&mut self, buffer: &mut Vec<u8>) -> Result<Option<NodeKind>, XmlParseError> {
+    let available = self.inner.fill_buf()?;
+
+    match available.first() {
+      None => Ok(None),
+      Some(b'<') => self.read_markup(buffer).map(Some),
+      Some(_) => {
+        self.read_text(buffer)?;
+        Ok(Some(NodeKind::Text))
+      }
+    }
+  }
+
+  /// Appends the remaining nodes of an element to the buffer, including its end tag. Expects the
+  /// non-empty start tag to be the last node read.
+  pub fn read_element(&mut self, buffer: &mut Vec<u8>) -> Result<(), XmlParseError> {
+    let mut depth: usize = 1;
+
+    while depth > 0 {
+      match self.read_node(buffer)? {
+        Some(NodeKind::Start { is_empty: false }) => depth += 1,
+        Some(NodeKind::End) => depth -= 1,
+        Some(_) => {}
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    }
+
+    Ok(())
+  }
+
+  fn read_text(&mut self, buffer: &mut Vec<u8>) -> Result<(), XmlParseError> {
+    loop {
+      let available = self.inner.fill_buf()?;
+
+      if available.is_empty() {
+        return Ok(());
+      }
+
+      match available.iter().position(|b| *b == b'<') {
+        Some(length) => {
+          buffer.extend_from_slice(&available[..length]);
+          self.inner.consume(length);
+          return Ok(());
+        }
+        None => {
+          let length = available.len();
+          buffer.extend_from_slice(available);
+          self.inner.consume(length);
+        }
+      }
+    }
+  }
+
+  fn read_markup(&mut self, buffer: &mut Vec<u8>) -> Result<NodeKind, XmlParseError> {
+    let start = buffer.len();
+    self.read_byte(buffer)?;
+
+    match self.read_byte(buffer)? {
+      b'?' => {
+        self.read_until_suffix(buffer, start + 2, b"?>")?;
+        Ok(NodeKind::Misc)
+      }
+      b'/' => {
+        self.read_tag(buffer)?;
+        Ok(NodeKind::End)
+      }
+      b'!' => {
+        // Reads just enough bytes to tell comments, CDATA and DOCTYPE apart.
+        while buffer.len() - start <= 9 {
+          let marker = &buffer[start..];
+
+          if marker == b"<!--" {
+            // Suffix must not overlap the opening marker, `<!-->` is not a complete comment.
+            self.read_until_suffix(buffer, start + marker.len(), b"-->")?;
+            return Ok(NodeKind::Misc);
+          } else if marker == b"<![CDATA[" {
+            self.read_until_suffix(buffer, start + marker.len(), b"]]>")?;
+            return Ok(NodeKind::Misc);
+          } else if marker == b"<!DOCTYPE" {
+            self.read_doctype(buffer)?;
+            return Ok(NodeKind::Misc);
+          }
+
+          self.read_byte(buffer)?;
+        }
+
+        Err(XmlParseError::InvalidSyntax { position: start })
+      }
+      _ => {
+        self.read_tag(buffer)?;
+        let is_empty = buffer.len() - start > 2 && buffer[buffer.len() - 2] == b'/';
+        Ok(NodeKind::Start { is_empty })
+      }
+    }
+  }
+
+  #[inline]
+  fn read_byte(&mut self, buffer: &mut Vec<u8>) -> Result<u8, XmlParseError> {
+    let available = self.inner.fill_buf()?;
+
+    match available.first() {
+      Some(byte) => {
+        let byte = *byte;
+        buffer.push(byte);
+        self.inner.consume(1);
+        Ok(byte)
+      }
+      None => Err(XmlParseError::UnexpectedEof),
+    }
+  }
+
+  /// Reads until the closing `>`, skipping the ones in quoted attribute values.
+  fn read_tag(&mut self, buffer: &mut Vec<u8>) -> Result<(), XmlParseError> {
+    let mut quote: Option<u8> = None;
+
+    loop {
+      let available = self.inner.fill_buf()?;
+
+      if available.is_empty() {
+        return Err(XmlParseError::UnexpectedEof);
+      }
+
+      for (idx, byte) in available.iter().enumerate() {
+        match (quote, *byte) {
+          (Some(q), b) if q == b => quote = None,
+          (Some(_), _) => {}
+          (None, b'"' | b'\'') => quote = Some(*byte),
+          (None, b'>') => {
+            buffer.extend_from_slice(&available[..=idx]);
+            self.inner.consume(idx + 1);
+            return Ok(());
+          }
+          _ => {}
+        }
+      }
+
+      let length = available.len();
+      buffer.extend_from_slice(available);
+      self.inner.consume(length);
+    }
+  }
+
+  fn read_doctype(&mut self, buffer: &mut Vec<u8>) -> Result<(), XmlParseError> {
+    let mut in_subset = false;
+    let mut quote: Option<u8> = None;
+
+    loop {
+      let byte = self.read_byte(buffer)?;
+
+      match (quote, byte) {
+        (Some(q), b) if q == b => quote = None,
+        (Some(_), _) => {}
+        (None, b'"' | b'\'') => quote = Some(byte),
+        (None, b'[') => in_subset = true,
+        (None, b']') => in_subset = false,
+        (None, b'>') if !in_subset => return Ok(()),
+        _ => {}
+      }
+    }
+  }
+
+  /// Reads until the buffer ends with the suffix. Suffix search starts from `search_start`.
+  fn read_until_suffix(
+    &mut self,
+    buffer: &mut Vec<u8>,
+    search_start: usize,
+    suffix: &[u8],
+  ) -> Result<(), XmlParseError> {
+    let last = suffix[suffix.len() - 1];
+
+    loop {
+      if buffer.len() >= search_start + suffix.len() && buffer.ends_with(suffix) {
+        return Ok(());
+      }
+
+      if self.inner.read_until(last, buffer)? == 0 {
+        return Err(XmlParseError::UnexpectedEof);
+      }
+    }
+  }
+}
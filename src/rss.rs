@@ -0,0 +1,11 @@
+mod channel;
+mod item;
+mod metadata;
+#[cfg(feature = "std")]
+mod parser;
+
+pub use channel::*;
+pub use item::*;
+pub use metadata::*;
+#[cfg(feature = "std")]
+pub use parser::*;
@@ -0,0 +1,223 @@
+use crate::{
+  common::{AttributeMap, AttributeName, AttributeValue, TimeStamp},
+  error::XmlSerializeError,
+  serializer::{ElementSerializer, Serialize},
+  utils::xml_name_token::is_valid_name,
+};
+
+use super::{text_node, Category, Cloud, Day, Image, Item, TextInput};
+
+pub const RSS_VERSION: &str = "2.0";
+pub const RSS_DOCS: &str = "https://www.rssboard.org/rss-specification";
+
+/// RSS 2.0 channel. Serializes as a complete `<rss version="2.0">` document, custom attributes
+/// are set on the `rss` root element (e.g. namespace declarations for extensions).
+pub struct Channel<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub categories: Vec<Category<'a>>,
+  pub cloud: Option<Cloud<'a>>,
+  pub copyright: Option<&'a str>,
+  pub description: &'a str,
+  pub docs: Option<&'a str>,
+  pub generator: Option<&'a str>,
+  pub image: Option<Image<'a>>,
+  pub items: Vec<Item<'a>>,
+  pub language: Option<&'a str>,
+  pub last_build_date: Option<TimeStamp>,
+  pub link: &'a str,
+  pub managing_editor: Option<&'a str>,
+  pub pub_date: Option<TimeStamp>,
+  pub rating: Option<&'a str>,
+  pub skip_days: Vec<Day>,
+  pub skip_hours: Vec<u8>,
+  pub text_input: Option<TextInput<'a>>,
+  pub title: &'a str,
+  pub ttl: Option<u32>,
+  pub web_master: Option<&'a str>,
+}
+
+impl Serialize for Channel<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    if let Some(name) = namespace {
+      if !is_valid_name(name) {
+        return Err(XmlSerializeError::InvalidNamespace);
+      }
+    }
+
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_value!(attributes, "version", RSS_VERSION);
+
+    let mut rss = serializer.serialize_element("rss", namespace, Some(&attributes))?;
+    rss.serialize(ChannelNode(self), namespace)?;
+    rss.end()?;
+
+    Ok(())
+  }
+}
+
+/// Helper node for the inner `channel` element.
+struct ChannelNode<'a, 'b>(&'b Channel<'a>);
+
+impl Serialize for ChannelNode<'_, '_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let channel = self.0;
+    let mut element = serializer.serialize_element("channel", namespace, None)?;
+
+    element.serialize(text_node("title", channel.title), namespace)?;
+    element.serialize(text_node("link", channel.link), namespace)?;
+    element.serialize(text_node("description", channel.description), namespace)?;
+
+    if let Some(language) = channel.language {
+      element.serialize(text_node("language", language), namespace)?;
+    }
+
+    if let Some(copyright) = channel.copyright {
+      element.serialize(text_node("copyright", copyright), namespace)?;
+    }
+
+    if let Some(managing_editor) = channel.managing_editor {
+      element.serialize(text_node("managingEditor", managing_editor), namespace)?;
+    }
+
+    if let Some(web_master) = channel.web_master {
+      element.serialize(text_node("webMaster", web_master), namespace)?;
+    }
+
+    if let Some(pub_date) = channel.pub_date.as_ref() {
+      element.serialize(text_node("pubDate", &pub_date.format_rfc822()), namespace)?;
+    }
+
+    if let Some(last_build_date) = channel.last_build_date.as_ref() {
+      let value = last_build_date.format_rfc822();
+      element.serialize(text_node("lastBuildDate", &value), namespace)?;
+    }
+
+    for category in channel.categories.iter() {
+      element.serialize(category, namespace)?;
+    }
+
+    if let Some(generator) = channel.generator {
+      element.serialize(text_node("generator", generator), namespace)?;
+    }
+
+    if let Some(docs) = channel.docs {
+      element.serialize(text_node("docs", docs), namespace)?;
+    }
+
+    if let Some(cloud) = channel.cloud.as_ref() {
+      element.serialize(cloud, namespace)?;
+    }
+
+    if let Some(ttl) = channel.ttl {
+      element.serialize(text_node("ttl", &ttl.to_string()), namespace)?;
+    }
+
+    if let Some(image) = channel.image.as_ref() {
+      element.serialize(image, namespace)?;
+    }
+
+    if let Some(rating) = channel.rating {
+      element.serialize(text_node("rating", rating), namespace)?;
+    }
+
+    if let Some(text_input) = channel.text_input.as_ref() {
+      element.serialize(text_input, namespace)?;
+    }
+
+    if !channel.skip_hours.is_empty() {
+      element.serialize(SkipHours(&channel.skip_hours), namespace)?;
+    }
+
+    if !channel.skip_days.is_empty() {
+      element.serialize(SkipDays(&channel.skip_days), namespace)?;
+    }
+
+    for item in channel.items.iter() {
+      element.serialize(item, namespace)?;
+    }
+
+    element.end()?;
+    Ok(())
+  }
+}
+
+struct SkipHours<'a>(&'a [u8]);
+
+impl Serialize for SkipHours<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut element = serializer.serialize_element("skipHours", namespace, None)?;
+
+    for hour in self.0.iter() {
+      element.serialize(text_node("hour", &hour.to_string()), namespace)?;
+    }
+
+    element.end()?;
+    Ok(())
+  }
+}
+
+struct SkipDays<'a>(&'a [Day]);
+
+impl Serialize for SkipDays<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut element = serializer.serialize_element("skipDays", namespace, None)?;
+
+    for day in self.0.iter() {
+      element.serialize(text_node("day", day.as_str()), namespace)?;
+    }
+
+    element.end()?;
+    Ok(())
+  }
+}
+
+impl<'a> Channel<'a> {
+  pub fn new(title: &'a str, link: &'a str, description: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      categories: Vec::default(),
+      cloud: None,
+      copyright: None,
+      description,
+      docs: Some(RSS_DOCS),
+      generator: Some(env!("CARGO_PKG_NAME")),
+      image: None,
+      items: Vec::default(),
+      language: None,
+      last_build_date: None,
+      link,
+      managing_editor: None,
+      pub_date: None,
+      rating: None,
+      skip_days: Vec::default(),
+      skip_hours: Vec::default(),
+      text_input: None,
+      title,
+      ttl: None,
+      web_master: None,
+    }
+  }
+
+  #[inline]
+  pub fn set_attribute(&mut self, name: AttributeName, value: AttributeValue) -> &mut Self {
+    self.attributes.set(name, value);
+    self
+  }
+
+  #[inline]
+  pub fn get_mut_attributes(&mut self) -> &mut AttributeMap {
+    &mut self.attributes
+  }
+}
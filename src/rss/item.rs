@@ -0,0 +1,94 @@
+use crate::{
+  common::{AttributeMap, TimeStamp},
+  error::XmlSerializeError,
+  serializer::{ElementSerializer, Serialize},
+};
+
+use super::{text_node, Category, Enclosure, Guid, Source};
+
+/// RSS 2.0 item. All elements are optional, but at least one of title or description must be
+/// present.
+#[derive(Default)]
+pub struct Item<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub author: Option<&'a str>,
+  pub categories: Vec<Category<'a>>,
+  pub comments: Option<&'a str>,
+  pub description: Option<&'a str>,
+  pub enclosure: Option<Enclosure<'a>>,
+  pub guid: Option<Guid<'a>>,
+  pub link: Option<&'a str>,
+  pub pub_date: Option<TimeStamp>,
+  pub source: Option<Source<'a>>,
+  pub title: Option<&'a str>,
+}
+
+impl<'a> Item<'a> {
+  pub fn new(title: &'a str) -> Self {
+    Self {
+      title: Some(title),
+      ..Self::default()
+    }
+  }
+
+  pub fn with_description(description: &'a str) -> Self {
+    Self {
+      description: Some(description),
+      ..Self::default()
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Item<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut item = serializer.serialize_element("item", namespace, Some(&self.attributes))?;
+
+    if let Some(title) = self.title {
+      item.serialize(text_node("title", title), namespace)?;
+    }
+
+    if let Some(link) = self.link {
+      item.serialize(text_node("link", link), namespace)?;
+    }
+
+    if let Some(description) = self.description {
+      item.serialize(text_node("description", description), namespace)?;
+    }
+
+    if let Some(author) = self.author {
+      item.serialize(text_node("author", author), namespace)?;
+    }
+
+    for category in self.categories.iter() {
+      item.serialize(category, namespace)?;
+    }
+
+    if let Some(comments) = self.comments {
+      item.serialize(text_node("comments", comments), namespace)?;
+    }
+
+    if let Some(enclosure) = self.enclosure.as_ref() {
+      item.serialize(enclosure, namespace)?;
+    }
+
+    if let Some(guid) = self.guid.as_ref() {
+      item.serialize(guid, namespace)?;
+    }
+
+    if let Some(pub_date) = self.pub_date.as_ref() {
+      item.serialize(text_node("pubDate", &pub_date.format_rfc822()), namespace)?;
+    }
+
+    if let Some(source) = self.source.as_ref() {
+      item.serialize(source, namespace)?;
+    }
+
+    item.end()?;
+    Ok(())
+  }
+}
@@ -0,0 +1,324 @@
+use core::fmt::Display;
+
+use crate::{
+  common::AttributeMap,
+  error::XmlSerializeError,
+  serializer::{ElementNode, ElementSerializer, Serialize},
+};
+
+pub struct Category<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub domain: Option<&'a str>,
+  pub value: &'a str,
+}
+
+impl<'a> Category<'a> {
+  pub fn new(value: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      domain: None,
+      value,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Category<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_option!(attributes, "domain", self.domain);
+
+    let category = serializer.serialize_element("category", namespace, Some(&attributes))?;
+    category.serialize_escaped_str(self.value)?;
+    Ok(())
+  }
+}
+
+pub struct Cloud<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub domain: &'a str,
+  pub path: &'a str,
+  pub port: u16,
+  pub protocol: &'a str,
+  pub register_procedure: &'a str,
+}
+
+impl<'a> Cloud<'a> {
+  pub fn new(
+    domain: &'a str,
+    port: u16,
+    path: &'a str,
+    register_procedure: &'a str,
+    protocol: &'a str,
+  ) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      domain,
+      path,
+      port,
+      protocol,
+      register_procedure,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Cloud<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_value!(attributes, "domain", self.domain);
+    set_from_value!(attributes, "port", self.port.to_string());
+    set_from_value!(attributes, "path", self.path);
+    set_from_value!(attributes, "registerProcedure", self.register_procedure);
+    set_from_value!(attributes, "protocol", self.protocol);
+
+    serializer.serialize_empty_element("cloud", namespace, Some(&attributes))?;
+    Ok(())
+  }
+}
+
+pub struct Enclosure<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub length: u64,
+  pub media_type: &'a str,
+  pub url: &'a str,
+}
+
+impl<'a> Enclosure<'a> {
+  pub fn new(url: &'a str, length: u64, media_type: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      length,
+      media_type,
+      url,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Enclosure<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_value!(attributes, "url", self.url);
+    set_from_value!(attributes, "length", self.length.to_string());
+    set_from_value!(attributes, "type", self.media_type);
+
+    serializer.serialize_empty_element("enclosure", namespace, Some(&attributes))?;
+    Ok(())
+  }
+}
+
+pub struct Guid<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub is_permalink: bool,
+  pub value: &'a str,
+}
+
+impl<'a> Guid<'a> {
+  /// Creates a guid with `isPermaLink` set to `true`, which is the RSS 2.0 default.
+  pub fn new(value: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      is_permalink: true,
+      value,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Guid<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+
+    if !self.is_permalink {
+      set_from_value!(attributes, "isPermaLink", "false");
+    }
+
+    let guid = serializer.serialize_element("guid", namespace, Some(&attributes))?;
+    guid.serialize_escaped_str(self.value)?;
+    Ok(())
+  }
+}
+
+pub struct Image<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub description: Option<&'a str>,
+  pub height: Option<u32>,
+  pub link: &'a str,
+  pub title: &'a str,
+  pub url: &'a str,
+  pub width: Option<u32>,
+}
+
+impl<'a> Image<'a> {
+  pub fn new(url: &'a str, title: &'a str, link: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      description: None,
+      height: None,
+      link,
+      title,
+      url,
+      width: None,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Image<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut image = serializer.serialize_element("image", namespace, Some(&self.attributes))?;
+
+    image.serialize(text_node("url", self.url), namespace)?;
+    image.serialize(text_node("title", self.title), namespace)?;
+    image.serialize(text_node("link", self.link), namespace)?;
+
+    if let Some(width) = self.width {
+      image.serialize(text_node("width", &width.to_string()), namespace)?;
+    }
+
+    if let Some(height) = self.height {
+      image.serialize(text_node("height", &height.to_string()), namespace)?;
+    }
+
+    if let Some(description) = self.description {
+      image.serialize(text_node("description", description), namespace)?;
+    }
+
+    image.end()?;
+    Ok(())
+  }
+}
+
+pub struct Source<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub url: &'a str,
+  pub value: &'a str,
+}
+
+impl<'a> Source<'a> {
+  pub fn new(url: &'a str, value: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      url,
+      value,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Source<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_value!(attributes, "url", self.url);
+
+    let source = serializer.serialize_element("source", namespace, Some(&attributes))?;
+    source.serialize_escaped_str(self.value)?;
+    Ok(())
+  }
+}
+
+pub struct TextInput<'a> {
+  pub(crate) attributes: AttributeMap,
+  pub description: &'a str,
+  pub link: &'a str,
+  pub name: &'a str,
+  pub title: &'a str,
+}
+
+impl<'a> TextInput<'a> {
+  pub fn new(title: &'a str, description: &'a str, name: &'a str, link: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      description,
+      link,
+      name,
+      title,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for TextInput<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut text_input =
+      serializer.serialize_element("textInput", namespace, Some(&self.attributes))?;
+
+    text_input.serialize(text_node("title", self.title), namespace)?;
+    text_input.serialize(text_node("description", self.description), namespace)?;
+    text_input.serialize(text_node("name", self.name), namespace)?;
+    text_input.serialize(text_node("link", self.link), namespace)?;
+    text_input.end()?;
+
+    Ok(())
+  }
+}
+
+/// Day names for channel's `skipDays` element.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Day {
+  Monday,
+  Tuesday,
+  Wednesday,
+  Thursday,
+  Friday,
+  Saturday,
+  Sunday,
+}
+
+impl Display for Day {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl Day {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Day::Monday => "Monday",
+      Day::Tuesday => "Tuesday",
+      Day::Wednesday => "Wednesday",
+      Day::Thursday => "Thursday",
+      Day::Friday => "Friday",
+      Day::Saturday => "Saturday",
+      Day::Sunday => "Sunday",
+    }
+  }
+}
+
+#[inline]
+pub(crate) fn text_node<'a>(name: &'a str, value: &'a str) -> ElementNode<'a> {
+  ElementNode {
+    attributes: None,
+    name,
+    value: Some(value),
+  }
+}
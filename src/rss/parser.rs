@@ -0,0 +1,695 @@
+use std::borrow::Cow;
+
+use core::str::FromStr;
+
+use crate::{
+  common::{AttributeMap, AttributeName, LenientDateOptions, TimeStamp},
+  error::{ParseWarning, XmlParseError},
+  escape::{unescape, unescape_lenient},
+  reader::{Parsed, StartElement, StringArena, XmlEvent, XmlReader},
+};
+
+use super::{Category, Channel, Cloud, Day, Enclosure, Guid, Image, Item, Source, TextInput};
+
+/// Versions that share the RSS 2.0 element set. RSS 0.91 and 0.92 are subsets of RSS 2.0.
+const KNOWN_VERSIONS: &[&str] = &["0.91", "0.92", "2.0"];
+
+/// Parses an RSS 0.91, 0.92 or 2.0 document.
+///
+/// Real world feeds rarely follow the specification, so the parser only fails on malformed XML
+/// and a missing `channel` element. Everything else is recovered from and reported as
+/// [ParseWarning]:
+///
+/// - missing required elements and attributes are replaced with empty values,
+/// - dates are accepted in RFC 822 and RFC 3339 formats, other formats are recovered by the
+///   lenient date heuristics (see [TimeStamp::parse_lenient]) and only unrecognised dates are
+///   dropped,
+/// - undefined entities like `&nbsp;` are kept as is,
+/// - unescaped HTML markup in text elements, even unclosed, is kept as is with the entities in
+///   its text decoded.
+///
+/// Text values are borrowed from `input` when possible, otherwise they are decoded into
+/// `arena`. Elements from extension namespaces are skipped.
+///
+/// ```
+/// use syndication_format::{reader::StringArena, rss};
+///
+/// let input = r#"<rss version="2.0"><channel>
+///   <title>Liftoff News</title>
+///   <item><description><![CDATA[<p>Sky watchers</p>]]></description></item>
+/// </channel></rss>"#;
+///
+/// let arena = StringArena::new();
+/// let parsed = rss::parse(input, &arena).unwrap();
+///
+/// assert_eq!("Liftoff News", parsed.value.title);
+/// assert_eq!(Some("<p>Sky watchers</p>"), parsed.value.items[0].description);
+/// // Channel link and description are missing.
+/// assert_eq!(2, parsed.warnings.len());
+/// ```
+pub fn parse<'a>(
+  input: &'a str,
+  arena: &'a StringArena,
+) -> Result<Parsed<Channel<'a>>, XmlParseError> {
+  let mut parser = RssParser::new(XmlReader::new(input), arena);
+  let channel = parser.rss()?;
+  parser.finish()?;
+
+  Ok(Parsed {
+    value: channel,
+    warnings: parser.warnings,
+  })
+}
+
+pub(crate) struct RssParser<'a> {
+  pub(crate) reader: XmlReader<'a>,
+  pub(crate) arena: &'a StringArena,
+  pub(crate) warnings: Vec<ParseWarning>,
+}
+
+impl<'a> RssParser<'a> {
+  pub(crate) fn new(reader: XmlReader<'a>, arena: &'a StringArena) -> Self {
+    Self {
+      reader,
+      arena,
+      warnings: Vec::new(),
+    }
+  }
+
+  /// Consumes the trailing comments and processing instructions after the root element.
+  pub(crate) fn finish(&mut self) -> Result<(), XmlParseError> {
+    while self.reader.next_event()?.is_some() {}
+    Ok(())
+  }
+
+  #[inline]
+  pub(crate) fn warn(&mut self, warning: ParseWarning) {
+    self.warnings.push(warning);
+  }
+
+  /// Returns the next RSS child element of the current element, or `None` when the current
+  /// element ends. Elements from other namespaces are skipped.
+  fn next_child(&mut self) -> Result<Option<StartElement<'a>>, XmlParseError> {
+    loop {
+      match self.reader.next_event()? {
+        Some(XmlEvent::Start(start)) if start.namespace().is_none() => return Ok(Some(start)),
+        Some(XmlEvent::Start(_)) => {
+          self.reader.skip_element()?;
+        }
+        Some(XmlEvent::End(_)) => return Ok(None),
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    }
+  }
+
+  /// Decodes entities, undefined ones are kept as is.
+  pub(crate) fn decode(&mut self, value: &'a str, position: usize) -> Cow<'a, str> {
+    match unescape(value) {
+      Ok(value) => value,
+      Err(_) => {
+        self.warn(ParseWarning::InvalidEntity { position });
+        unescape_lenient(value)
+      }
+    }
+  }
+
+  /// Reads the element text. Unlike [XmlReader::read_text], child elements are treated as
+  /// unescaped HTML. The inner markup is kept as is, including unclosed tags, and only the
+  /// entities in its text are decoded.
+  pub(crate) fn read_text(
+    &mut self,
+    name: &'static str,
+    start: &StartElement<'a>,
+  ) -> Result<&'a str, XmlParseError> {
+    let depth = self.reader.depth();
+    let mut text: Option<Cow<'a, str>> = None;
+
+    loop {
+      let part = match self.reader.next_event() {
+        Ok(Some(XmlEvent::Text(value))) => {
+          let position = self.reader.position() - value.len();
+          self.decode(value, position)
+        }
+        Ok(Some(XmlEvent::CData(value))) => Cow::Borrowed(value),
+        Ok(Some(XmlEvent::Start(_))) => return self.read_markup(name, start, depth),
+        Ok(Some(XmlEvent::End(_))) => break,
+        Ok(Some(_)) => continue,
+        Ok(None) => return Err(XmlParseError::UnexpectedEof),
+        // Stray `<` and `&` characters, or markup which is not well-formed XML.
+        Err(err) => return self.read_markup(name, start, depth).map_err(|_| err),
+      };
+
+      text = match text {
+        Some(previous) => {
+          let mut joined = previous.into_owned();
+          joined.push_str(&part);
+          Some(Cow::Owned(joined))
+        }
+        None => Some(part),
+      };
+    }
+
+    Ok(self.arena.store(text.unwrap_or(Cow::Borrowed(""))))
+  }
+
+  /// Reads the raw element content up to its end tag, when the text contains markup.
+  fn read_markup(
+    &mut self,
+    name: &'static str,
+    start: &StartElement<'a>,
+    depth: usize,
+  ) -> Result<&'a str, XmlParseError> {
+    let end = self.reader.skip_unparsed(depth)?;
+
+    self.warn(ParseWarning::MarkupInText {
+      name,
+      position: start.position(),
+    });
+
+    let content_start = start.end_position();
+    let content = &self.reader.input()[content_start..end.position()];
+
+    if !content.contains('&') && !content.contains("<![CDATA[") {
+      return Ok(content);
+    }
+
+    let mut decoded = String::with_capacity(content.len());
+    let mut offset = 0;
+
+    while offset < content.len() {
+      let remaining = &content[offset..];
+
+      let length = if let Some(cdata) = remaining.strip_prefix("<![CDATA[") {
+        let length = cdata.find("]]>").unwrap_or(cdata.len());
+        decoded.push_str(&cdata[..length]);
+        length + "<![CDATA[]]>".len()
+      } else if remaining.starts_with("<!--") {
+        let length = remaining.find("-->").map_or(remaining.len(), |end| end + 3);
+        decoded.push_str(&remaining[..length]);
+        length
+      } else if is_tag_start(remaining) {
+        let length = remaining.find('>').map_or(remaining.len(), |end| end + 1);
+        decoded.push_str(&remaining[..length]);
+        length
+      } else {
+        // Text runs until the next tag, stray `<` characters are part of the text.
+        let length = remaining
+          .char_indices()
+          .skip(1)
+          .find(|(index, _)| is_tag_start(&remaining[*index..]))
+          .map_or(remaining.len(), |(index, _)| index);
+        let text = self.decode(&remaining[..length], content_start + offset);
+        decoded.push_str(&text);
+        length
+      };
+
+      offset += length;
+    }
+
+    Ok(self.arena.store(Cow::Owned(decoded)))
+  }
+
+  /// Reads single token values like links, numbers and dates, surrounding whitespace is trimmed.
+  #[inline]
+  pub(crate) fn read_value(
+    &mut self,
+    name: &'static str,
+    start: &StartElement<'a>,
+  ) -> Result<&'a str, XmlParseError> {
+    Ok(self.read_text(name, start)?.trim())
+  }
+
+  pub(crate) fn read_number<T: FromStr>(
+    &mut self,
+    name: &'static str,
+    start: &StartElement<'a>,
+  ) -> Result<Option<T>, XmlParseError> {
+    let value = self.read_value(name, start)?;
+    let number = value.parse().ok();
+
+    if number.is_none() {
+      self.warn(ParseWarning::InvalidValue {
+        name,
+        position: start.position(),
+      });
+    }
+
+    Ok(number)
+  }
+
+  /// Reads RFC 822 dates, with RFC 3339 and the lenient date heuristics as fallbacks since both
+  /// are common in the wild.
+  pub(crate) fn read_timestamp(
+    &mut self,
+    name: &'static str,
+    start: &StartElement<'a>,
+  ) -> Result<Option<TimeStamp>, XmlParseError> {
+    let value = self.read_value(name, start)?;
+
+    match TimeStamp::parse_lenient(value, &LenientDateOptions::default()) {
+      Ok(parsed) => {
+        if let Some(heuristic) = parsed.heuristic {
+          self.warn(ParseWarning::RecoveredTimeStamp {
+            name,
+            position: start.position(),
+            heuristic,
+            assumed_time_zone: parsed.assumed_time_zone,
+          });
+        }
+
+        Ok(Some(parsed.timestamp))
+      }
+      Err(inner) => {
+        self.warn(ParseWarning::InvalidTimeStamp {
+          name,
+          position: start.position(),
+          inner,
+        });
+
+        Ok(None)
+      }
+    }
+  }
+
+  pub(crate) fn read_attribute(&mut self, start: &StartElement<'a>, name: &str) -> Option<&'a str> {
+    let value = start.attribute(name)?;
+    let value = self.decode(value, start.position());
+    Some(self.arena.store(value))
+  }
+
+  pub(crate) fn required_attribute(
+    &mut self,
+    start: &StartElement<'a>,
+    name: &'static str,
+  ) -> &'a str {
+    match self.read_attribute(start, name) {
+      Some(value) => value,
+      None => {
+        self.warn(ParseWarning::MissingAttribute {
+          name,
+          position: start.position(),
+        });
+
+        ""
+      }
+    }
+  }
+
+  /// Copies element attributes except the ones mapped to struct fields.
+  pub(crate) fn read_attributes(
+    &mut self,
+    start: &StartElement<'a>,
+    mapped: &[&str],
+  ) -> Result<AttributeMap, XmlParseError> {
+    let mut attributes = AttributeMap::new();
+
+    for attribute in start.attributes() {
+      if mapped.contains(&attribute.name) {
+        continue;
+      }
+
+      let value = self.decode(attribute.value, start.position());
+      attributes.set(AttributeName::new(attribute.name)?, value.into());
+    }
+
+    Ok(attributes)
+  }
+
+  /// Returns the value of a required element, or an empty value when it's missing.
+  pub(crate) fn required(
+    &mut self,
+    value: Option<&'a str>,
+    name: &'static str,
+    parent: &StartElement<'a>,
+  ) -> &'a str {
+    match value {
+      Some(value) => value,
+      None => {
+        self.warn(ParseWarning::MissingElement {
+          name,
+          position: parent.position(),
+        });
+
+        ""
+      }
+    }
+  }
+
+  fn rss(&mut self) -> Result<Channel<'a>, XmlParseError> {
+    let start = loop {
+      match self.reader.next_event()? {
+        Some(XmlEvent::Start(start)) if start.name() == "rss" => break start,
+        Some(XmlEvent::Start(start)) => {
+          return Err(XmlParseError::UnexpectedElement {
+            position: start.position(),
+          })
+        }
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    };
+
+    if !start
+      .attribute("version")
+      .is_some_and(|version| KNOWN_VERSIONS.contains(&version.trim()))
+    {
+      self.warn(ParseWarning::UnsupportedVersion {
+        position: start.position(),
+      });
+    }
+
+    let attributes = self.read_attributes(&start, &["version"])?;
+    let mut channel = None;
+
+    while let Some(child) = self.next_child()? {
+      if child.local_name() == "channel" && channel.is_none() {
+        channel = Some(self.channel(child)?);
+      } else {
+        self.reader.skip_element()?;
+      }
+    }
+
+    let mut channel = channel.ok_or(XmlParseError::MissingElement { name: "channel" })?;
+    channel.attributes = attributes;
+
+    Ok(channel)
+  }
+
+  fn channel(&mut self, start: StartElement<'a>) -> Result<Channel<'a>, XmlParseError> {
+    let mut channel = Channel::new("", "", "");
+    let mut description = None;
+    let mut link = None;
+    let mut title = None;
+    channel.docs = None;
+    channel.generator = None;
+
+    while let Some(child) = self.next_child()? {
+      match child.local_name() {
+        "title" => title = Some(self.read_text("title", &child)?),
+        "link" => link = Some(self.read_value("link", &child)?),
+        "description" => description = Some(self.read_text("description", &child)?),
+        "language" => channel.language = Some(self.read_value("language", &child)?),
+        "copyright" => channel.copyright = Some(self.read_text("copyright", &child)?),
+        "managingEditor" => {
+          channel.managing_editor = Some(self.read_text("managingEditor", &child)?)
+        }
+        "webMaster" => channel.web_master = Some(self.read_text("webMaster", &child)?),
+        "pubDate" => channel.pub_date = self.read_timestamp("pubDate", &child)?,
+        "lastBuildDate" => {
+          channel.last_build_date = self.read_timestamp("lastBuildDate", &child)?
+        }
+        "category" => channel.categories.push(self.category(child)?),
+        "generator" => channel.generator = Some(self.read_text("generator", &child)?),
+        "docs" => channel.docs = Some(self.read_value("docs", &child)?),
+        "cloud" => channel.cloud = Some(self.cloud(child)?),
+        "ttl" => channel.ttl = self.read_number("ttl", &child)?,
+        "image" => channel.image = Some(self.image(child)?),
+        "rating" => channel.rating = Some(self.read_text("rating", &child)?),
+        "textInput" => channel.text_input = Some(self.text_input(child)?),
+        "skipHours" => channel.skip_hours = self.skip_hours()?,
+        "skipDays" => channel.skip_days = self.skip_days()?,
+        "item" => channel.items.push(self.item(child)?),
+        _ => {
+          self.reader.skip_element()?;
+        }
+      }
+    }
+
+    channel.title = self.required(title, "title", &start);
+    channel.link = self.required(link, "link", &start);
+    channel.description = self.required(description, "description", &start);
+
+    Ok(channel)
+  }
+
+  fn item(&mut self, start: StartElement<'a>) -> Result<Item<'a>, XmlParseError> {
+    let mut item = Item {
+      attributes: self.read_attributes(&start, &[])?,
+      ..Item::default()
+    };
+
+    while let Some(child) = self.next_child()? {
+      match child.local_name() {
+        "title" => item.title = Some(self.read_text("title", &child)?),
+        "link" => item.link = Some(self.read_value("link", &child)?),
+        "description" => item.description = Some(self.read_text("description", &child)?),
+        "author" => item.author = Some(self.read_text("author", &child)?),
+        "category" => item.categories.push(self.category(child)?),
+        "comments" => item.comments = Some(self.read_value("comments", &child)?),
+        "enclosure" => item.enclosure = Some(self.enclosure(child)?),
+        "guid" => item.guid = Some(self.guid(child)?),
+        "pubDate" => item.pub_date = self.read_timestamp("pubDate", &child)?,
+        "source" => item.source = Some(self.source(child)?),
+        _ => {
+          self.reader.skip_element()?;
+        }
+      }
+    }
+
+    if item.title.is_none() && item.description.is_none() {
+      self.warn(ParseWarning::MissingElement {
+        name: "title",
+        position: start.position(),
+      });
+    }
+
+    Ok(item)
+  }
+
+  fn category(&mut self, start: StartElement<'a>) -> Result<Category<'a>, XmlParseError> {
+    let mut category = Category::new("");
+    category.attributes = self.read_attributes(&start, &["domain"])?;
+    category.domain = self.read_attribute(&start, "domain");
+    category.value = self.read_text("category", &start)?;
+
+    Ok(category)
+  }
+
+  fn cloud(&mut self, start: StartElement<'a>) -> Result<Cloud<'a>, XmlParseError> {
+    const MAPPED: &[&str] = &["domain", "port", "path", "registerProcedure", "protocol"];
+
+    let port = match self.read_attribute(&start, "port") {
+      Some(port) => port.trim().parse().ok().unwrap_or_else(|| {
+        self.warn(ParseWarning::InvalidValue {
+          name: "port",
+          position: start.position(),
+        });
+
+        0
+      }),
+      None => {
+        self.warn(ParseWarning::MissingAttribute {
+          name: "port",
+          position: start.position(),
+        });
+
+        0
+      }
+    };
+
+    let mut cloud = Cloud::new(
+      self.required_attribute(&start, "domain"),
+      port,
+      self.required_attribute(&start, "path"),
+      self.required_attribute(&start, "registerProcedure"),
+      self.required_attribute(&start, "protocol"),
+    );
+    cloud.attributes = self.read_attributes(&start, MAPPED)?;
+    self.reader.skip_element()?;
+
+    Ok(cloud)
+  }
+
+  fn enclosure(&mut self, start: StartElement<'a>) -> Result<Enclosure<'a>, XmlParseError> {
+    // Length is often empty or missing in the wild, where it's treated as unknown.
+    let length = match self.read_attribute(&start, "length") {
+      Some(length) => length.trim().parse().ok().unwrap_or_else(|| {
+        self.warn(ParseWarning::InvalidValue {
+          name: "length",
+          position: start.position(),
+        });
+
+        0
+      }),
+      None => {
+        self.warn(ParseWarning::MissingAttribute {
+          name: "length",
+          position: start.position(),
+        });
+
+        0
+      }
+    };
+
+    let mut enclosure = Enclosure::new(
+      self.required_attribute(&start, "url"),
+      length,
+      self.required_attribute(&start, "type"),
+    );
+    enclosure.attributes = self.read_attributes(&start, &["url", "length", "type"])?;
+    self.reader.skip_element()?;
+
+    Ok(enclosure)
+  }
+
+  fn guid(&mut self, start: StartElement<'a>) -> Result<Guid<'a>, XmlParseError> {
+    let mut guid = Guid::new("");
+    guid.attributes = self.read_attributes(&start, &["isPermaLink"])?;
+
+    match start.attribute("isPermaLink").map(str::trim) {
+      None | Some("true") => (),
+      Some("false") => guid.is_permalink = false,
+      Some(_) => self.warn(ParseWarning::InvalidValue {
+        name: "isPermaLink",
+        position: start.position(),
+      }),
+    }
+
+    guid.value = self.read_value("guid", &start)?;
+
+    Ok(guid)
+  }
+
+  fn source(&mut self, start: StartElement<'a>) -> Result<Source<'a>, XmlParseError> {
+    let url = self.required_attribute(&start, "url");
+    let mut source = Source::new(url, "");
+    source.attributes = self.read_attributes(&start, &["url"])?;
+    source.value = self.read_text("source", &start)?;
+
+    Ok(source)
+  }
+
+  fn image(&mut self, start: StartElement<'a>) -> Result<Image<'a>, XmlParseError> {
+    let mut image = Image::new("", "", "");
+    image.attributes = self.read_attributes(&start, &[])?;
+    let mut link = None;
+    let mut title = None;
+    let mut url = None;
+
+    while let Some(child) = self.next_child()? {
+      match child.local_name() {
+        "url" => url = Some(self.read_value("url", &child)?),
+        "title" => title = Some(self.read_text("title", &child)?),
+        "link" => link = Some(self.read_value("link", &child)?),
+        "width" => image.width = self.read_number("width", &child)?,
+        "height" => image.height = self.read_number("height", &child)?,
+        "description" => image.description = Some(self.read_text("description", &child)?),
+        _ => {
+          self.reader.skip_element()?;
+        }
+      }
+    }
+
+    image.url = self.required(url, "url", &start);
+    image.title = self.required(title, "title", &start);
+    image.link = self.required(link, "link", &start);
+
+    Ok(image)
+  }
+
+  fn text_input(&mut self, start: StartElement<'a>) -> Result<TextInput<'a>, XmlParseError> {
+    let mut description = None;
+    let mut link = None;
+    let mut name = None;
+    let mut title = None;
+
+    let attributes = self.read_attributes(&start, &[])?;
+
+    while let Some(child) = self.next_child()? {
+      match child.local_name() {
+        "title" => title = Some(self.read_text("title", &child)?),
+        "description" => description = Some(self.read_text("description", &child)?),
+        "name" => name = Some(self.read_value("name", &child)?),
+        "link" => link = Some(self.read_value("link", &child)?),
+        _ => {
+          self.reader.skip_element()?;
+        }
+      }
+    }
+
+    let mut text_input = TextInput::new(
+      self.required(title, "title", &start),
+      self.required(description, "description", &start),
+      self.required(name, "name", &start),
+      self.required(link, "link", &start),
+    );
+    text_input.attributes = attributes;
+
+    Ok(text_input)
+  }
+
+  fn skip_hours(&mut self) -> Result<Vec<u8>, XmlParseError> {
+    let mut hours = Vec::new();
+
+    while let Some(child) = self.next_child()? {
+      if child.local_name() != "hour" {
+        self.reader.skip_element()?;
+        continue;
+      }
+
+      match self.read_number::<u8>("hour", &child)? {
+        Some(hour) if hour < 24 => hours.push(hour),
+        Some(_) => self.warn(ParseWarning::InvalidValue {
+          name: "hour",
+          position: child.position(),
+        }),
+        None => (),
+      }
+    }
+
+    Ok(hours)
+  }
+
+  fn skip_days(&mut self) -> Result<Vec<Day>, XmlParseError> {
+    let mut days = Vec::new();
+
+    while let Some(child) = self.next_child()? {
+      if child.local_name() != "day" {
+        self.reader.skip_element()?;
+        continue;
+      }
+
+      let value = self.read_value("day", &child)?;
+
+      match day(value) {
+        Some(day) => days.push(day),
+        None => self.warn(ParseWarning::InvalidValue {
+          name: "day",
+          position: child.position(),
+        }),
+      }
+    }
+
+    Ok(days)
+  }
+}
+
+#[inline]
+fn day(value: &str) -> Option<Day> {
+  const DAYS: &[Day] = &[
+    Day::Monday,
+    Day::Tuesday,
+    Day::Wednesday,
+    Day::Thursday,
+    Day::Friday,
+    Day::Saturday,
+    Day::Sunday,
+  ];
+
+  DAYS
+    .iter()
+    .find(|day| day.as_str().eq_ignore_ascii_case(value))
+    .copied()
+}
+
+/// Checks whether the text starts with a tag, e.g. `<p>`, `</p>` or `<!DOCTYPE`.
+#[inline]
+fn is_tag_start(value: &str) -> bool {
+  let mut bytes = value.bytes();
+
+  bytes.next() == Some(b'<')
+    && bytes
+      .next()
+      .is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, b'/' | b'!' | b'?'))
+}
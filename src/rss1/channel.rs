@@ -0,0 +1,170 @@
+use crate::{
+  common::{AttributeMap, AttributeName},
+  error::XmlSerializeError,
+  rss::text_node,
+  serializer::{ElementSerializer, Serialize},
+  utils::xml_name_token::is_valid_name,
+};
+
+use super::{dc_prefix, resource_node, DublinCore, Image, Item, TextInput};
+
+pub const RSS1_NAMESPACE: &str = "http://purl.org/rss/1.0/";
+pub const RDF_NAMESPACE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+pub const DC_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+
+/// RSS 1.0 channel. Serializes as a complete `<rdf:RDF>` document with the `rdf`, RSS 1.0 and
+/// Dublin Core namespace declarations. Image, items and text input are written as siblings of
+/// the channel and referenced from it, as the RDF model requires.
+///
+/// Namespace argument of [Serialize::serialize] is used as the prefix of the RSS 1.0 elements,
+/// which are written in the default namespace otherwise.
+pub struct Channel<'a> {
+  pub(crate) attributes: AttributeMap,
+  /// URI of the channel, usually the URL of the RSS document itself.
+  pub about: &'a str,
+  pub description: &'a str,
+  pub dublin_core: DublinCore<'a>,
+  pub image: Option<Image<'a>>,
+  pub items: Vec<Item<'a>>,
+  pub link: &'a str,
+  pub text_input: Option<TextInput<'a>>,
+  pub title: &'a str,
+}
+
+impl<'a> Channel<'a> {
+  pub fn new(about: &'a str, title: &'a str, link: &'a str, description: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      about,
+      description,
+      dublin_core: DublinCore::default(),
+      image: None,
+      items: Vec::default(),
+      link,
+      text_input: None,
+      title,
+    }
+  }
+
+  impl_attribute_fns!();
+
+  #[inline]
+  fn has_dublin_core(&self) -> bool {
+    !self.dublin_core.is_empty() || self.items.iter().any(|item| !item.dublin_core.is_empty())
+  }
+}
+
+impl Serialize for Channel<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_value!(attributes, "xmlns:rdf", RDF_NAMESPACE);
+
+    match namespace {
+      Some(name) if !is_valid_name(name) => return Err(XmlSerializeError::InvalidNamespace),
+      Some(name) => attributes.set(
+        AttributeName::new(&format!("xmlns:{}", name))?,
+        RSS1_NAMESPACE.into(),
+      ),
+      None => {
+        set_from_value!(attributes, "xmlns", RSS1_NAMESPACE);
+      }
+    }
+
+    if self.has_dublin_core() {
+      let prefix = dc_prefix(serializer.namespace_prefix(DC_NAMESPACE));
+      attributes.set(
+        AttributeName::unchecked_new(&format!("xmlns:{}", prefix)),
+        DC_NAMESPACE.into(),
+      );
+    }
+
+    let mut rdf = serializer.serialize_element("RDF", Some("rdf"), Some(&attributes))?;
+    rdf.serialize(ChannelNode(self), namespace)?;
+
+    if let Some(image) = self.image.as_ref() {
+      rdf.serialize(image, namespace)?;
+    }
+
+    for item in self.items.iter() {
+      rdf.serialize(item, namespace)?;
+    }
+
+    if let Some(text_input) = self.text_input.as_ref() {
+      rdf.serialize(text_input, namespace)?;
+    }
+
+    rdf.end()?;
+    Ok(())
+  }
+}
+
+/// Helper node for the inner `channel` element.
+struct ChannelNode<'a, 'b>(&'b Channel<'a>);
+
+impl Serialize for ChannelNode<'_, '_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let channel = self.0;
+    let mut attributes = AttributeMap::with_capacity(1);
+    set_from_value!(attributes, "rdf:about", channel.about);
+
+    let mut element = serializer.serialize_element("channel", namespace, Some(&attributes))?;
+
+    element.serialize(text_node("title", channel.title), namespace)?;
+    element.serialize(text_node("link", channel.link), namespace)?;
+    element.serialize(text_node("description", channel.description), namespace)?;
+    channel.dublin_core.serialize_elements(&mut element)?;
+
+    if let Some(image) = channel.image.as_ref() {
+      element.serialize(resource_node("image", image.about), namespace)?;
+    }
+
+    element.serialize(ItemsNode(&channel.items), namespace)?;
+
+    if let Some(text_input) = channel.text_input.as_ref() {
+      element.serialize(resource_node("textinput", text_input.about), namespace)?;
+    }
+
+    element.end()?;
+    Ok(())
+  }
+}
+
+/// Table of contents of the channel, `<items><rdf:Seq>` with an `rdf:li` for each item.
+struct ItemsNode<'a, 'b>(&'b [Item<'a>]);
+
+impl Serialize for ItemsNode<'_, '_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut items = serializer.serialize_element("items", namespace, None)?;
+    items.serialize(SeqNode(self.0), Some("rdf"))?;
+    items.end()?;
+
+    Ok(())
+  }
+}
+
+struct SeqNode<'a, 'b>(&'b [Item<'a>]);
+
+impl Serialize for SeqNode<'_, '_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut seq = serializer.serialize_element("Seq", namespace, None)?;
+
+    for item in self.0.iter() {
+      seq.serialize(resource_node("li", item.about), namespace)?;
+    }
+
+    seq.end()?;
+    Ok(())
+  }
+}
@@ -0,0 +1,58 @@
+use crate::{
+  common::AttributeMap,
+  error::XmlSerializeError,
+  rss::text_node,
+  serializer::{ElementSerializer, Serialize},
+};
+
+use super::DublinCore;
+
+/// RSS 1.0 item. Title and link are required, `about` is the URI listed in the channel's
+/// `items` sequence.
+pub struct Item<'a> {
+  pub(crate) attributes: AttributeMap,
+  /// URI of the item, usually the same as `link`.
+  pub about: &'a str,
+  pub description: Option<&'a str>,
+  pub dublin_core: DublinCore<'a>,
+  pub link: &'a str,
+  pub title: &'a str,
+}
+
+impl<'a> Item<'a> {
+  pub fn new(title: &'a str, link: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      about: link,
+      description: None,
+      dublin_core: DublinCore::default(),
+      link,
+      title,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Item<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_value!(attributes, "rdf:about", self.about);
+
+    let mut item = serializer.serialize_element("item", namespace, Some(&attributes))?;
+    item.serialize(text_node("title", self.title), namespace)?;
+    item.serialize(text_node("link", self.link), namespace)?;
+
+    if let Some(description) = self.description {
+      item.serialize(text_node("description", description), namespace)?;
+    }
+
+    self.dublin_core.serialize_elements(&mut item)?;
+    item.end()?;
+
+    Ok(())
+  }
+}
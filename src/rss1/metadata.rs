@@ -0,0 +1,190 @@
+use super::DC_NAMESPACE;
+
+use crate::{
+  common::{AttributeMap, TimeStamp},
+  error::XmlSerializeError,
+  rss::text_node,
+  serializer::{ElementSerializer, Serialize},
+};
+
+/// [Dublin Core](https://www.dublincore.org/specifications/dublin-core/dces/) metadata elements,
+/// serialized with the prefix bound to [super::DC_NAMESPACE], `dc` by default.
+#[derive(Default)]
+pub struct DublinCore<'a> {
+  pub contributor: Option<&'a str>,
+  pub coverage: Option<&'a str>,
+  pub creator: Option<&'a str>,
+  pub date: Option<TimeStamp>,
+  pub description: Option<&'a str>,
+  pub format: Option<&'a str>,
+  pub identifier: Option<&'a str>,
+  pub language: Option<&'a str>,
+  pub publisher: Option<&'a str>,
+  pub relation: Option<&'a str>,
+  /// `dc:type` element, the nature or genre of the resource.
+  pub resource_type: Option<&'a str>,
+  pub rights: Option<&'a str>,
+  pub source: Option<&'a str>,
+  pub subject: Option<&'a str>,
+  pub title: Option<&'a str>,
+}
+
+impl DublinCore<'_> {
+  pub fn is_empty(&self) -> bool {
+    self.date.is_none() && self.elements(None).iter().all(|(_, value)| value.is_none())
+  }
+
+  /// Returns the elements in the order of the element set definition.
+  #[inline]
+  fn elements<'b>(&'b self, date: Option<&'b str>) -> [(&'static str, Option<&'b str>); 15] {
+    [
+      ("title", self.title),
+      ("creator", self.creator),
+      ("subject", self.subject),
+      ("description", self.description),
+      ("publisher", self.publisher),
+      ("contributor", self.contributor),
+      ("date", date),
+      ("type", self.resource_type),
+      ("format", self.format),
+      ("identifier", self.identifier),
+      ("source", self.source),
+      ("language", self.language),
+      ("relation", self.relation),
+      ("coverage", self.coverage),
+      ("rights", self.rights),
+    ]
+  }
+
+  /// Writes the elements into the parent element.
+  pub(crate) fn serialize_elements<E>(&self, parent: &mut E) -> Result<(), XmlSerializeError>
+  where
+    E: ElementSerializer,
+  {
+    let date = self.date.map(|date| date.to_string());
+    let prefix = dc_prefix(parent.namespace_prefix(DC_NAMESPACE));
+
+    for (name, value) in self.elements(date.as_deref()) {
+      if let Some(value) = value {
+        parent.serialize(text_node(name, value), Some(prefix))?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Dublin Core prefix bound by the serializer, `dc` when it's not bound or bound as the default
+/// namespace.
+pub(crate) fn dc_prefix(bound: Option<&str>) -> &str {
+  bound.filter(|prefix| !prefix.is_empty()).unwrap_or("dc")
+}
+
+pub struct Image<'a> {
+  pub(crate) attributes: AttributeMap,
+  /// URI of the image, usually the same as `url`.
+  pub about: &'a str,
+  pub link: &'a str,
+  pub title: &'a str,
+  pub url: &'a str,
+}
+
+impl<'a> Image<'a> {
+  pub fn new(url: &'a str, title: &'a str, link: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      about: url,
+      link,
+      title,
+      url,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for Image<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_value!(attributes, "rdf:about", self.about);
+
+    let mut image = serializer.serialize_element("image", namespace, Some(&attributes))?;
+    image.serialize(text_node("title", self.title), namespace)?;
+    image.serialize(text_node("url", self.url), namespace)?;
+    image.serialize(text_node("link", self.link), namespace)?;
+    image.end()?;
+
+    Ok(())
+  }
+}
+
+pub struct TextInput<'a> {
+  pub(crate) attributes: AttributeMap,
+  /// URI of the text input, usually the same as `link`.
+  pub about: &'a str,
+  pub description: &'a str,
+  pub link: &'a str,
+  pub name: &'a str,
+  pub title: &'a str,
+}
+
+impl<'a> TextInput<'a> {
+  pub fn new(title: &'a str, description: &'a str, name: &'a str, link: &'a str) -> Self {
+    Self {
+      attributes: AttributeMap::default(),
+      about: link,
+      description,
+      link,
+      name,
+      title,
+    }
+  }
+
+  impl_attribute_fns!();
+}
+
+impl Serialize for TextInput<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::new_from(&self.attributes);
+    set_from_value!(attributes, "rdf:about", self.about);
+
+    let mut text_input = serializer.serialize_element("textinput", namespace, Some(&attributes))?;
+    text_input.serialize(text_node("title", self.title), namespace)?;
+    text_input.serialize(text_node("description", self.description), namespace)?;
+    text_input.serialize(text_node("name", self.name), namespace)?;
+    text_input.serialize(text_node("link", self.link), namespace)?;
+    text_input.end()?;
+
+    Ok(())
+  }
+}
+
+/// Empty element referencing another resource with `rdf:resource` attribute.
+pub(crate) struct ResourceNode<'a> {
+  name: &'a str,
+  resource: &'a str,
+}
+
+#[inline]
+pub(crate) fn resource_node<'a>(name: &'a str, resource: &'a str) -> ResourceNode<'a> {
+  ResourceNode { name, resource }
+}
+
+impl Serialize for ResourceNode<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: crate::serializer::Serializer,
+  {
+    let mut attributes = AttributeMap::with_capacity(1);
+    set_from_value!(attributes, "rdf:resource", self.resource);
+
+    serializer.serialize_empty_element(self.name, namespace, Some(&attributes))?;
+    Ok(())
+  }
+}
@@ -0,0 +1,323 @@
+use crate::{
+  common::AttributeMap,
+  error::{ParseWarning, XmlParseError},
+  reader::{Parsed, StartElement, StringArena, XmlEvent, XmlReader},
+  rss::RssParser,
+};
+
+use super::{
+  Channel, DublinCore, Image, Item, TextInput, DC_NAMESPACE, RDF_NAMESPACE, RSS1_NAMESPACE,
+};
+
+/// RSS 0.90 shares the RDF structure and the element names of RSS 1.0.
+pub(crate) const RSS090_NAMESPACE: &str = "http://my.netscape.com/rdf/simple/0.9/";
+
+/// Parses an RSS 1.0 (RDF Site Summary) document. RSS 0.90 documents are accepted as well.
+///
+/// Like [crate::rss::parse], parser only fails on malformed XML and a missing `channel`
+/// element, other problems are recovered from and reported as [ParseWarning]. The `items`
+/// sequence of the channel is not used, items are returned in document order.
+///
+/// ```
+/// use syndication_format::{reader::StringArena, rss1};
+///
+/// let input = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+///   xmlns="http://purl.org/rss/1.0/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+///   <channel rdf:about="http://www.xml.com/xml/news.rss">
+///     <title>XML.com</title>
+///     <link>http://xml.com/pub</link>
+///     <description>XML.com features a rich mix of information and services.</description>
+///   </channel>
+///   <item rdf:about="http://xml.com/pub/2000/08/09/xslt/xslt.html">
+///     <title>Processing Inclusions with XSLT</title>
+///     <link>http://xml.com/pub/2000/08/09/xslt/xslt.html</link>
+///     <dc:creator>Bob DuCharme</dc:creator>
+///   </item>
+/// </rdf:RDF>"#;
+///
+/// let arena = StringArena::new();
+/// let parsed = rss1::parse(input, &arena).unwrap();
+///
+/// assert!(parsed.warnings.is_empty());
+/// assert_eq!(Some("Bob DuCharme"), parsed.value.items[0].dublin_core.creator);
+/// ```
+pub fn parse<'a>(
+  input: &'a str,
+  arena: &'a StringArena,
+) -> Result<Parsed<Channel<'a>>, XmlParseError> {
+  let mut parser = RdfParser {
+    inner: RssParser::new(XmlReader::new(input), arena),
+  };
+  let channel = parser.rdf()?;
+  parser.inner.finish()?;
+
+  Ok(Parsed {
+    value: channel,
+    warnings: parser.inner.warnings,
+  })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Vocabulary {
+  DublinCore,
+  Other,
+  Rss,
+}
+
+struct RdfParser<'a> {
+  inner: RssParser<'a>,
+}
+
+impl<'a> RdfParser<'a> {
+  /// Returns the next child element of the current element, or `None` when the current element
+  /// ends.
+  fn next_child(&mut self) -> Result<Option<(Vocabulary, StartElement<'a>)>, XmlParseError> {
+    loop {
+      match self.inner.reader.next_event()? {
+        Some(XmlEvent::Start(start)) => {
+          let vocabulary = match start.namespace() {
+            Some(RSS1_NAMESPACE | RSS090_NAMESPACE) => Vocabulary::Rss,
+            Some(DC_NAMESPACE) => Vocabulary::DublinCore,
+            _ => Vocabulary::Other,
+          };
+
+          return Ok(Some((vocabulary, start)));
+        }
+        Some(XmlEvent::End(_)) => return Ok(None),
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    }
+  }
+
+  /// Returns the `rdf:about` value and the rest of the attributes.
+  fn about(&mut self, start: &StartElement<'a>) -> Result<(&'a str, AttributeMap), XmlParseError> {
+    let reader = &self.inner.reader;
+    let about = start.attributes().find(|attribute| {
+      attribute.local_name() == "about"
+        && attribute
+          .prefix()
+          .and_then(|prefix| reader.resolve_prefix(prefix))
+          == Some(RDF_NAMESPACE)
+    });
+
+    match about {
+      Some(about) => {
+        let value = self.inner.decode(about.value, start.position());
+        let attributes = self.inner.read_attributes(start, &[about.name])?;
+
+        Ok((self.inner.arena.store(value), attributes))
+      }
+      None => {
+        self.inner.warn(ParseWarning::MissingAttribute {
+          name: "rdf:about",
+          position: start.position(),
+        });
+
+        Ok(("", self.inner.read_attributes(start, &[])?))
+      }
+    }
+  }
+
+  fn rdf(&mut self) -> Result<Channel<'a>, XmlParseError> {
+    let start = loop {
+      match self.inner.reader.next_event()? {
+        Some(XmlEvent::Start(start))
+          if start.local_name() == "RDF" && start.namespace() == Some(RDF_NAMESPACE) =>
+        {
+          break start
+        }
+        Some(XmlEvent::Start(start)) => {
+          return Err(XmlParseError::UnexpectedElement {
+            position: start.position(),
+          })
+        }
+        Some(_) => continue,
+        None => return Err(XmlParseError::UnexpectedEof),
+      }
+    };
+
+    let attributes = self.inner.read_attributes(&start, &[])?;
+    let mut channel = None;
+    let mut image = None;
+    let mut items = Vec::new();
+    let mut text_input = None;
+
+    while let Some((vocabulary, child)) = self.next_child()? {
+      match (vocabulary, child.local_name()) {
+        (Vocabulary::Rss, "channel") if channel.is_none() => channel = Some(self.channel(child)?),
+        (Vocabulary::Rss, "image") => image = Some(self.image(child)?),
+        (Vocabulary::Rss, "item") => items.push(self.item(child)?),
+        (Vocabulary::Rss, "textinput" | "textInput") => text_input = Some(self.text_input(child)?),
+        _ => {
+          self.inner.reader.skip_element()?;
+        }
+      }
+    }
+
+    let mut channel = channel.ok_or(XmlParseError::MissingElement { name: "channel" })?;
+    channel.attributes = attributes;
+    channel.image = image;
+    channel.items = items;
+    channel.text_input = text_input;
+
+    Ok(channel)
+  }
+
+  fn channel(&mut self, start: StartElement<'a>) -> Result<Channel<'a>, XmlParseError> {
+    let (about, _) = self.about(&start)?;
+    let mut dublin_core = DublinCore::default();
+    let mut description = None;
+    let mut link = None;
+    let mut title = None;
+
+    while let Some((vocabulary, child)) = self.next_child()? {
+      match (vocabulary, child.local_name()) {
+        (Vocabulary::Rss, "title") => title = Some(self.inner.read_text("title", &child)?),
+        (Vocabulary::Rss, "link") => link = Some(self.inner.read_value("link", &child)?),
+        (Vocabulary::Rss, "description") => {
+          description = Some(self.inner.read_text("description", &child)?)
+        }
+        (Vocabulary::DublinCore, _) => self.dublin_core(&mut dublin_core, child)?,
+        _ => {
+          self.inner.reader.skip_element()?;
+        }
+      }
+    }
+
+    let mut channel = Channel::new(
+      about,
+      self.inner.required(title, "title", &start),
+      self.inner.required(link, "link", &start),
+      self.inner.required(description, "description", &start),
+    );
+    channel.dublin_core = dublin_core;
+
+    Ok(channel)
+  }
+
+  fn item(&mut self, start: StartElement<'a>) -> Result<Item<'a>, XmlParseError> {
+    let (about, attributes) = self.about(&start)?;
+    let mut dublin_core = DublinCore::default();
+    let mut description = None;
+    let mut link = None;
+    let mut title = None;
+
+    while let Some((vocabulary, child)) = self.next_child()? {
+      match (vocabulary, child.local_name()) {
+        (Vocabulary::Rss, "title") => title = Some(self.inner.read_text("title", &child)?),
+        (Vocabulary::Rss, "link") => link = Some(self.inner.read_value("link", &child)?),
+        (Vocabulary::Rss, "description") => {
+          description = Some(self.inner.read_text("description", &child)?)
+        }
+        (Vocabulary::DublinCore, _) => self.dublin_core(&mut dublin_core, child)?,
+        _ => {
+          self.inner.reader.skip_element()?;
+        }
+      }
+    }
+
+    let mut item = Item::new(
+      self.inner.required(title, "title", &start),
+      self.inner.required(link, "link", &start),
+    );
+    item.about = about;
+    item.attributes = attributes;
+    item.description = description;
+    item.dublin_core = dublin_core;
+
+    Ok(item)
+  }
+
+  fn image(&mut self, start: StartElement<'a>) -> Result<Image<'a>, XmlParseError> {
+    let (about, attributes) = self.about(&start)?;
+    let mut link = None;
+    let mut title = None;
+    let mut url = None;
+
+    while let Some((vocabulary, child)) = self.next_child()? {
+      match (vocabulary, child.local_name()) {
+        (Vocabulary::Rss, "title") => title = Some(self.inner.read_text("title", &child)?),
+        (Vocabulary::Rss, "url") => url = Some(self.inner.read_value("url", &child)?),
+        (Vocabulary::Rss, "link") => link = Some(self.inner.read_value("link", &child)?),
+        _ => {
+          self.inner.reader.skip_element()?;
+        }
+      }
+    }
+
+    let mut image = Image::new(
+      self.inner.required(url, "url", &start),
+      self.inner.required(title, "title", &start),
+      self.inner.required(link, "link", &start),
+    );
+    image.about = about;
+    image.attributes = attributes;
+
+    Ok(image)
+  }
+
+  fn text_input(&mut self, start: StartElement<'a>) -> Result<TextInput<'a>, XmlParseError> {
+    let (about, attributes) = self.about(&start)?;
+    let mut description = None;
+    let mut link = None;
+    let mut name = None;
+    let mut title = None;
+
+    while let Some((vocabulary, child)) = self.next_child()? {
+      match (vocabulary, child.local_name()) {
+        (Vocabulary::Rss, "title") => title = Some(self.inner.read_text("title", &child)?),
+        (Vocabulary::Rss, "description") => {
+          description = Some(self.inner.read_text("description", &child)?)
+        }
+        (Vocabulary::Rss, "name") => name = Some(self.inner.read_value("name", &child)?),
+        (Vocabulary::Rss, "link") => link = Some(self.inner.read_value("link", &child)?),
+        _ => {
+          self.inner.reader.skip_element()?;
+        }
+      }
+    }
+
+    let mut text_input = TextInput::new(
+      self.inner.required(title, "title", &start),
+      self.inner.required(description, "description", &start),
+      self.inner.required(name, "name", &start),
+      self.inner.required(link, "link", &start),
+    );
+    text_input.about = about;
+    text_input.attributes = attributes;
+
+    Ok(text_input)
+  }
+
+  fn dublin_core(
+    &mut self,
+    dublin_core: &mut DublinCore<'a>,
+    start: StartElement<'a>,
+  ) -> Result<(), XmlParseError> {
+    let parser = &mut self.inner;
+
+    match start.local_name() {
+      "title" => dublin_core.title = Some(parser.read_text("dc:title", &start)?),
+      "creator" => dublin_core.creator = Some(parser.read_text("dc:creator", &start)?),
+      "subject" => dublin_core.subject = Some(parser.read_text("dc:subject", &start)?),
+      "description" => dublin_core.description = Some(parser.read_text("dc:description", &start)?),
+      "publisher" => dublin_core.publisher = Some(parser.read_text("dc:publisher", &start)?),
+      "contributor" => dublin_core.contributor = Some(parser.read_text("dc:contributor", &start)?),
+      "date" => dublin_core.date = parser.read_timestamp("dc:date", &start)?,
+      "type" => dublin_core.resource_type = Some(parser.read_text("dc:type", &start)?),
+      "format" => dublin_core.format = Some(parser.read_value("dc:format", &start)?),
+      "identifier" => dublin_core.identifier = Some(parser.read_value("dc:identifier", &start)?),
+      "source" => dublin_core.source = Some(parser.read_text("dc:source", &start)?),
+      "language" => dublin_core.language = Some(parser.read_value("dc:language", &start)?),
+      "relation" => dublin_core.relation = Some(parser.read_text("dc:relation", &start)?),
+      "coverage" => dublin_core.coverage = Some(parser.read_text("dc:coverage", &start)?),
+      "rights" => dublin_core.rights = Some(parser.read_text("dc:rights", &start)?),
+      _ => {
+        parser.reader.skip_element()?;
+      }
+    }
+
+    Ok(())
+  }
+}
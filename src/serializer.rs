@@ -1,5 +1,59 @@
 #[cfg(feature = "std")]
 pub mod formatter;
+mod namespace;
+
+pub use namespace::*;
+
+/// Derives [Serialize] for structs, see the attribute list below.
+///
+/// ```
+/// use syndication_format::serializer::{
+///   formatter::DefaultWriter, NamespaceContext, Serialize, Serializer, XmlSerializer,
+/// };
+///
+/// #[derive(Serialize)]
+/// #[xml(element = "thumbnail", namespace = "http://search.yahoo.com/mrss/")]
+/// struct Thumbnail<'a> {
+///   #[xml(attribute)]
+///   url: &'a str,
+///   #[xml(attribute, skip_if_none)]
+///   width: Option<u32>,
+/// }
+///
+/// #[derive(Serialize)]
+/// #[xml(element = "group", namespace = "http://search.yahoo.com/mrss/")]
+/// struct Group<'a> {
+///   #[xml(element = "title")]
+///   title: &'a str,
+///   thumbnails: Vec<Thumbnail<'a>>,
+/// }
+///
+/// let group = Group {
+///   title: "Media & more",
+///   thumbnails: vec![Thumbnail { url: "https://example.org/1.jpg", width: Some(75) }],
+/// };
+///
+/// let mut namespaces = NamespaceContext::new();
+/// namespaces.bind("media", "http://search.yahoo.com/mrss/").unwrap();
+///
+/// let mut bytes: Vec<u8> = Vec::new();
+/// let mut writer = DefaultWriter::new(&mut bytes);
+/// XmlSerializer::with_namespaces(&mut writer, &namespaces)
+///   .serialize(&group, None)
+///   .unwrap();
+///
+/// assert_eq!(
+///   concat!(
+///     r#"<media:group xmlns:media="http://search.yahoo.com/mrss/">"#,
+///     r#"<media:title>Media &amp; more</media:title>"#,
+///     r#"<media:thumbnail url="https://example.org/1.jpg" width="75"/>"#,
+///     r#"</media:group>"#
+///   ),
+///   String::from_utf8(bytes).unwrap()
+/// );
+/// ```
+#[cfg(feature = "derive")]
+pub use syndication_format_derive::Serialize;
 
 use core::fmt::Arguments;
 
@@ -18,6 +72,12 @@ pub trait Write {
   fn decrement_level(&mut self);
   fn set_level(&mut self, level: usize);
   fn get_level(&self) -> usize;
+
+  /// Whether element text is written on the same line as the element tags. Line breaks and
+  /// indentation are then only added between the child elements, so the text values stay as is.
+  fn is_text_inline(&self) -> bool {
+    false
+  }
 }
 
 enum ElementType {
@@ -77,9 +137,9 @@ pub trait Serializer: Sized {
   ///
   /// Basic Example:
   /// ```
-  /// use atom_syndication_format::common::{ AttributeMap, AttributeName };
-  /// use atom_syndication_format::serializer::*;
-  /// use atom_syndication_format::error::{XmlSerializeError};
+  /// use syndication_format::common::{ AttributeMap, AttributeName };
+  /// use syndication_format::serializer::*;
+  /// use syndication_format::error::{XmlSerializeError};
   ///
   /// pub struct BasicExample {
   ///   lang: String,
@@ -126,6 +186,18 @@ pub trait Serializer: Sized {
     namespace: Option<&str>,
     attributes: Option<&AttributeMap>,
   ) -> Result<(), XmlSerializeError>;
+
+  /// Returns the prefix bound to the namespace `uri`, if there is any. Empty prefix means the
+  /// default namespace.
+  ///
+  /// Prefix is borrowed from the namespace context rather than the serializer, so it can be
+  /// passed to [Serializer::serialize_element].
+  fn namespace_prefix<'n>(&self, _uri: &str) -> Option<&'n str>
+  where
+    Self: 'n,
+  {
+    None
+  }
 }
 
 pub trait ElementSerializer {
@@ -138,6 +210,20 @@ pub trait ElementSerializer {
   fn serialize_escaped_str(self, value: &str) -> Result<(), XmlSerializeError>;
 
   fn end(self) -> Result<(), XmlSerializeError>;
+
+  /// Returns the prefix bound to the namespace `uri`, see [Serializer::namespace_prefix].
+  fn namespace_prefix<'n>(&self, _uri: &str) -> Option<&'n str>
+  where
+    Self: 'n,
+  {
+    None
+  }
+
+  /// Returns the namespace URI bound to `prefix`, if there is any. Empty prefix means the default
+  /// namespace.
+  fn namespace_uri(&self, _prefix: &str) -> Option<&str> {
+    None
+  }
 }
 
 pub struct XmlSerializer<'a, W>
@@ -145,8 +231,12 @@ where
   W: Write + ?Sized,
 {
   pub(super) writer: &'a mut W,
+  namespaces: &'a NamespaceContext,
+  is_root: bool,
 }
 
+static EMPTY_NAMESPACES: NamespaceContext = NamespaceContext::new();
+
 pub struct XmlElementSerializer<'a, W>
 where
   W: Write + ?Sized,
@@ -154,6 +244,7 @@ where
   inner: &'a mut XmlSerializer<'a, W>,
   name: Box<str>,
   namespace: Option<Box<str>>,
+  has_children: bool,
 }
 
 impl<'a, W> XmlSerializer<'a, W>
@@ -161,7 +252,83 @@ where
   W: Write + ?Sized,
 {
   pub fn new(writer: &'a mut W) -> Self {
-    Self { writer }
+    Self {
+      writer,
+      namespaces: &EMPTY_NAMESPACES,
+      is_root: true,
+    }
+  }
+
+  /// Creates a serializer which declares the `namespaces` bindings on the root element.
+  /// Declarations already present in the root attributes are not repeated, but binding a
+  /// prefix to another URI fails with [XmlSerializeError::ConflictingNamespace].
+  ///
+  /// ```
+  /// use syndication_format::{
+  ///   common::{AttributeMap, AttributeName},
+  ///   error::XmlSerializeError,
+  ///   serializer::{
+  ///     formatter::DefaultWriter, ElementSerializer, NamespaceContext, Serialize, Serializer,
+  ///     XmlSerializer,
+  ///   },
+  /// };
+  ///
+  /// const DC_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+  ///
+  /// struct Creator<'a>(&'a str);
+  ///
+  /// impl Serialize for Creator<'_> {
+  ///   fn serialize<S>(&self, serializer: S, _: Option<&str>) -> Result<(), XmlSerializeError>
+  ///   where
+  ///     S: Serializer,
+  ///   {
+  ///     let prefix = serializer.namespace_prefix(DC_NAMESPACE);
+  ///     let element = serializer.serialize_element("creator", prefix, None)?;
+  ///     element.serialize_escaped_str(self.0)
+  ///   }
+  /// }
+  ///
+  /// struct Root;
+  ///
+  /// impl Serialize for Root {
+  ///   fn serialize<S>(&self, serializer: S, _: Option<&str>) -> Result<(), XmlSerializeError>
+  ///   where
+  ///     S: Serializer,
+  ///   {
+  ///     let mut root = serializer.serialize_element("root", None, None)?;
+  ///     root.serialize(Creator("Jane"), None)?;
+  ///     root.end()
+  ///   }
+  /// }
+  ///
+  /// let mut namespaces = NamespaceContext::new();
+  /// namespaces.bind("dc", DC_NAMESPACE).unwrap();
+  ///
+  /// let mut bytes: Vec<u8> = Vec::new();
+  /// let mut writer = DefaultWriter::new(&mut bytes);
+  /// XmlSerializer::with_namespaces(&mut writer, &namespaces)
+  ///   .serialize(Root, None)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(
+  ///   r#"<root xmlns:dc="http://purl.org/dc/elements/1.1/"><dc:creator>Jane</dc:creator></root>"#,
+  ///   String::from_utf8(bytes).unwrap()
+  /// );
+  /// ```
+  pub fn with_namespaces(writer: &'a mut W, namespaces: &'a NamespaceContext) -> Self {
+    Self {
+      writer,
+      namespaces,
+      is_root: true,
+    }
+  }
+
+  /// Writes the `<?xml version="1.0" encoding="utf-8"?>` declaration. Call it before
+  /// serializing the root element, the output must be UTF-8 encoded.
+  pub fn serialize_declaration(&mut self) -> Result<(), XmlSerializeError> {
+    self
+      .writer
+      .write_line(r#"<?xml version="1.0" encoding="utf-8"?>"#)
   }
 
   #[inline]
@@ -172,7 +339,8 @@ where
     attributes: Option<&AttributeMap>,
     element_type: ElementType,
   ) -> Result<(), XmlSerializeError> {
-    if let Some(namespace) = namespace {
+    // Empty prefix is the default namespace binding, see [Serializer::namespace_prefix].
+    if let Some(namespace) = namespace.filter(|prefix| !prefix.is_empty()) {
       self
         .writer
         .write_fmt(format_args!("<{}:{}", namespace, name))?;
@@ -190,8 +358,18 @@ where
       }
     }
 
+    if self.is_root {
+      self.is_root = false;
+      self.declare_namespaces(attributes)?;
+    }
+
     match element_type {
       ElementType::Empty => self.writer.write_line("/>")?,
+      ElementType::NonEmpty if self.writer.is_text_inline() => {
+        // Line break is written before the first child element, if there's any.
+        self.writer.write(">")?;
+        self.writer.increment_level();
+      }
       ElementType::NonEmpty => {
         self.writer.write_line(">")?;
         self.writer.increment_level();
@@ -201,6 +379,32 @@ where
     Ok(())
   }
 
+  /// Writes the namespace bindings which are not declared by the root attributes.
+  fn declare_namespaces(
+    &mut self,
+    attributes: Option<&AttributeMap>,
+  ) -> Result<(), XmlSerializeError> {
+    for binding in self.namespaces.iter() {
+      let name = binding.attribute_name();
+
+      match attributes.and_then(|attributes| attributes.get(&name)) {
+        Some(value) if value.as_ref() == binding.uri.as_ref() => (),
+        Some(_) => {
+          return Err(XmlSerializeError::ConflictingNamespace {
+            prefix: binding.prefix.clone(),
+          })
+        }
+        None => self.writer.write_fmt(format_args!(
+          " {}=\"{}\"",
+          name,
+          escape_xml_attr!(&binding.uri)
+        ))?,
+      }
+    }
+
+    Ok(())
+  }
+
   #[inline]
   fn close_element(
     &mut self,
@@ -209,7 +413,7 @@ where
   ) -> Result<(), XmlSerializeError> {
     self.writer.decrement_level();
 
-    if let Some(namespace) = namespace {
+    if let Some(namespace) = namespace.filter(|prefix| !prefix.is_empty()) {
       self
         .writer
         .write_fmt(format_args!("</{}:{}>", namespace, name))?;
@@ -254,6 +458,7 @@ where
       inner: self,
       name: Box::from(name),
       namespace: namespace.map(Box::from),
+      has_children: false,
     })
   }
 
@@ -272,6 +477,14 @@ where
     escape_writer(value, self.writer, XML_ESCAPE_PATTERNS)?;
     Ok(())
   }
+
+  fn namespace_prefix<'n>(&self, uri: &str) -> Option<&'n str>
+  where
+    Self: 'n,
+  {
+    let namespaces: &'a NamespaceContext = self.namespaces;
+    namespaces.prefix(uri)
+  }
 }
 
 impl<'a, W> ElementSerializer for XmlElementSerializer<'a, W>
@@ -282,15 +495,28 @@ where
   where
     V: Serialize,
   {
+    if !self.has_children && self.inner.writer.is_text_inline() {
+      self.inner.writer.write_line("")?;
+    }
+
+    self.has_children = true;
+
     let mut ser = XmlSerializer {
       writer: self.inner.writer,
+      namespaces: self.inner.namespaces,
+      is_root: false,
     };
 
     value.serialize(&mut ser, namespace)
   }
 
   fn serialize_str(self, value: &str) -> Result<(), XmlSerializeError> {
-    self.inner.writer.write_line(value)?;
+    if self.inner.writer.is_text_inline() {
+      self.inner.writer.write(value)?;
+    } else {
+      self.inner.writer.write_line(value)?;
+    }
+
     self.end()
   }
 
@@ -302,7 +528,59 @@ where
 
   fn serialize_escaped_str(self, value: &str) -> Result<(), XmlSerializeError> {
     escape_writer(value, self.inner.writer, XML_ESCAPE_PATTERNS)?;
-    self.inner.writer.write_line("")?;
+
+    if !self.inner.writer.is_text_inline() {
+      self.inner.writer.write_line("")?;
+    }
+
     self.end()
   }
+
+  fn namespace_prefix<'n>(&self, uri: &str) -> Option<&'n str>
+  where
+    Self: 'n,
+  {
+    let namespaces: &'a NamespaceContext = self.inner.namespaces;
+    namespaces.prefix(uri)
+  }
+
+  fn namespace_uri(&self, prefix: &str) -> Option<&str> {
+    self.inner.namespaces.uri(prefix)
+  }
+}
+
+/// Generic helper node to simplify some property serializations. Value is escaped, nodes without
+/// value are written as empty elements.
+pub struct ElementNode<'a> {
+  pub(crate) attributes: Option<&'a AttributeMap>,
+  pub(crate) name: &'a str,
+  pub(crate) value: Option<&'a str>,
+}
+
+impl<'a> ElementNode<'a> {
+  #[inline]
+  pub fn new(name: &'a str, attributes: Option<&'a AttributeMap>, value: Option<&'a str>) -> Self {
+    Self {
+      attributes,
+      name,
+      value,
+    }
+  }
+}
+
+impl Serialize for ElementNode<'_> {
+  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: Serializer,
+  {
+    match self.value {
+      Some(value) => {
+        let element = serializer.serialize_element(self.name, namespace, self.attributes)?;
+        element.serialize_escaped_str(value)?;
+      }
+      None => serializer.serialize_empty_element(self.name, namespace, self.attributes)?,
+    }
+
+    Ok(())
+  }
 }
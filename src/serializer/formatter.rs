@@ -31,6 +31,7 @@ where
   tab_spaces: usize,
   space_style: SpaceStyle,
   is_next_line: bool,
+  is_text_inline: bool,
 }
 
 impl<'a, W> Write for DefaultWriter<'a, W>
@@ -143,22 +144,67 @@ where
   fn get_level(&self) -> usize {
     self.level
   }
+
+  #[inline]
+  fn is_text_inline(&self) -> bool {
+    self.is_text_inline
+  }
 }
 
 impl<'a, W> IndentedWriter<'a, W>
 where
   W: std::io::Write,
 {
+  /// Creates a writer which puts the element text on its own indented line.
   pub fn new(writer: &'a mut W, space_style: SpaceStyle, tab_spaces: usize) -> Self {
     Self {
       inner_writer: writer,
       tab_spaces,
       space_style,
       is_next_line: false,
+      is_text_inline: false,
       level: 0,
     }
   }
 
+  /// Creates a writer which keeps the text and mixed content on the same line as the element
+  /// tags and indents only the child elements. Unlike [IndentedWriter::new], the output has
+  /// the same text values as the compact output.
+  ///
+  /// ```
+  /// use syndication_format::{
+  ///   atom::Feed,
+  ///   common::{TimeStamp, XmlText},
+  ///   serializer::{
+  ///     formatter::{IndentedWriter, SpaceStyle},
+  ///     Serializer, XmlSerializer,
+  ///   },
+  /// };
+  ///
+  /// let mut feed = Feed::new("urn:feed", XmlText::PlainText("Feed"), TimeStamp::default());
+  /// feed.generator = None;
+  ///
+  /// let mut bytes: Vec<u8> = Vec::new();
+  /// let mut writer = IndentedWriter::with_inline_text(&mut bytes, SpaceStyle::WhiteSpace, 2);
+  /// XmlSerializer::new(&mut writer).serialize(feed, None).unwrap();
+  ///
+  /// assert_eq!(
+  ///   r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  ///   <title type="text">Feed</title>
+  ///   <id>urn:feed</id>
+  ///   <updated>1970-01-01T00:00:00Z</updated>
+  /// </feed>
+  /// "#,
+  ///   String::from_utf8(bytes).unwrap()
+  /// );
+  /// ```
+  pub fn with_inline_text(writer: &'a mut W, space_style: SpaceStyle, tab_spaces: usize) -> Self {
+    Self {
+      is_text_inline: true,
+      ..Self::new(writer, space_style, tab_spaces)
+    }
+  }
+
   #[inline]
   fn write_indentation(&mut self) -> core::result::Result<(), XmlSerializeError> {
     let space_size = self.tab_spaces.saturating_mul(self.level);
@@ -0,0 +1,98 @@
+use crate::{error::XmlSerializeError, utils::xml_name_token::is_valid_name};
+
+pub struct NamespaceBinding {
+  /// Bound prefix, empty for the default namespace.
+  pub prefix: Box<str>,
+  pub uri: Box<str>,
+}
+
+impl NamespaceBinding {
+  /// Name of the declaring attribute, `xmlns` or `xmlns:prefix`.
+  pub fn attribute_name(&self) -> String {
+    if self.prefix.is_empty() {
+      String::from("xmlns")
+    } else {
+      format!("xmlns:{}", self.prefix)
+    }
+  }
+}
+
+/// Prefix to namespace URI bindings used by [super::XmlSerializer]. All bindings are declared
+/// once on the root element.
+///
+/// ```
+/// use syndication_format::serializer::NamespaceContext;
+///
+/// let mut namespaces = NamespaceContext::new();
+/// namespaces
+///   .bind("dc", "http://purl.org/dc/elements/1.1/")
+///   .unwrap()
+///   .bind("media", "http://search.yahoo.com/mrss/")
+///   .unwrap();
+///
+/// assert_eq!(Some("dc"), namespaces.prefix("http://purl.org/dc/elements/1.1/"));
+/// assert!(namespaces.bind("dc", "https://example.org/other").is_err());
+/// ```
+#[derive(Default)]
+pub struct NamespaceContext {
+  bindings: Vec<NamespaceBinding>,
+}
+
+impl NamespaceContext {
+  pub const fn new() -> Self {
+    Self {
+      bindings: Vec::new(),
+    }
+  }
+
+  /// Binds `prefix` to `uri`, use an empty prefix for the default namespace. Binding the same
+  /// pair again is a no-op, binding a prefix to another URI fails with
+  /// [XmlSerializeError::ConflictingNamespace].
+  pub fn bind(&mut self, prefix: &str, uri: &str) -> Result<&mut Self, XmlSerializeError> {
+    let is_reserved = prefix.eq_ignore_ascii_case("xml") || prefix.eq_ignore_ascii_case("xmlns");
+
+    if !prefix.is_empty() && (is_reserved || prefix.contains(':') || !is_valid_name(prefix)) {
+      return Err(XmlSerializeError::InvalidNamespace);
+    }
+
+    match self.uri(prefix) {
+      Some(bound) if bound == uri => Ok(self),
+      Some(_) => Err(XmlSerializeError::ConflictingNamespace {
+        prefix: Box::from(prefix),
+      }),
+      None => {
+        self.bindings.push(NamespaceBinding {
+          prefix: Box::from(prefix),
+          uri: Box::from(uri),
+        });
+
+        Ok(self)
+      }
+    }
+  }
+
+  /// Returns the first prefix bound to `uri`.
+  pub fn prefix(&self, uri: &str) -> Option<&str> {
+    self
+      .bindings
+      .iter()
+      .find(|binding| binding.uri.as_ref() == uri)
+      .map(|binding| binding.prefix.as_ref())
+  }
+
+  pub fn uri(&self, prefix: &str) -> Option<&str> {
+    self
+      .bindings
+      .iter()
+      .find(|binding| binding.prefix.as_ref() == prefix)
+      .map(|binding| binding.uri.as_ref())
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.bindings.is_empty()
+  }
+
+  pub fn iter(&self) -> core::slice::Iter<'_, NamespaceBinding> {
+    self.bindings.iter()
+  }
+}
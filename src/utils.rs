@@ -1,2 +1,3 @@
+pub(crate) mod base64;
 pub(crate) mod byte_search;
 pub(crate) mod xml_name_token;
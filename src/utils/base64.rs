@@ -0,0 +1,95 @@
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PADDING: u8 = b'=';
+
+/// Encodes with the standard RFC4648 alphabet and padding.
+pub(crate) fn encode(input: &[u8]) -> String {
+  let mut output = String::with_capacity(input.len().div_ceil(3) * 4);
+
+  for chunk in input.chunks(3) {
+    let b0 = chunk[0] as u32;
+    let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+    let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+    let group = (b0 << 16) | (b1 << 8) | b2;
+
+    for idx in 0..4 {
+      if idx <= chunk.len() {
+        let sextet = (group >> (18 - idx * 6)) & 0x3F;
+        output.push(ALPHABET[sextet as usize] as char);
+      } else {
+        output.push(PADDING as char);
+      }
+    }
+  }
+
+  output
+}
+
+/// Decodes standard alphabet input. Whitespace is ignored since the encoded text is commonly
+/// line wrapped, padding is optional. Returns `None` for invalid input.
+pub(crate) fn decode(input: &str) -> Option<Vec<u8>> {
+  let mut output = Vec::with_capacity(input.len() / 4 * 3);
+  let mut group = 0u32;
+  let mut length = 0;
+  let mut padding = 0;
+
+  for c in input.bytes().filter(|c| !c.is_ascii_whitespace()) {
+    let sextet = match c {
+      b'A'..=b'Z' => c - b'A',
+      b'a'..=b'z' => c - b'a' + 26,
+      b'0'..=b'9' => c - b'0' + 52,
+      b'+' => 62,
+      b'/' => 63,
+      PADDING => {
+        padding += 1;
+        continue;
+      }
+      _ => return None,
+    };
+
+    // Data after the padding.
+    if padding > 0 {
+      return None;
+    }
+
+    group = (group << 6) | sextet as u32;
+    length += 1;
+
+    if length == 4 {
+      output.extend_from_slice(&group.to_be_bytes()[1..]);
+      group = 0;
+      length = 0;
+    }
+  }
+
+  match (length, padding) {
+    (0, 0) => (),
+    (2, 0 | 2) => output.push((group >> 4) as u8),
+    (3, 0 | 1) => output.extend_from_slice(&((group >> 2) as u16).to_be_bytes()),
+    _ => return None,
+  }
+
+  Some(output)
+}
+
+/// Serializes bytes as a Base64 string, used with `#[serde(serialize_with)]`.
+#[cfg(feature = "serde")]
+pub(crate) fn serialize<S>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: serde::Serializer,
+{
+  serializer.serialize_str(&encode(data))
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  use serde::Deserialize;
+
+  let value = String::deserialize(deserializer)?;
+
+  decode(&value).ok_or_else(|| {
+    serde::de::Error::invalid_value(serde::de::Unexpected::Str(&value), &"a Base64 string")
+  })
+}
@@ -2,71 +2,71 @@ pub(crate) fn is_valid_name(name: &str) -> bool {
   let mut chars_iter = name.chars();
 
   if let Some(first_char) = chars_iter.next() {
-    if name_start_char(first_char) == false {
+    if !name_start_char(first_char) {
       return false;
     }
 
     for char in name.chars() {
-      if name_char(char) == false {
+      if !name_char(char) {
         return false;
       }
     }
 
-    return true;
+    true
   } else {
     false
   }
 }
 
 #[inline]
-fn name_start_char<'a>(value: char) -> bool {
-  match value {
-    ':' => true,
-    'A'..'Z' => true,
-    '_' => true,
-    'a'..'z' => true,
-    '\u{C0}'..='\u{D6}' => true,
-    '\u{D8}'..='\u{F6}' => true,
-    '\u{F8}'..='\u{2FF}' => true,
-    '\u{370}'..='\u{37D}' => true,
-    '\u{37F}'..='\u{1FFF}' => true,
-    '\u{200C}'..='\u{200D}' => true,
-    '\u{2070}'..='\u{218F}' => true,
-    '\u{2C00}'..='\u{2FEF}' => true,
-    '\u{3001}'..='\u{D7FF}' => true,
-    '\u{F900}'..='\u{FDCF}' => true,
-    '\u{FDF0}'..='\u{FFFD}' => true,
-    '\u{10000}'..='\u{EFFFF}' => true,
-    _ => false,
-  }
+fn name_start_char(value: char) -> bool {
+  matches!(
+    value,
+    ':'
+      | 'A'..='Z'
+      | '_'
+      | 'a'..='z'
+      | '\u{C0}'..='\u{D6}'
+      | '\u{D8}'..='\u{F6}'
+      | '\u{F8}'..='\u{2FF}'
+      | '\u{370}'..='\u{37D}'
+      | '\u{37F}'..='\u{1FFF}'
+      | '\u{200C}'..='\u{200D}'
+      | '\u{2070}'..='\u{218F}'
+      | '\u{2C00}'..='\u{2FEF}'
+      | '\u{3001}'..='\u{D7FF}'
+      | '\u{F900}'..='\u{FDCF}'
+      | '\u{FDF0}'..='\u{FFFD}'
+      | '\u{10000}'..='\u{EFFFF}'
+  )
 }
 
 #[inline]
-fn name_char<'a>(value: char) -> bool {
-  match value {
-    '.' => true,
-    '-' => true,
-    '0'..='9' => true,
-    ':' => true,
-    'A'..'Z' => true,
-    '_' => true,
-    'a'..'z' => true,
-    '\u{C0}'..='\u{D6}' => true,
-    '\u{D8}'..='\u{F6}' => true,
-    '\u{F8}'..='\u{2FF}' => true,
-    '\u{300}'..='\u{36F}' => true,
-    '\u{370}'..='\u{37D}' => true,
-    '\u{37F}'..='\u{1FFF}' => true,
-    '\u{203F}'..='\u{2040}' => true,
-    '\u{200C}'..='\u{200D}' => true,
-    '\u{2070}'..='\u{218F}' => true,
-    '\u{2C00}'..='\u{2FEF}' => true,
-    '\u{3001}'..='\u{D7FF}' => true,
-    '\u{F900}'..='\u{FDCF}' => true,
-    '\u{FDF0}'..='\u{FFFD}' => true,
-    '\u{10000}'..='\u{EFFFF}' => true,
-    _ => false,
-  }
+fn name_char(value: char) -> bool {
+  matches!(
+    value,
+    '.'
+      | '-'
+      | '0'..='9'
+      | ':'
+      | 'A'..='Z'
+      | '_'
+      | 'a'..='z'
+      | '\u{C0}'..='\u{D6}'
+      | '\u{D8}'..='\u{F6}'
+      | '\u{F8}'..='\u{2FF}'
+      | '\u{300}'..='\u{36F}'
+      | '\u{370}'..='\u{37D}'
+      | '\u{37F}'..='\u{1FFF}'
+      | '\u{203F}'..='\u{2040}'
+      | '\u{200C}'..='\u{200D}'
+      | '\u{2070}'..='\u{218F}'
+      | '\u{2C00}'..='\u{2FEF}'
+      | '\u{3001}'..='\u{D7FF}'
+      | '\u{F900}'..='\u{FDCF}'
+      | '\u{FDF0}'..='\u{FFFD}'
+      | '\u{10000}'..='\u{EFFFF}'
+  )
 }
 
 #[cfg(test)]
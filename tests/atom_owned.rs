@@ -0,0 +1,98 @@
+use syndication_format::{
+  atom::{
+    self,
+    owned::{Author, Content, ContentValue, Entry, Feed, Link, Source, Title, XmlText},
+  },
+  common::{self, LinkRelation, TimeStamp},
+  reader::StringArena,
+  serializer::{formatter::DefaultWriter, Serialize, Serializer, XmlSerializer},
+};
+
+fn to_xml<V: Serialize>(value: V) -> String {
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  XmlSerializer::new(&mut writer)
+    .serialize(value, None)
+    .unwrap();
+
+  String::from_utf8(bytes).unwrap()
+}
+
+fn build_feed(count: usize) -> Feed {
+  let mut feed = Feed::new(
+    "urn:feed",
+    XmlText::PlainText(String::from("Feed & more")),
+    TimeStamp::default(),
+  );
+  feed.generator = None;
+  feed.authors.push(Author::new("John Doe"));
+
+  let mut link = Link::new("https://example.org/feed.atom");
+  link.rel = Some(LinkRelation::SelfRel);
+  feed.links.push(link);
+
+  for idx in 0..count {
+    let mut entry = Entry::new(
+      format!("urn:entry:{}", idx),
+      XmlText::PlainText(format!("Entry {}", idx)),
+      TimeStamp::default(),
+    );
+    entry.content = Some(Content::new(ContentValue::InlinedMedia {
+      media_type: String::from("application/octet-stream"),
+      data: vec![idx as u8; 3],
+    }));
+
+    let mut source = Source::new();
+    source.title = Some(Title::new(XmlText::Html(format!("<b>Origin {}</b>", idx))));
+    entry.source = Some(source);
+
+    feed.entries.push(entry);
+  }
+
+  feed
+}
+
+#[test]
+fn owned_feed_serialization() {
+  let feed = build_feed(2);
+  let xml = to_xml(&feed);
+
+  assert_eq!(xml, to_xml(feed.as_borrowed()));
+  assert!(xml.contains(concat!(
+    r#"<entry><content type="application/octet-stream">AAAA</content>"#,
+    r#"<source><title type="html">&lt;b&gt;Origin 0&lt;/b&gt;</title></source>"#,
+    r#"<title type="text">Entry 0</title><id>urn:entry:0</id>"#
+  )));
+
+  let borrowed = feed.as_borrowed();
+  assert_eq!("urn:entry:1", borrowed.entries[1].id.value);
+  assert!(matches!(
+    borrowed.title.value,
+    common::XmlText::PlainText("Feed & more")
+  ));
+}
+
+#[test]
+fn owned_feed_from_parsed() {
+  let xml = to_xml(build_feed(3));
+
+  let owned = {
+    let arena = StringArena::new();
+    let feed = atom::parse(&xml, &arena).unwrap();
+    Feed::from(&feed)
+  };
+
+  assert_eq!(3, owned.entries.len());
+  assert!(matches!(
+    &owned.entries[2].content.as_ref().unwrap().value,
+    ContentValue::InlinedMedia { data, .. } if data == &[2, 2, 2]
+  ));
+  assert_eq!(xml, to_xml(&owned));
+
+  // Cached feeds can be modified and serialized again.
+  let mut cached = owned.clone();
+  cached.entries.truncate(1);
+  cached.entries[0].id.value.push_str(":updated");
+  assert!(to_xml(&cached).contains("<id>urn:entry:0:updated</id>"));
+  assert_eq!(xml, to_xml(&owned));
+}
@@ -0,0 +1,423 @@
+use std::str::from_utf8;
+
+use syndication_format::{
+  atom::{
+    self, Author, Category, Content, ContentValue, Entry, Feed, Id, Link, Published, Source,
+    Summary, Title,
+  },
+  common::{AttributeName, LinkRelation, TimeStamp, XmlText},
+  error::XmlParseError,
+  html,
+  reader::StringArena,
+  serializer::{formatter::DefaultWriter, Serializer, XmlSerializer},
+  text,
+};
+
+const RFC_EXAMPLE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title type="text">dive into mark</title>
+  <subtitle type="html">
+    A &lt;em&gt;lot&lt;/em&gt; of effort
+    went into making this effortless
+  </subtitle>
+  <updated>2005-07-31T12:29:29Z</updated>
+  <id>tag:example.org,2003:3</id>
+  <link rel="alternate" type="text/html"
+   hreflang="en" href="http://example.org/"/>
+  <link rel="self" type="application/atom+xml"
+   href="http://example.org/feed.atom"/>
+  <rights>Copyright (c) 2003, Mark Pilgrim</rights>
+  <generator uri="http://www.example.com/" version="1.0">
+    Example Toolkit
+  </generator>
+  <entry>
+    <title>Atom draft-07 snapshot</title>
+    <link rel="alternate" type="text/html"
+     href="http://example.org/2005/04/02/atom"/>
+    <link rel="enclosure" type="audio/mpeg" length="1337"
+     href="http://example.org/audio/ph34r_my_podcast.mp3"/>
+    <id>tag:example.org,2003:3.2397</id>
+    <updated>2005-07-31T12:29:29Z</updated>
+    <author>
+      <name>Mark Pilgrim</name>
+      <uri>http://example.org/</uri>
+      <email>f8dy@example.com</email>
+    </author>
+    <contributor>
+      <name>Sam Ruby</name>
+    </contributor>
+    <contributor>
+      <name>Joe Gregorio</name>
+    </contributor>
+    <content type="xhtml" xml:lang="en"
+     xml:base="http://diveintomark.org/">
+      <div xmlns="http://www.w3.org/1999/xhtml">
+        <p><i>[Update: The Atom draft is finished.]</i></p>
+      </div>
+    </content>
+  </entry>
+</feed>"#;
+
+fn to_xml(feed: &Feed<'_>) -> String {
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  let mut xml_serializer = XmlSerializer::new(&mut writer);
+
+  xml_serializer.serialize(feed, None).unwrap();
+
+  String::from(from_utf8(&bytes).unwrap())
+}
+
+#[test]
+fn parse_rfc_example() {
+  let arena = StringArena::new();
+  let feed = atom::parse(RFC_EXAMPLE, &arena).unwrap();
+
+  assert_eq!("tag:example.org,2003:3", feed.id.value);
+  assert!(matches!(
+    feed.title.value,
+    XmlText::PlainText("dive into mark")
+  ));
+  assert!(matches!(
+    feed.subtitle.as_ref().map(|v| &v.value),
+    Some(XmlText::Html(value)) if value.contains("A <em>lot</em> of effort")
+  ));
+  assert_eq!("2005-07-31T12:29:29Z", feed.updated.value.to_string());
+  assert_eq!(2, feed.links.len());
+  assert!(matches!(feed.links[0].rel, Some(LinkRelation::Alternate)));
+  assert_eq!(Some("en"), feed.links[0].hreflang);
+  assert_eq!(Some("application/atom+xml"), feed.links[1].link_type);
+
+  let generator = feed.generator.as_ref().unwrap();
+  assert_eq!("Example Toolkit", generator.value.trim());
+  assert_eq!(Some("1.0"), generator.version);
+
+  let entry = &feed.entries[0];
+  assert_eq!(1, feed.entries.len());
+  assert_eq!("Mark Pilgrim", entry.authors[0].name);
+  assert_eq!(Some("f8dy@example.com"), entry.authors[0].email);
+  assert_eq!(2, entry.contributors.len());
+  assert_eq!(Some(1337), entry.links[1].length);
+
+  let content = entry.content.as_ref().unwrap();
+  assert!(matches!(
+    content.value,
+    ContentValue::TextContent { text: XmlText::Xhtml(value) } if value.trim().starts_with("<div xmlns=")
+  ));
+}
+
+#[test]
+fn parse_serialized_feed() {
+  let mut feed = Feed::new("urn:feed", text!("Hello & World"), TimeStamp::default());
+  let mut author = Author::new("SuperiorOne");
+  author.email = Some("pm@smdd.dev");
+  author.uri = Some("https://smdd.dev?a=1&b=2");
+
+  let mut link = Link::new("https://smdd.dev");
+  link.rel = Some(LinkRelation::SelfRel);
+  link.length = Some(42);
+
+  let mut category = Category::new("Atom & RSS");
+  category.label = Some("\"Syndication\"");
+
+  feed.authors.push(author);
+  feed.links.push(link);
+  feed.categories.push(category);
+  feed
+    .title
+    .set_attribute(AttributeName::new("xml:lang").unwrap(), "en-US".into());
+
+  let mut entry = Entry::new("urn:entry:0", text!("Entry 0"), TimeStamp::default());
+  let mut content = Content::new(html!("<div> Test content for 0 </div>").into());
+  content.set_attribute(AttributeName::new("custom-attr").unwrap(), "id-0".into());
+
+  entry.content = Some(content);
+  entry.summary = Some(Summary::new(text!("Summary for 0")));
+  feed.entries.push(entry);
+
+  let serialized = to_xml(&feed);
+  let arena = StringArena::new();
+  let parsed = atom::parse(&serialized, &arena).unwrap();
+
+  assert!(matches!(
+    parsed.title.value,
+    XmlText::PlainText("Hello & World")
+  ));
+  assert_eq!("https://smdd.dev?a=1&b=2", parsed.authors[0].uri.unwrap());
+  assert_eq!("Atom & RSS", parsed.categories[0].term);
+  assert_eq!(serialized, to_xml(&parsed));
+}
+
+#[test]
+fn round_trip_trailing_entities() {
+  let mut feed = Feed::new("urn:feed", text!("a&b"), TimeStamp::default());
+  let mut category = Category::new("x<y");
+  category.label = Some("\"a\"b");
+  feed.categories.push(category);
+  feed.links.push(Link::new("https://example.org/?a=1&b"));
+
+  let serialized = to_xml(&feed);
+  let arena = StringArena::new();
+  let parsed = atom::parse(&serialized, &arena).unwrap();
+
+  assert!(matches!(parsed.title.value, XmlText::PlainText("a&b")));
+  assert_eq!("x<y", parsed.categories[0].term);
+  assert_eq!(Some("\"a\"b"), parsed.categories[0].label);
+  assert_eq!("https://example.org/?a=1&b", parsed.links[0].href);
+}
+
+#[test]
+fn parse_entry_document() {
+  let input = r#"<atom:entry xmlns:atom="http://www.w3.org/2005/Atom">
+    <atom:title>Entry</atom:title>
+    <atom:id>urn:entry</atom:id>
+    <atom:updated>2024-09-18T23:28:25.542+03:00</atom:updated>
+    <atom:content type="text/plain">Plain &amp; simple</atom:content>
+    <ext:rating xmlns:ext="https://example.org/ext">5</ext:rating>
+  </atom:entry>"#;
+
+  let arena = StringArena::new();
+  let entry = atom::parse_entry(input, &arena).unwrap();
+
+  assert_eq!("urn:entry", entry.id.value);
+  assert_eq!(
+    "2024-09-18T23:28:25.542+03:00",
+    entry.updated.value.to_string()
+  );
+  assert!(matches!(
+    entry.content.as_ref().map(|v| &v.value),
+    Some(ContentValue::InlinedMedia {
+      media_type: "text/plain",
+      data: b"Plain & simple"
+    })
+  ));
+}
+
+#[test]
+fn entry_published_and_source() {
+  let mut feed = Feed::new("urn:feed", text!("Aggregator"), TimeStamp::default());
+  feed.generator = None;
+
+  let mut source = Source::new();
+  source.id = Some(Id::new("urn:origin"));
+  source.title = Some(Title::new(text!("Origin")));
+  source.authors.push(Author::new("Jane"));
+
+  let mut link = Link::new("https://example.org/origin.atom");
+  link.rel = Some(LinkRelation::SelfRel);
+  source.links.push(link);
+
+  let mut entry = Entry::new("urn:entry", text!("Entry"), TimeStamp::default());
+  entry.published = Some(Published::new(TimeStamp::default()));
+  entry.source = Some(source);
+  feed.entries.push(entry);
+
+  let xml = to_xml(&feed);
+  assert!(xml.contains(concat!(
+    r#"<source><author><name>Jane</name></author>"#,
+    r#"<link href="https://example.org/origin.atom" rel="self"/>"#,
+    r#"<title type="text">Origin</title><id>urn:origin</id></source>"#,
+    r#"<title type="text">Entry</title><id>urn:entry</id>"#,
+    r#"<updated>1970-01-01T00:00:00Z</updated><published>1970-01-01T00:00:00Z</published>"#,
+  )));
+
+  let arena = StringArena::new();
+  let parsed = atom::parse(&xml, &arena).unwrap();
+  let entry = &parsed.entries[0];
+  let source = entry.source.as_ref().unwrap();
+
+  assert_eq!(
+    "1970-01-01T00:00:00Z",
+    entry.published.as_ref().unwrap().value.to_string()
+  );
+  assert_eq!(Some("urn:origin"), source.id.as_ref().map(|id| id.value));
+  assert_eq!("Jane", source.authors[0].name);
+  assert!(matches!(source.links[0].rel, Some(LinkRelation::SelfRel)));
+  assert!(source.updated.is_none());
+}
+
+#[test]
+fn link_relation_registry_and_extensions() {
+  assert_eq!(Ok(LinkRelation::Hub), "hub".parse().map_err(|_| ()));
+  assert_eq!(
+    Ok(LinkRelation::EditMedia),
+    "Edit-Media".parse().map_err(|_| ())
+  );
+  assert_eq!(
+    Ok(LinkRelation::License),
+    "http://www.iana.org/assignments/relation/license"
+      .parse()
+      .map_err(|_| ())
+  );
+  assert!("unknown".parse::<LinkRelation>().is_err());
+  assert!("1tag:rel".parse::<LinkRelation>().is_err());
+  assert!("https:".parse::<LinkRelation>().is_err());
+  assert!("https://example.org/a b".parse::<LinkRelation>().is_err());
+
+  let mut feed = Feed::new("urn:feed", text!("Feed"), TimeStamp::default());
+  feed.generator = None;
+
+  let mut link = Link::new("https://example.org/similar");
+  link.rel = Some(LinkRelation::Extension(
+    "tag:example.org,2024:similar".into(),
+  ));
+  feed.links.push(link);
+
+  let xml = to_xml(&feed);
+  assert!(xml
+    .contains(r#"<link href="https://example.org/similar" rel="tag:example.org,2024:similar"/>"#));
+
+  let xml = xml.replace(
+    "<title",
+    r#"<link href="https://example.org/hub" rel="hub"/><link href="https://example.org/x" rel="x"/><title"#,
+  );
+  let arena = StringArena::new();
+  let parsed = atom::parse(&xml, &arena).unwrap();
+
+  assert_eq!(
+    Some(LinkRelation::Extension(
+      "tag:example.org,2024:similar".into()
+    )),
+    parsed.links[0].rel
+  );
+  assert_eq!(Some(LinkRelation::Hub), parsed.links[1].rel);
+  // Relations that are neither registered nor IRIs are kept as plain attributes.
+  assert_eq!(None, parsed.links[2].rel);
+  assert!(to_xml(&parsed).contains(r#"<link rel="x" href="https://example.org/x"/>"#));
+}
+
+#[test]
+fn inlined_media_encoding() {
+  let png: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00];
+  let contents = [
+    ("image/png", png, "iVBORw0KGgoA"),
+    (
+      "text/csv; charset=utf-8",
+      &b"a,b\n1,<2>"[..],
+      "a,b\n1,&lt;2&gt;",
+    ),
+    ("image/svg+xml", &b"<svg><g/></svg>"[..], "<svg><g/></svg>"),
+  ];
+
+  for (media_type, data, serialized) in contents {
+    let mut feed = Feed::new("urn:feed", text!("Feed"), TimeStamp::default());
+    feed.generator = None;
+
+    let mut entry = Entry::new("urn:entry", text!("Entry"), TimeStamp::default());
+    entry.content = Some(Content::new(ContentValue::InlinedMedia {
+      media_type,
+      data,
+    }));
+    feed.entries.push(entry);
+
+    let xml = to_xml(&feed);
+    assert!(xml.contains(&format!(
+      r#"<content type="{}">{}</content>"#,
+      media_type, serialized
+    )));
+
+    let arena = StringArena::new();
+    let parsed = atom::parse(&xml, &arena).unwrap();
+
+    assert!(matches!(
+      parsed.entries[0].content.as_ref().map(|v| &v.value),
+      Some(ContentValue::InlinedMedia { data: value, .. }) if *value == data
+    ));
+  }
+
+  let input = r#"<entry xmlns="http://www.w3.org/2005/Atom">
+    <title>Entry</title>
+    <id>urn:entry</id>
+    <updated>2024-09-18T23:28:25Z</updated>
+    <content type="application/octet-stream">
+      AAEC
+      Aw==
+    </content>
+  </entry>"#;
+
+  let arena = StringArena::new();
+  let entry = atom::parse_entry(input, &arena).unwrap();
+  assert!(matches!(
+    entry.content.as_ref().map(|v| &v.value),
+    Some(ContentValue::InlinedMedia {
+      data: [0, 1, 2, 3],
+      ..
+    })
+  ));
+
+  let input = input.replace("Aw==", "A*==");
+  assert!(matches!(
+    atom::parse_entry(&input, &arena),
+    Err(XmlParseError::InvalidBase64 { .. })
+  ));
+}
+
+#[test]
+fn parse_invalid_documents() {
+  let arena = StringArena::new();
+
+  assert!(matches!(
+    atom::parse("<feed><title>Missing id</title></feed>", &arena),
+    Err(XmlParseError::MissingElement { name: "id" })
+  ));
+  assert!(matches!(
+    atom::parse("<feed><title>Unclosed</feed>", &arena),
+    Err(XmlParseError::MismatchedEndTag { .. })
+  ));
+  assert!(matches!(
+    atom::parse("<feed><title>Unclosed</title>", &arena),
+    Err(XmlParseError::UnexpectedEof)
+  ));
+  assert!(matches!(
+    atom::parse("<atom:feed></atom:feed>", &arena),
+    Err(XmlParseError::UnboundPrefix { .. })
+  ));
+  assert!(matches!(
+    atom::parse("<rss version=\"2.0\"></rss>", &arena),
+    Err(XmlParseError::UnexpectedElement { .. })
+  ));
+  assert!(matches!(
+    atom::parse("<feed><title>&nbsp;</title></feed>", &arena),
+    Err(XmlParseError::InvalidEntity)
+  ));
+}
+
+#[test]
+fn parse_without_copying() {
+  let arena = StringArena::new();
+  let feed = atom::parse(RFC_EXAMPLE, &arena).unwrap();
+  let input_range = RFC_EXAMPLE.as_bytes().as_ptr_range();
+  let is_borrowed = |value: &str| input_range.contains(&value.as_ptr());
+
+  assert!(is_borrowed(feed.id.value));
+  assert!(is_borrowed(feed.links[0].href));
+  assert!(is_borrowed(feed.entries[0].authors[0].name));
+  assert!(matches!(feed.title.value, XmlText::PlainText(value) if is_borrowed(value)));
+
+  // Only the subtitle has escaped characters.
+  assert_eq!(1, arena.len());
+  assert!(matches!(
+    feed.subtitle.as_ref().map(|v| &v.value),
+    Some(XmlText::Html(value)) if !is_borrowed(value)
+  ));
+}
+
+#[test]
+fn parse_mixed_cdata() {
+  let input = r#"<entry xmlns="http://www.w3.org/2005/Atom">
+    <title type="html"><![CDATA[<b>Bold</b>]]></title>
+    <summary>Text &amp; <![CDATA[<CDATA>]]></summary>
+    <id>urn:entry</id>
+    <updated>2024-09-18T23:28:25Z</updated>
+  </entry>"#;
+
+  let arena = StringArena::new();
+  let entry = atom::parse_entry(input, &arena).unwrap();
+
+  assert!(matches!(entry.title.value, XmlText::Html("<b>Bold</b>")));
+  assert!(matches!(
+    entry.summary.as_ref().map(|v| &v.value),
+    Some(XmlText::PlainText("Text & <CDATA>"))
+  ));
+  assert_eq!(1, arena.len());
+}
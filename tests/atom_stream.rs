@@ -0,0 +1,104 @@
+use std::io::{BufReader, Cursor};
+
+use syndication_format::{atom::FeedReader, common::XmlText, error::XmlParseError};
+
+fn generate_feed(entry_count: usize) -> String {
+  let mut feed = String::from(
+    r#"<?xml version="1.0" encoding="utf-8"?>
+<!-- Generated archive -->
+<a:feed xmlns:a="http://www.w3.org/2005/Atom" xmlns:ext="https://example.org/ext">
+  <a:title type="html">Archive &amp; Co.</a:title>
+  <a:id>urn:archive</a:id>
+  <a:updated>2024-09-18T23:28:25Z</a:updated>
+  <ext:note attr="a > b">Extension</ext:note>
+"#,
+  );
+
+  for idx in 0..entry_count {
+    feed.push_str(&format!(
+      r#"  <a:entry>
+    <a:title>Entry {idx}</a:title>
+    <a:id>urn:archive:{idx}</a:id>
+    <a:updated>2024-09-18T23:28:25Z</a:updated>
+    <a:content type="html"><![CDATA[<p>Content <!-- not a comment --> {idx}</p>]]></a:content>
+    <ext:rating>{idx}</ext:rating>
+  </a:entry>
+"#
+    ));
+  }
+
+  feed.push_str("</a:feed>\n");
+  feed
+}
+
+#[test]
+fn stream_entries() {
+  let input = generate_feed(100);
+
+  // Small buffer capacity forces markup to be split between multiple reads.
+  let mut reader = FeedReader::new(BufReader::with_capacity(7, Cursor::new(input)));
+
+  let feed = reader.feed().unwrap();
+  assert_eq!("urn:archive", feed.id.value);
+  assert!(matches!(feed.title.value, XmlText::Html("Archive & Co.")));
+  assert!(feed.entries.is_empty());
+
+  let mut count = 0;
+
+  while let Some(entry) = reader.next_entry().unwrap() {
+    assert_eq!(format!("urn:archive:{}", count), entry.id.value);
+    count += 1;
+  }
+
+  assert_eq!(100, count);
+  assert!(reader.next_entry().unwrap().is_none());
+
+  // Metadata is kept after the entries are consumed.
+  assert_eq!("urn:archive", reader.feed().unwrap().id.value);
+}
+
+#[test]
+fn stream_empty_looking_comments() {
+  let input = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Comments</title><id>urn:comments</id><updated>2024-09-18T23:28:25Z</updated>
+  <!--><entry><id>urn:commented</id></entry>-->
+  <entry><title>Entry</title><id>urn:entry</id><updated>2024-09-18T23:28:25Z</updated>
+    <!-->x--><?>x?>
+  </entry>
+</feed>"#;
+
+  for capacity in 1..=8 {
+    let mut reader = FeedReader::new(BufReader::with_capacity(capacity, Cursor::new(input)));
+
+    assert_eq!("urn:entry", reader.next_entry().unwrap().unwrap().id.value);
+    assert!(reader.next_entry().unwrap().is_none());
+  }
+}
+
+#[test]
+fn stream_without_entries() {
+  let input = generate_feed(0);
+  let mut reader = FeedReader::new(Cursor::new(input));
+
+  assert!(reader.next_entry().unwrap().is_none());
+  assert_eq!("urn:archive", reader.feed().unwrap().id.value);
+}
+
+#[test]
+fn stream_invalid_documents() {
+  let mut reader = FeedReader::new(Cursor::new("<rss version=\"2.0\"></rss>"));
+  assert!(matches!(
+    reader.next_entry(),
+    Err(XmlParseError::UnexpectedElement { .. })
+  ));
+
+  let mut input = generate_feed(2);
+  input.truncate(input.len() - 40);
+
+  let mut reader = FeedReader::new(Cursor::new(input));
+  assert!(reader.next_entry().unwrap().is_some());
+  assert!(matches!(
+    reader.next_entry(),
+    Err(XmlParseError::UnexpectedEof)
+  ));
+}
@@ -0,0 +1,122 @@
+use syndication_format::{
+  atom::{
+    Author, Content, ContentValue, Entry, Feed, Link, Rule, Severity, Source, Summary, Violation,
+  },
+  common::{LinkRelation, TimeStamp},
+  text,
+};
+
+fn link(href: &str, rel: LinkRelation) -> Link<'_> {
+  let mut link = Link::new(href);
+  link.rel = Some(rel);
+  link
+}
+
+fn entry(id: &str) -> Entry<'_> {
+  let mut entry = Entry::new(id, text!("Entry"), TimeStamp::default());
+  entry.links.push(Link::new("https://example.org/entry"));
+  entry
+}
+
+fn valid_feed() -> Feed<'static> {
+  let mut feed = Feed::new("urn:feed", text!("Feed"), TimeStamp::default());
+  feed.authors.push(Author::new("John Doe"));
+  feed
+    .links
+    .push(link("https://example.org/feed.atom", LinkRelation::SelfRel));
+  feed.entries.push(entry("urn:entry:1"));
+  feed
+}
+
+#[test]
+fn validate_conforming_feed() {
+  let feed = valid_feed();
+  assert_eq!(Vec::<Violation>::new(), feed.validate());
+}
+
+#[test]
+fn validate_feed_violations() {
+  let mut feed = valid_feed();
+  feed.authors.clear();
+  feed.links.clear();
+  feed.links.push(Link::new("https://example.org/"));
+  feed
+    .links
+    .push(link("https://example.org/index", LinkRelation::Alternate));
+
+  // Author inherited from the source.
+  let mut source_entry = entry("urn:entry:2");
+  let mut source = Source::new();
+  source.authors.push(Author::new("Jane"));
+  source_entry.source = Some(source);
+  feed.entries.push(source_entry);
+
+  let mut duplicate = entry("urn:entry:1");
+  duplicate.authors.push(Author::new("John Doe"));
+  duplicate.links.clear();
+  feed.entries.push(duplicate);
+
+  let violations = feed.validate();
+  assert_eq!(
+    vec![
+      Violation::new(Rule::MissingSelfLink, None),
+      Violation::new(Rule::DuplicateAlternateLink, None),
+      Violation::new(Rule::MissingAuthor, Some(0)),
+      Violation::new(Rule::DuplicateEntry, Some(2)),
+      Violation::new(Rule::MissingAlternateLink, Some(2)),
+    ],
+    violations
+  );
+
+  assert_eq!(Severity::Warning, violations[0].severity());
+  assert_eq!("4.1.1", violations[0].section());
+  assert_eq!(Severity::Error, violations[4].severity());
+  assert_eq!("4.1.2", violations[4].section());
+}
+
+#[test]
+fn validate_entry_content() {
+  let mut entry = entry("");
+  entry.authors.push(Author::new("John Doe"));
+  entry.content = Some(Content::new(ContentValue::LinkContent {
+    media_type: Some("html"),
+    src: "https://example.org/content",
+  }));
+
+  assert_eq!(
+    vec![
+      Violation::new(Rule::EmptyId, None),
+      Violation::new(Rule::MissingSummary, None),
+      Violation::new(Rule::InvalidContentType, None),
+    ],
+    entry.validate()
+  );
+
+  entry.id.value = "urn:entry";
+  entry.links.clear();
+  entry.summary = Some(Summary::new(text!("Summary")));
+  entry.content = Some(Content::new(ContentValue::LinkContent {
+    media_type: None,
+    src: "https://example.org/content",
+  }));
+  assert_eq!(
+    vec![Violation::new(Rule::MissingContentType, None)],
+    entry.validate()
+  );
+
+  entry.summary = None;
+  entry.content = Some(Content::new(ContentValue::InlinedMedia {
+    media_type: "image/svg+xml",
+    data: b"<svg/>",
+  }));
+  assert!(entry.validate().is_empty());
+
+  entry.content = Some(Content::new(ContentValue::InlinedMedia {
+    media_type: "image/png",
+    data: &[0x89, 0x50, 0x4E, 0x47],
+  }));
+  assert_eq!(
+    vec![Violation::new(Rule::MissingSummary, None)],
+    entry.validate()
+  );
+}
@@ -1,17 +1,18 @@
-use std::str::{from_utf8_unchecked, FromStr};
+use std::str::from_utf8_unchecked;
 
-use atom_syndication_format::{
+use syndication_format::{
   atom::{
-    Author, Category, Content, ContentValue, Contributor, Entry, Feed, Icon, Link, Logo, Rights,
-    SubTitle, Summary,
+    self, Author, Category, Content, ContentValue, Contributor, Entry, Feed, Icon, Link, Logo,
+    Rights, SubTitle, Summary, Title, XHTML_NAMESPACE,
   },
   common::{AttributeName, TimeStamp, XmlText},
   html,
+  reader::StringArena,
   serializer::{
-    formatter::{IndentedWriter, SpaceStyle},
-    Serializer, XmlSerializer,
+    formatter::{DefaultWriter, IndentedWriter, SpaceStyle},
+    NamespaceContext, Serializer, XmlSerializer,
   },
-  text,
+  text, xhtml,
 };
 
 struct TestEntry {
@@ -106,8 +107,6 @@ fn simple_atom_feed() {
   xml_serializer.serialize(feed, Some("atom")).unwrap();
 
   println!("{}", unsafe { from_utf8_unchecked(&bytes) });
-
-  assert!(true)
 }
 
 #[test]
@@ -118,11 +117,196 @@ fn atom_feed_invalid_namespace() {
   let mut xml_serializer = XmlSerializer::new(&mut writer);
 
   match xml_serializer.serialize(feed, Some("-123-invalid:namespace")) {
-    Ok(_) => assert!(
-      false,
-      "Feed serializer should have been failed due to invalid namespace"
-    ),
-    Err(atom_syndication_format::error::XmlSerializeError::InvalidNamespace) => assert!(true),
-    Err(_) => assert!(false, "Unexpected error type"),
+    Ok(_) => panic!("Feed serializer should have been failed due to invalid namespace"),
+    Err(syndication_format::error::XmlSerializeError::InvalidNamespace) => {}
+    Err(_) => panic!("Unexpected error type"),
   }
 }
+
+#[test]
+fn atom_feed_namespace_declaration() {
+  let serialize = |namespace: Option<&str>| {
+    let mut feed = Feed::new("urn:feed", text!("Feed"), TimeStamp::default());
+    feed.generator = None;
+    feed.set_attribute(AttributeName::new("xml:lang").unwrap(), "en".into());
+
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut writer = DefaultWriter::new(&mut bytes);
+    let mut xml_serializer = XmlSerializer::new(&mut writer);
+
+    xml_serializer.serialize_declaration().unwrap();
+    xml_serializer.serialize(feed, namespace).unwrap();
+
+    String::from_utf8(bytes).unwrap()
+  };
+
+  assert_eq!(
+    concat!(
+      r#"<?xml version="1.0" encoding="utf-8"?>"#,
+      r#"<feed xml:lang="en" xmlns="http://www.w3.org/2005/Atom">"#,
+      r#"<title type="text">Feed</title><id>urn:feed</id>"#,
+      r#"<updated>1970-01-01T00:00:00Z</updated></feed>"#
+    ),
+    serialize(None)
+  );
+
+  assert_eq!(
+    concat!(
+      r#"<?xml version="1.0" encoding="utf-8"?>"#,
+      r#"<atom:feed xml:lang="en" xmlns:atom="http://www.w3.org/2005/Atom">"#,
+      r#"<atom:title type="text">Feed</atom:title><atom:id>urn:feed</atom:id>"#,
+      r#"<atom:updated>1970-01-01T00:00:00Z</atom:updated></atom:feed>"#
+    ),
+    serialize(Some("atom"))
+  );
+}
+
+#[test]
+fn atom_xhtml_div_wrapper() {
+  let serialize = |value: &str, namespaces: &NamespaceContext| {
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut writer = DefaultWriter::new(&mut bytes);
+    let mut xml_serializer = XmlSerializer::with_namespaces(&mut writer, namespaces);
+
+    xml_serializer
+      .serialize(Title::new(xhtml!(value)), None)
+      .unwrap();
+
+    String::from_utf8(bytes).unwrap()
+  };
+
+  let empty = NamespaceContext::new();
+
+  assert_eq!(
+    r#"<title type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml">Less: <em>&lt;</em></div></title>"#,
+    serialize("Less: <em>&lt;</em>", &empty)
+  );
+
+  // Existing wrappers are kept.
+  let wrapped = r#"<xhtml:div xmlns:xhtml="http://www.w3.org/1999/xhtml">Title</xhtml:div>"#;
+  assert_eq!(
+    format!(r#"<title type="xhtml">{}</title>"#, wrapped),
+    serialize(wrapped, &empty)
+  );
+
+  let wrapped = r#" <div xmlns='http://www.w3.org/1999/xhtml'><div>A</div><br/></div> "#;
+  assert_eq!(
+    format!(r#"<title type="xhtml">{}</title>"#, wrapped),
+    serialize(wrapped, &empty)
+  );
+
+  // Child elements, multiple divs, or a div outside the XHTML namespace need a wrapper.
+  assert_eq!(
+    r#"<title type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml"><p>A</p><p>B</p></div></title>"#,
+    serialize("<p>A</p><p>B</p>", &empty)
+  );
+  assert_eq!(
+    r#"<title type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml"><div>A</div><div>B</div></div></title>"#,
+    serialize("<div>A</div><div>B</div>", &empty)
+  );
+  assert_eq!(
+    r#"<title type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml"><div>A</div></div></title>"#,
+    serialize("<div>A</div>", &empty)
+  );
+  assert_eq!(
+    r#"<title type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml"><x:div xmlns:x="urn:x">A</x:div></div></title>"#,
+    serialize(r#"<x:div xmlns:x="urn:x">A</x:div>"#, &empty)
+  );
+
+  // Wrapper always declares XHTML as the default namespace, even with a bound prefix.
+  let mut namespaces = NamespaceContext::new();
+  namespaces.bind("xhtml", XHTML_NAMESPACE).unwrap();
+
+  assert_eq!(
+    concat!(
+      r#"<title type="xhtml" xmlns:xhtml="http://www.w3.org/1999/xhtml">"#,
+      r#"<div xmlns="http://www.w3.org/1999/xhtml">Title</div></title>"#
+    ),
+    serialize("Title", &namespaces)
+  );
+
+  // Div prefixed with a prefix bound to XHTML is not wrapped again.
+  assert_eq!(
+    concat!(
+      r#"<title type="xhtml" xmlns:xhtml="http://www.w3.org/1999/xhtml">"#,
+      r#"<xhtml:div><xhtml:b>Title</xhtml:b></xhtml:div></title>"#
+    ),
+    serialize(
+      "<xhtml:div><xhtml:b>Title</xhtml:b></xhtml:div>",
+      &namespaces
+    )
+  );
+
+  // Prefix declared on the parsed feed root is copied to the div.
+  let arena = StringArena::new();
+  let feed = atom::parse(
+    r#"<feed xmlns="http://www.w3.org/2005/Atom" xmlns:xhtml="http://www.w3.org/1999/xhtml">
+  <id>urn:feed</id>
+  <title type="xhtml"><xhtml:div>A <xhtml:b>B</xhtml:b></xhtml:div></title>
+  <updated>2024-09-18T23:28:25Z</updated>
+</feed>"#,
+    &arena,
+  )
+  .unwrap();
+  let XmlText::Xhtml(title) = feed.title.value else {
+    panic!("expected XHTML title");
+  };
+
+  assert_eq!(
+    concat!(
+      r#"<title type="xhtml"><xhtml:div xmlns:xhtml="http://www.w3.org/1999/xhtml">"#,
+      r#"A <xhtml:b>B</xhtml:b></xhtml:div></title>"#
+    ),
+    serialize(title, &empty)
+  );
+}
+
+#[test]
+fn atom_inline_text_pretty_output() {
+  let mut feed = Feed::new("urn:feed", text!("Feed & more"), TimeStamp::default());
+  let mut entry = Entry::new("urn:entry", text!("Entry"), TimeStamp::default());
+  entry.content = Some(Content::new(
+    XmlText::Xhtml("<p>First</p>\n<p>Second</p>").into(),
+  ));
+  feed.entries.push(entry);
+
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = IndentedWriter::with_inline_text(&mut bytes, SpaceStyle::WhiteSpace, 2);
+  XmlSerializer::new(&mut writer)
+    .serialize(&feed, None)
+    .unwrap();
+  let pretty = String::from_utf8(bytes).unwrap();
+
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  XmlSerializer::new(&mut writer)
+    .serialize(&feed, None)
+    .unwrap();
+  let compact = String::from_utf8(bytes).unwrap();
+
+  assert_ne!(compact, pretty);
+
+  let arena = StringArena::new();
+  let pretty = atom::parse(&pretty, &arena).unwrap();
+  let compact = atom::parse(&compact, &arena).unwrap();
+
+  assert!(matches!(
+    pretty.title.value,
+    XmlText::PlainText("Feed & more")
+  ));
+  assert_eq!(compact.id.value, pretty.id.value);
+  assert_eq!(
+    compact.updated.value.to_string(),
+    pretty.updated.value.to_string()
+  );
+  assert!(matches!(
+    (
+      compact.entries[0].content.as_ref().map(|v| &v.value),
+      pretty.entries[0].content.as_ref().map(|v| &v.value),
+    ),
+    (
+      Some(ContentValue::TextContent { text: XmlText::Xhtml(compact) }),
+      Some(ContentValue::TextContent { text: XmlText::Xhtml(pretty) }),
+    ) if compact == pretty
+  ));
+}
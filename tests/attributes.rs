@@ -1,4 +1,4 @@
-use atom_syndication_format::common::{AttributeMap, AttributeName};
+use syndication_format::common::{AttributeMap, AttributeName};
 
 #[test]
 fn attribute_map_create() {
@@ -43,7 +43,7 @@ fn attribute_map_has() {
   attr_map.set(AttributeName::new("lang").unwrap(), "unknown".into());
   attr_map.set(AttributeName::new("attr1").unwrap(), "false".into());
 
-  assert_eq!(true, attr_map.has("lang"));
-  assert_eq!(true, attr_map.has("attr1"));
-  assert_eq!(false, attr_map.has("attr2"));
+  assert!(attr_map.has("lang"));
+  assert!(attr_map.has("attr1"));
+  assert!(!attr_map.has("attr2"));
 }
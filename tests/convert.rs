@@ -0,0 +1,324 @@
+use core::time::Duration;
+
+use syndication_format::{
+  atom::{self, Content, ContentValue, Entry, Link, Logo, Summary},
+  common::{AttributeName, LinkRelation, TimeStamp, XmlText},
+  convert::{Converted, UnmappedField},
+  error::ConvertError,
+  json_feed::{self, Attachment, JsonFeed},
+  rss::{self, Channel, Enclosure, Guid, Source},
+};
+
+fn timestamp(secs: u64) -> TimeStamp {
+  TimeStamp::new(Duration::from_secs(secs))
+}
+
+fn field(name: &'static str, item: Option<usize>) -> UnmappedField {
+  UnmappedField { name, item }
+}
+
+fn link(href: &str, rel: LinkRelation) -> Link<'_> {
+  let mut link = Link::new(href);
+  link.rel = Some(rel);
+  link
+}
+
+fn atom_feed() -> atom::Feed<'static> {
+  let mut feed = atom::Feed::new(
+    "https://example.org/",
+    XmlText::PlainText("Example Feed"),
+    timestamp(1726702105),
+  );
+  feed.generator = None;
+  feed.set_attribute(AttributeName::unchecked_new("xml:lang"), "en".into());
+  feed.links.push(Link::new("https://example.org/"));
+  feed
+    .links
+    .push(link("https://example.org/feed.xml", LinkRelation::SelfRel));
+  feed.logo = Some(Logo::new("https://example.org/logo.png"));
+
+  let mut author = atom::Author::new("John Doe");
+  author.email = Some("john@example.org");
+  feed.authors.push(author);
+
+  let mut category = atom::Category::new("tech");
+  category.scheme = Some("https://example.org/tags");
+  feed.categories.push(category);
+
+  let mut entry = Entry::new(
+    "https://example.org/1",
+    XmlText::PlainText("First"),
+    timestamp(1726700000),
+  );
+  entry.links.push(Link::new("https://example.org/1"));
+
+  let mut enclosure = link("https://example.org/1.mp3", LinkRelation::Enclosure);
+  enclosure.link_type = Some("audio/mpeg");
+  enclosure.length = Some(1024);
+  entry.links.push(enclosure);
+
+  entry.summary = Some(Summary::new(XmlText::PlainText("Summary")));
+  entry.content = Some(Content::new(XmlText::Html("<p>Content</p>").into()));
+  feed.entries.push(entry);
+
+  let mut entry = Entry::new(
+    "urn:example:2",
+    XmlText::HtmlUnchecked("&lt;b&gt;Second&lt;/b&gt;"),
+    timestamp(1726600000),
+  );
+  entry.content = Some(Content::new(ContentValue::LinkContent {
+    media_type: None,
+    src: "https://example.org/2",
+  }));
+  entry.contributors.push(atom::Contributor::new("Jane"));
+  feed.entries.push(entry);
+
+  feed
+}
+
+#[test]
+fn atom_to_rss() {
+  let feed = atom_feed();
+  let Converted { value, unmapped } = Converted::<Channel>::try_from(&feed).unwrap();
+
+  assert_eq!("Example Feed", value.title);
+  assert_eq!("https://example.org/", value.link);
+  assert_eq!("", value.description);
+  assert_eq!(Some("en"), value.language);
+  assert_eq!(Some("john@example.org"), value.managing_editor);
+  assert_eq!(Some("https://example.org/tags"), value.categories[0].domain);
+  assert_eq!("https://example.org/logo.png", value.image.unwrap().url);
+  assert_eq!(
+    "2024-09-18T23:28:25Z",
+    value.last_build_date.unwrap().to_string()
+  );
+
+  let item = &value.items[0];
+  let guid = item.guid.as_ref().unwrap();
+  let enclosure = item.enclosure.as_ref().unwrap();
+  assert_eq!(Some("First"), item.title);
+  assert_eq!(Some("https://example.org/1"), item.link);
+  assert!(guid.is_permalink);
+  assert_eq!(Some("Summary"), item.description);
+  assert_eq!(
+    (1024, "audio/mpeg"),
+    (enclosure.length, enclosure.media_type)
+  );
+
+  let item = &value.items[1];
+  assert_eq!(None, item.title);
+  assert!(!item.guid.as_ref().unwrap().is_permalink);
+  assert_eq!(None, item.description);
+
+  assert_eq!(
+    vec![
+      field("authors", None),
+      field("links", None),
+      field("content", Some(0)),
+      field("title", Some(1)),
+      field("content", Some(1)),
+      field("contributors", Some(1)),
+    ],
+    unmapped
+  );
+}
+
+fn rss_channel() -> Channel<'static> {
+  let mut channel = Channel::new("Example Channel", "https://example.org/", "Description");
+  channel.language = Some("en-us");
+  channel.managing_editor = Some("editor@example.org (Editor)");
+  channel.ttl = Some(60);
+
+  let mut item = rss::Item::new("First");
+  item.pub_date = Some(timestamp(1726600000));
+  item.guid = Some(Guid::new("https://example.org/1"));
+  item.description = Some("<p>Content</p>");
+  item.enclosure = Some(Enclosure::new(
+    "https://example.org/1.mp3",
+    2048,
+    "audio/mpeg",
+  ));
+  item.source = Some(Source::new("https://example.com/rss", "Origin"));
+  item.comments = Some("https://example.org/1#comments");
+  channel.items.push(item);
+
+  let mut item = rss::Item::with_description("Second");
+  item.link = Some("https://example.org/2");
+  item.pub_date = Some(timestamp(1726702105));
+  item.categories.push(rss::Category::new("news"));
+  channel.items.push(item);
+
+  channel
+}
+
+#[test]
+fn rss_to_atom() {
+  let channel = rss_channel();
+  let Converted { value, unmapped } = Converted::<atom::Feed>::try_from(&channel).unwrap();
+
+  assert_eq!("https://example.org/", value.id.value);
+  assert_eq!("2024-09-18T23:28:25Z", value.updated.value.to_string());
+  assert_eq!(
+    Some("syndication_format"),
+    value.generator.as_ref().map(|generator| generator.value)
+  );
+  assert_eq!("editor@example.org (Editor)", value.authors[0].name);
+  assert_eq!(1, value.links.len());
+
+  let entry = &value.entries[0];
+  assert_eq!("https://example.org/1", entry.id.value);
+  assert_eq!("2024-09-17T19:06:40Z", entry.updated.value.to_string());
+  assert_eq!(3, entry.links.len());
+  assert_eq!("https://example.org/1", entry.links[0].href);
+  assert_eq!(Some(2048), entry.links[1].length);
+  assert_eq!(Some("Origin"), entry.links[2].title);
+  assert!(matches!(
+    entry.summary.as_ref().unwrap().value,
+    XmlText::Html("<p>Content</p>")
+  ));
+
+  let entry = &value.entries[1];
+  assert_eq!("https://example.org/2", entry.id.value);
+  assert!(matches!(entry.title.value, XmlText::PlainText("")));
+  assert_eq!("news", entry.categories[0].term);
+
+  assert_eq!(
+    vec![field("ttl", None), field("comments", Some(0))],
+    unmapped
+  );
+
+  // Round trip keeps the mapped fields.
+  let Converted { value, .. } = Converted::<Channel>::try_from(&value).unwrap();
+  assert_eq!("Example Channel", value.title);
+  assert_eq!("Description", value.description);
+  assert_eq!(Some("en-us"), value.language);
+  assert_eq!(Some("<p>Content</p>"), value.items[0].description);
+  assert_eq!(Some("https://example.org/2"), value.items[1].link);
+}
+
+#[test]
+fn json_feed_conversions() {
+  let mut feed = JsonFeed::new("Example JSON Feed");
+  feed.home_page_url = Some("https://example.org/");
+  feed.feed_url = Some("https://example.org/feed.json");
+  feed.authors.push(json_feed::Author::new("John Doe"));
+  feed.next_url = Some("https://example.org/feed.json?page=2");
+
+  let mut item = json_feed::Item::new("1");
+  item.url = Some("https://example.org/1");
+  item.content_html = Some("<p>Content</p>");
+  item.content_text = Some("Content");
+  item.date_published = Some(timestamp(1726702105));
+  item.tags = vec!["news"];
+
+  let mut attachment = Attachment::new("https://example.org/1.mp3", "audio/mpeg");
+  attachment.duration_in_seconds = Some(60.0);
+  item.attachments.push(attachment);
+  feed.items.push(item);
+
+  let Converted { value, unmapped } = Converted::<atom::Feed>::try_from(&feed).unwrap();
+  assert_eq!("https://example.org/feed.json", value.id.value);
+  assert_eq!("2024-09-18T23:28:25Z", value.updated.value.to_string());
+  assert_eq!(2, value.links.len());
+  assert_eq!("John Doe", value.authors[0].name);
+
+  let entry = &value.entries[0];
+  assert_eq!(2, entry.links.len());
+  assert!(matches!(
+    entry.content.as_ref().unwrap().value,
+    ContentValue::TextContent {
+      text: XmlText::Html("<p>Content</p>")
+    }
+  ));
+  assert_eq!(
+    vec![
+      field("next_url", None),
+      field("attachments", Some(0)),
+      field("content_text", Some(0)),
+    ],
+    unmapped
+  );
+
+  let Converted { value, unmapped } = Converted::<JsonFeed>::try_from(&value).unwrap();
+  assert_eq!(Some("https://example.org/"), value.home_page_url);
+  assert_eq!(Some("https://example.org/feed.json"), value.feed_url);
+  assert_eq!(Some("<p>Content</p>"), value.items[0].content_html);
+  assert_eq!(vec!["news"], value.items[0].tags);
+  assert_eq!(1, value.items[0].attachments.len());
+  assert_eq!(
+    vec![field("updated", None), field("generator", None)],
+    unmapped
+  );
+
+  let Converted { value, unmapped } = Converted::<Channel>::try_from(&feed).unwrap();
+  let item = &value.items[0];
+  assert_eq!("https://example.org/", value.link);
+  assert_eq!(Some("<p>Content</p>"), item.description);
+  assert!(!item.guid.as_ref().unwrap().is_permalink);
+  assert_eq!(0, item.enclosure.as_ref().unwrap().length);
+  assert_eq!(
+    vec![
+      field("authors", None),
+      field("feed_url", None),
+      field("next_url", None),
+      field("content_text", Some(0)),
+      field("attachments", Some(0)),
+    ],
+    unmapped
+  );
+
+  let channel = rss_channel();
+  let Converted { value, unmapped } = Converted::<JsonFeed>::try_from(&channel).unwrap();
+  assert_eq!("Example Channel", value.title);
+  assert_eq!(Some("Description"), value.description);
+  assert_eq!(Some("https://example.org/1"), value.items[0].url);
+  assert_eq!(Some(2048), value.items[0].attachments[0].size_in_bytes);
+  assert_eq!("https://example.org/2", value.items[1].id);
+  assert_eq!(
+    vec![
+      field("generator", None),
+      field("ttl", None),
+      field("comments", Some(0)),
+      field("source", Some(0)),
+    ],
+    unmapped
+  );
+}
+
+#[test]
+fn missing_required_fields() {
+  let mut channel = Channel::new("Channel", "https://example.org/", "");
+  channel.items.push(rss::Item::new("No date"));
+  assert!(matches!(
+    Converted::<atom::Feed>::try_from(&channel),
+    Err(ConvertError::MissingField {
+      name: "last_build_date"
+    })
+  ));
+
+  channel.pub_date = Some(timestamp(0));
+  assert!(matches!(
+    Converted::<atom::Feed>::try_from(&channel),
+    Err(ConvertError::MissingField { name: "guid" })
+  ));
+
+  let feed = atom::Feed::new("urn:feed", XmlText::PlainText("Feed"), timestamp(0));
+  assert!(matches!(
+    Converted::<Channel>::try_from(&feed),
+    Err(ConvertError::MissingField { name: "links" })
+  ));
+
+  let feed = atom::Feed::new("urn:feed", XmlText::HtmlUnchecked("&amp;"), timestamp(0));
+  assert!(matches!(
+    Converted::<JsonFeed>::try_from(&feed),
+    Err(ConvertError::MissingField { name: "title" })
+  ));
+
+  let feed = JsonFeed::new("Feed");
+  assert!(matches!(
+    Converted::<Channel>::try_from(&feed),
+    Err(ConvertError::MissingField {
+      name: "home_page_url"
+    })
+  ));
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "derive")]
+
+use syndication_format::{
+  error::XmlSerializeError,
+  serializer::{formatter::DefaultWriter, NamespaceContext, Serialize, Serializer, XmlSerializer},
+};
+
+const DC_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+
+#[derive(Serialize)]
+#[xml(element = "li")]
+struct ListItem {
+  #[xml(attribute)]
+  value: usize,
+  #[xml(attribute = "xml:lang", skip_if_none)]
+  lang: Option<String>,
+}
+
+#[derive(Serialize)]
+#[xml(element = "ul")]
+struct List {
+  #[xml(attribute = "class")]
+  class_name: &'static str,
+  items: Vec<ListItem>,
+  #[xml(skip)]
+  #[allow(dead_code)]
+  cache: usize,
+}
+
+#[derive(Serialize)]
+#[xml(element = "creator", namespace = "http://purl.org/dc/elements/1.1/")]
+struct Creator<'a> {
+  #[xml(text)]
+  name: &'a str,
+}
+
+#[derive(Serialize)]
+#[xml(element = "note")]
+struct Note {
+  #[xml(text, skip_if_none)]
+  text: Option<String>,
+}
+
+#[derive(Serialize)]
+#[xml(element = "hr")]
+struct Separator;
+
+#[derive(Serialize)]
+#[xml(element = "entry")]
+struct Entry<'a> {
+  #[xml(element = "title")]
+  title: &'a str,
+  #[xml(element = "rights", skip_if_none)]
+  rights: Option<&'a str>,
+  #[xml(namespace = "http://purl.org/dc/elements/1.1/")]
+  creator: Creator<'a>,
+  #[xml(element = "subject", namespace = "http://purl.org/dc/elements/1.1/")]
+  subjects: Vec<&'a str>,
+  separator: Separator,
+}
+
+fn to_xml<V: Serialize>(
+  value: V,
+  namespaces: &NamespaceContext,
+) -> Result<String, XmlSerializeError> {
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  XmlSerializer::with_namespaces(&mut writer, namespaces).serialize(value, None)?;
+
+  Ok(String::from_utf8(bytes).unwrap())
+}
+
+#[test]
+fn derive_attributes_and_children() {
+  let list = List {
+    class_name: "items",
+    items: vec![
+      ListItem {
+        value: 1,
+        lang: None,
+      },
+      ListItem {
+        value: 2,
+        lang: Some(String::from("en")),
+      },
+    ],
+    cache: 0,
+  };
+
+  assert_eq!(
+    r#"<ul class="items"><li value="1"/><li value="2" xml:lang="en"/></ul>"#,
+    to_xml(&list, &NamespaceContext::new()).unwrap()
+  );
+}
+
+#[test]
+fn derive_text() {
+  let namespaces = NamespaceContext::new();
+
+  assert_eq!(
+    r#"<creator xmlns="http://purl.org/dc/elements/1.1/">Jane &amp; John</creator>"#,
+    to_xml(
+      Creator {
+        name: "Jane & John"
+      },
+      &namespaces
+    )
+    .unwrap()
+  );
+  assert_eq!(
+    "<note>a &lt; b</note>",
+    to_xml(
+      Note {
+        text: Some(String::from("a < b"))
+      },
+      &namespaces
+    )
+    .unwrap()
+  );
+  assert_eq!("<note/>", to_xml(Note { text: None }, &namespaces).unwrap());
+  assert_eq!("<hr/>", to_xml(Separator, &namespaces).unwrap());
+}
+
+#[test]
+fn derive_namespaces() {
+  let entry = Entry {
+    title: "Entry",
+    rights: None,
+    creator: Creator { name: "Jane" },
+    subjects: vec!["xml", "atom"],
+    separator: Separator,
+  };
+
+  let mut namespaces = NamespaceContext::new();
+  namespaces.bind("dc", DC_NAMESPACE).unwrap();
+
+  assert_eq!(
+    concat!(
+      r#"<entry xmlns:dc="http://purl.org/dc/elements/1.1/"><title>Entry</title>"#,
+      r#"<dc:creator>Jane</dc:creator><dc:subject>xml</dc:subject>"#,
+      r#"<dc:subject>atom</dc:subject><hr/></entry>"#
+    ),
+    to_xml(&entry, &namespaces).unwrap()
+  );
+
+  assert!(matches!(
+    to_xml(&entry, &NamespaceContext::new()),
+    Err(XmlSerializeError::InvalidNamespace)
+  ));
+}
@@ -0,0 +1,85 @@
+use syndication_format::detect::{detect, FeedFormat};
+
+#[test]
+fn detect_xml_formats() {
+  let cases: &[(&[u8], FeedFormat)] = &[
+    (
+      b"<feed xmlns='http://www.w3.org/2005/Atom'/>",
+      FeedFormat::Atom,
+    ),
+    (
+      b"\xEF\xBB\xBF<?xml version=\"1.0\"?>\n<?xml-stylesheet href=\"feed.xsl\"?>\n\
+        <atom:feed xmlns:atom=\"http://www.w3.org/2005/Atom\" xml:lang=\"en\">",
+      FeedFormat::Atom,
+    ),
+    (b"<rss version=\"2.0\"><channel>", FeedFormat::Rss2),
+    (b"<rss>", FeedFormat::Rss2),
+    (
+      b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\n\
+        <!DOCTYPE rss PUBLIC \"-//Netscape Communications//DTD RSS 0.91//EN\"\n\
+        \"http://my.netscape.com/publish/formats/rss-0.91.dtd\">\n\
+        <rss version=\" 0.91 \">",
+      FeedFormat::Rss09x,
+    ),
+    (
+      b"<!-- generated <rss version=\"2.0\"> -->\n\
+        <!DOCTYPE rdf:RDF [ <!ENTITY copy \"&#169;\"> ]>\n\
+        <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\"\n\
+        xmlns=\"http://purl.org/rss/1.0/\">",
+      FeedFormat::Rss1,
+    ),
+    (
+      b"<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" \
+        xmlns=\"http://my.netscape.com/rdf/simple/0.9/\">",
+      FeedFormat::Rss09x,
+    ),
+  ];
+
+  for (input, format) in cases {
+    assert_eq!(*format, detect(input), "{}", String::from_utf8_lossy(input));
+  }
+}
+
+#[test]
+fn detect_json_feed() {
+  let input = br#"
+    { "title": "Example", "items": [{"id": "1", "nested": {"version": 2}}],
+      "version": "https://jsonfeed.org/version/1.1" }"#;
+  assert_eq!(FeedFormat::JsonFeed, detect(input));
+
+  let input = br#"{"version": "https://jsonfeed.org/version/1", "items": []}"#;
+  assert_eq!(FeedFormat::JsonFeed, detect(input));
+
+  let input = br#"{"version": "https://jsonfeed.org/version/2"}"#;
+  assert_eq!(FeedFormat::Unknown, detect(input));
+
+  let input = br#"{"name": "package", "version": "1.0.0"}"#;
+  assert_eq!(FeedFormat::Unknown, detect(input));
+}
+
+#[test]
+fn detect_unknown() {
+  let cases: &[&[u8]] = &[
+    b"",
+    b"   ",
+    b"plain text",
+    b"<!DOCTYPE html>\n<html><head><title>Feed</title></head></html>",
+    b"<feed><title>No namespace</title></feed>",
+    b"<feed xmlns=\"http://purl.org/atom/ns#\">",
+    b"<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">",
+    b"<?xml version=\"1.0\"",
+    b"<!-- unterminated comment <rss>",
+    b"<rss version=2.0>",
+    b"[1, 2, 3]",
+    b"{\"version\": ",
+  ];
+
+  for input in cases {
+    assert_eq!(
+      FeedFormat::Unknown,
+      detect(input),
+      "{}",
+      String::from_utf8_lossy(input)
+    );
+  }
+}
@@ -1,11 +1,10 @@
-use atom_syndication_format::escape::{escape_writer, XML_ESCAPE_PATTERNS};
-use atom_syndication_format::escape_xml;
-use atom_syndication_format::serializer::formatter::DefaultWriter;
-
-const INPUT_HTML: &'static str = "<div>'\"COOL&CREATE\"'</div>";
-const INPUT_HTML_ESCAPED: &'static str =
-  "&lt;div&gt;&apos;&quot;COOL&amp;CREATE&quot;&apos;&lt;/div&gt;";
-const INPUT_PLAIN: &'static str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit.
+use syndication_format::escape::{escape_writer, unescape, unescape_lenient, XML_ESCAPE_PATTERNS};
+use syndication_format::escape_xml;
+use syndication_format::serializer::formatter::DefaultWriter;
+
+const INPUT_HTML: &str = "<div>'\"COOL&CREATE\"'</div>";
+const INPUT_HTML_ESCAPED: &str = "&lt;div&gt;&apos;&quot;COOL&amp;CREATE&quot;&apos;&lt;/div&gt;";
+const INPUT_PLAIN: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit.
 Donec efficitur enim nisi, vel fringilla velit dapibus ac. Donec sit amet lobortis mi, vitae mattis ligula.
 In sit amet erat purus. Fusce mauris lacus, cursus ornare mauris nec, hendrerit mollis dui.
 Integer vestibulum lacinia tellus vitae pretium. Phasellus malesuada dictum ex, ut dapibus nisi auctor ut.
@@ -19,7 +18,7 @@ fn escape_html() {
 
   match escaped {
     std::borrow::Cow::Borrowed(_) => {
-      assert!(false, "It shouldn't return borrowed text back.")
+      panic!("It shouldn't return borrowed text back.")
     }
     std::borrow::Cow::Owned(escaped_text) => assert_eq!(INPUT_HTML_ESCAPED, &escaped_text),
   }
@@ -33,7 +32,7 @@ fn escape_edge_cases() {
 
   match escaped {
     std::borrow::Cow::Borrowed(_) => {
-      assert!(false, "It shouldn't return borrowed text back.")
+      panic!("It shouldn't return borrowed text back.")
     }
     std::borrow::Cow::Owned(escaped_text) => assert_eq!("&amp;Test", &escaped_text),
   }
@@ -42,12 +41,32 @@ fn escape_edge_cases() {
   let escaped = escape_xml!(&end_input);
   match escaped {
     std::borrow::Cow::Borrowed(_) => {
-      assert!(false, "It shouldn't return borrowed text back.")
+      panic!("It shouldn't return borrowed text back.")
     }
     std::borrow::Cow::Owned(escaped_text) => assert_eq!("Test&amp;", &escaped_text),
   }
 }
 
+#[test]
+fn escape_trailing_char() {
+  for (input, expected) in [
+    ("a&b", "a&amp;b"),
+    ("&b", "&amp;b"),
+    ("a<b>c", "a&lt;b&gt;c"),
+    (
+      "Hello & World, 1 < 2 > 0!",
+      "Hello &amp; World, 1 &lt; 2 &gt; 0!",
+    ),
+  ] {
+    assert_eq!(expected, escape_xml!(input));
+
+    let mut mem_buffer: Vec<u8> = Vec::new();
+    let mut writer = DefaultWriter::new(&mut mem_buffer);
+    escape_writer(input, &mut writer, XML_ESCAPE_PATTERNS).unwrap();
+    assert_eq!(expected.as_bytes(), &mem_buffer);
+  }
+}
+
 #[test]
 fn escape_text() {
   let escaped = escape_xml!(&INPUT_PLAIN);
@@ -56,7 +75,7 @@ fn escape_text() {
     std::borrow::Cow::Borrowed(escaped_text) => {
       assert_eq!(INPUT_PLAIN, escaped_text)
     }
-    std::borrow::Cow::Owned(_) => assert!(false, "It shouldn't allocate new string."),
+    std::borrow::Cow::Owned(_) => panic!("It shouldn't allocate new string."),
   }
 }
 
@@ -69,7 +88,7 @@ fn escape_writer_html() {
     Ok(()) => {
       assert_eq!(&mem_buffer, INPUT_HTML_ESCAPED.as_bytes())
     }
-    Err(_) => assert!(false, "Writer failed."),
+    Err(_) => panic!("Writer failed."),
   }
 }
 
@@ -84,7 +103,7 @@ fn escape_writer_edge_cases() {
     Ok(()) => {
       assert_eq!(&mem_buffer, "&amp;Test".as_bytes())
     }
-    Err(_) => assert!(false, "Writer failed."),
+    Err(_) => panic!("Writer failed."),
   }
 
   let end_input = "Test&";
@@ -95,7 +114,7 @@ fn escape_writer_edge_cases() {
     Ok(()) => {
       assert_eq!(&mem_buffer, "Test&amp;".as_bytes())
     }
-    Err(_) => assert!(false, "Writer failed."),
+    Err(_) => panic!("Writer failed."),
   }
 }
 
@@ -108,6 +127,18 @@ fn escape_writer_text() {
     Ok(()) => {
       assert_eq!(&mem_buffer, INPUT_PLAIN.as_bytes())
     }
-    Err(_) => assert!(false, "Writer failed."),
+    Err(_) => panic!("Writer failed."),
+  }
+}
+
+#[test]
+fn unescape_character_references() {
+  assert_eq!("a\u{5}b", unescape("a&#5;b").unwrap());
+  assert_eq!("a\u{5}b", unescape("a&#x5;b").unwrap());
+  assert_eq!("<&>", unescape("&#60;&amp;&#x3E;").unwrap());
+
+  for input in ["&#+5;", "&#x+5;", "&#-5;", "&#;", "&#x;", "&# 5;", "&#5a;"] {
+    assert!(unescape(input).is_err(), "{}", input);
+    assert_eq!(input, unescape_lenient(input));
   }
 }
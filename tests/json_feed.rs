@@ -0,0 +1,219 @@
+use core::time::Duration;
+use std::str::from_utf8;
+
+use syndication_format::{
+  common::TimeStamp,
+  error::{JsonParseError, JsonSerializeError},
+  json_feed::{self, Attachment, Author, Extension, Hub, Item, JsonFeed, JsonSerializer},
+  reader::StringArena,
+  serializer::formatter::{DefaultWriter, IndentedWriter, SpaceStyle},
+};
+
+fn to_json(feed: &JsonFeed<'_>) -> Result<String, JsonSerializeError> {
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  JsonSerializer::new(&mut writer).serialize(feed)?;
+
+  Ok(from_utf8(&bytes).unwrap().to_owned())
+}
+
+fn example_feed() -> JsonFeed<'static> {
+  let mut feed = JsonFeed::new("My \"Example\" Feed");
+  feed.home_page_url = Some("https://example.org/");
+  feed.feed_url = Some("https://example.org/feed.json");
+  feed.next_url = Some("https://example.org/feed.json?page=2");
+  feed.authors.push(Author::new("John Doe"));
+  feed.expired = Some(false);
+  feed
+    .hubs
+    .push(Hub::new("WebSub", "https://websub.example.org/"));
+  feed.extensions.push(Extension::new(
+    "_blue_shed",
+    r#"{"about": "https://blueshed.example.com/", "count": 2}"#,
+  ));
+
+  let mut attachment = Attachment::new("https://example.org/episode.mp3", "audio/mpeg");
+  attachment.size_in_bytes = Some(12216320);
+  attachment.duration_in_seconds = Some(1800.5);
+
+  let mut item = Item::new("2");
+  item.url = Some("https://example.org/second-item");
+  item.content_text = Some("Line\nbreak");
+  item.date_published = Some(TimeStamp::new(Duration::from_secs(1726702105)));
+  item.tags = vec!["one", "two"];
+  item.attachments.push(attachment);
+  feed.items.push(item);
+
+  let mut item = Item::new("1");
+  item.content_html = Some("<p>Hello, world!</p>");
+  feed.items.push(item);
+
+  feed
+}
+
+#[test]
+fn serialize_json_feed() {
+  let expected = concat!(
+    r#"{"version":"https://jsonfeed.org/version/1.1","title":"My \"Example\" Feed","#,
+    r#""home_page_url":"https://example.org/","feed_url":"https://example.org/feed.json","#,
+    r#""next_url":"https://example.org/feed.json?page=2","authors":[{"name":"John Doe"}],"#,
+    r#""expired":false,"hubs":[{"type":"WebSub","url":"https://websub.example.org/"}],"#,
+    r#""items":[{"id":"2","url":"https://example.org/second-item","content_text":"Line\nbreak","#,
+    r#""date_published":"2024-09-18T23:28:25Z","tags":["one","two"],"#,
+    r#""attachments":[{"url":"https://example.org/episode.mp3","mime_type":"audio/mpeg","#,
+    r#""size_in_bytes":12216320,"duration_in_seconds":1800.5}]},"#,
+    r#"{"id":"1","content_html":"<p>Hello, world!</p>"}],"#,
+    r#""_blue_shed":{"about": "https://blueshed.example.com/", "count": 2}}"#
+  );
+
+  assert_eq!(expected, to_json(&example_feed()).unwrap());
+}
+
+#[test]
+fn serialize_indented_json_feed() {
+  let expected = r#"{
+  "version":"https://jsonfeed.org/version/1.1",
+  "title":"Feed",
+  "items":[
+    {
+      "id":"1"
+    }
+  ]
+}"#;
+
+  let mut feed = JsonFeed::new("Feed");
+  feed.items.push(Item::new("1"));
+
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = IndentedWriter::new(&mut bytes, SpaceStyle::WhiteSpace, 2);
+  JsonSerializer::new(&mut writer).serialize(&feed).unwrap();
+
+  assert_eq!(expected, from_utf8(&bytes).unwrap());
+}
+
+#[test]
+fn serialize_invalid_values() {
+  let mut feed = JsonFeed::new("Feed");
+  feed.extensions.push(Extension::new("no_underscore", "{}"));
+  assert!(matches!(
+    to_json(&feed),
+    Err(JsonSerializeError::InvalidExtension)
+  ));
+
+  let mut feed = JsonFeed::new("Feed");
+  feed.extensions.push(Extension::new("_broken", "{\"a\":}"));
+  assert!(matches!(
+    to_json(&feed),
+    Err(JsonSerializeError::InvalidExtension)
+  ));
+
+  let mut feed = JsonFeed::new("Feed");
+  let mut attachment = Attachment::new("https://example.org/a.mp3", "audio/mpeg");
+  attachment.duration_in_seconds = Some(f64::NAN);
+  let mut item = Item::new("1");
+  item.attachments.push(attachment);
+  feed.items.push(item);
+  assert!(matches!(
+    to_json(&feed),
+    Err(JsonSerializeError::InvalidNumber)
+  ));
+}
+
+#[test]
+fn parse_serialized_feed() {
+  let json = to_json(&example_feed()).unwrap();
+  let arena = StringArena::new();
+  let feed = json_feed::parse(&json, &arena).unwrap();
+
+  assert_eq!("My \"Example\" Feed", feed.title);
+  assert_eq!(Some("https://example.org/feed.json?page=2"), feed.next_url);
+  assert_eq!(Some("John Doe"), feed.authors[0].name);
+  assert_eq!(Some(false), feed.expired);
+  assert_eq!("WebSub", feed.hubs[0].hub_type);
+  assert_eq!("_blue_shed", feed.extensions[0].name);
+  assert_eq!(
+    r#"{"about": "https://blueshed.example.com/", "count": 2}"#,
+    feed.extensions[0].value
+  );
+  assert_eq!(2, feed.items.len());
+
+  let item = &feed.items[0];
+  let attachment = &item.attachments[0];
+  assert_eq!(Some("Line\nbreak"), item.content_text);
+  assert_eq!(
+    "2024-09-18T23:28:25Z",
+    item.date_published.unwrap().to_string()
+  );
+  assert_eq!(vec!["one", "two"], item.tags);
+  assert_eq!(Some(12216320), attachment.size_in_bytes);
+  assert_eq!(Some(1800.5), attachment.duration_in_seconds);
+  assert_eq!(json, to_json(&feed).unwrap());
+}
+
+#[test]
+fn parse_json_feed_1_0() {
+  let input = "\u{FEFF}{
+    \"version\": \"https://jsonfeed.org/version/1\",
+    \"title\": \"Caf\\u00e9 \\ud83d\\ude00\",
+    \"author\": { \"name\": \"Jane\", \"unknown\": [1, {\"a\": null}] },
+    \"hubs\": null,
+    \"items\": [
+      { \"id\": 42, \"title\": null, \"content_text\": \"a\\/b\", \"_ext\": [true, false] }
+    ],
+    \"unknown\": -1.5e3
+  }";
+
+  let arena = StringArena::new();
+  let feed = json_feed::parse(input, &arena).unwrap();
+
+  assert_eq!("Caf\u{e9} \u{1F600}", feed.title);
+  assert_eq!(Some("Jane"), feed.authors[0].name);
+  assert!(feed.hubs.is_empty());
+  assert_eq!("42", feed.items[0].id);
+  assert_eq!(None, feed.items[0].title);
+  assert_eq!(Some("a/b"), feed.items[0].content_text);
+  assert_eq!("[true, false]", feed.items[0].extensions[0].value);
+}
+
+#[test]
+fn parse_invalid_documents() {
+  let arena = StringArena::new();
+  let parse = |input| json_feed::parse(input, &arena);
+
+  assert!(matches!(
+    parse(r#"{"title": "a", "items": []}"#),
+    Err(JsonParseError::MissingField { name: "version" })
+  ));
+  assert!(matches!(
+    parse(r#"{"version": "https://jsonfeed.org/version/2", "title": "a", "items": []}"#),
+    Err(JsonParseError::UnsupportedVersion)
+  ));
+  assert!(matches!(
+    parse(r#"{"version": "https://jsonfeed.org/version/1.1", "title": "a"}"#),
+    Err(JsonParseError::MissingField { name: "items" })
+  ));
+  assert!(matches!(
+    parse(r#"{"version": "https://jsonfeed.org/version/1.1", "title": 1, "items": []}"#),
+    Err(JsonParseError::InvalidType { name: "title", .. })
+  ));
+  assert!(matches!(
+    parse(r#"{"version": "https://jsonfeed.org/version/1.1", "title": "a", "items": [{}]}"#),
+    Err(JsonParseError::MissingField { name: "id" })
+  ));
+  assert!(matches!(
+    parse(r#"{"version": "https://jsonfeed.org/version/1.1", "title": "a", "items": [],}"#),
+    Err(JsonParseError::InvalidSyntax { .. })
+  ));
+  assert!(matches!(
+    parse(r#"{"version": "https://jsonfeed.org/version/1.1", "title": "\x", "items": []}"#),
+    Err(JsonParseError::InvalidEscape { .. })
+  ));
+  assert!(matches!(
+    parse(r#"{"version": "https://jsonfeed.org/version/1.1", "title": "a", "items": []} {}"#),
+    Err(JsonParseError::InvalidSyntax { .. })
+  ));
+  assert!(matches!(
+    parse(r#"{"version": "https://jsonfeed.org/version/1.1", "title": "a", "items": ["#),
+    Err(JsonParseError::UnexpectedEof)
+  ));
+}
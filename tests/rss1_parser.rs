@@ -0,0 +1,141 @@
+use std::str::from_utf8;
+
+use syndication_format::{
+  error::{ParseWarning, XmlParseError},
+  reader::StringArena,
+  rss1::{self, Channel, Image, Item},
+  serializer::{formatter::DefaultWriter, Serializer, XmlSerializer},
+};
+
+fn to_xml(channel: Channel<'_>, namespace: Option<&str>) -> String {
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  let mut xml_serializer = XmlSerializer::new(&mut writer);
+  xml_serializer.serialize(channel, namespace).unwrap();
+
+  from_utf8(&bytes).unwrap().to_owned()
+}
+
+#[test]
+fn parse_serialized_channel() {
+  for namespace in [None, Some("rss")] {
+    let mut channel = Channel::new(
+      "http://example.com/rss",
+      "Journal & Co",
+      "http://example.com",
+      "Latest articles",
+    );
+    channel.dublin_core.rights = Some("CC BY 4.0");
+    channel.image = Some(Image::new(
+      "http://example.com/logo.png",
+      "Journal",
+      "http://example.com",
+    ));
+
+    let mut item = Item::new("First article", "http://example.com/1");
+    item.dublin_core.creator = Some("Jane Doe");
+    item.dublin_core.identifier = Some("doi:10.1000/1");
+    channel.items.push(item);
+    channel
+      .items
+      .push(Item::new("Second article", "http://example.com/2"));
+
+    let xml = to_xml(channel, namespace);
+    let arena = StringArena::new();
+    let parsed = rss1::parse(&xml, &arena).unwrap();
+    let channel = parsed.value;
+
+    assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+    assert_eq!("http://example.com/rss", channel.about);
+    assert_eq!("Journal & Co", channel.title);
+    assert_eq!("Latest articles", channel.description);
+    assert_eq!(Some("CC BY 4.0"), channel.dublin_core.rights);
+    assert_eq!(
+      "http://example.com/logo.png",
+      channel.image.as_ref().unwrap().about
+    );
+    assert_eq!(2, channel.items.len());
+    assert_eq!("http://example.com/1", channel.items[0].about);
+    assert_eq!(Some("Jane Doe"), channel.items[0].dublin_core.creator);
+    assert_eq!(
+      Some("doi:10.1000/1"),
+      channel.items[0].dublin_core.identifier
+    );
+    assert!(channel.items[1].dublin_core.is_empty());
+    assert!(channel.text_input.is_none());
+  }
+}
+
+#[test]
+fn parse_lenient_documents() {
+  let input = r#"<?xml version="1.0"?>
+<r:RDF xmlns:r="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/"
+  xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:prism="http://prismstandard.org/namespaces/1.2/basic/">
+  <channel r:about="http://example.com/rss">
+    <title>Journal</title>
+    <link>http://example.com</link>
+    <dc:date>2024-09-18T23:28:25Z</dc:date>
+    <items><r:Seq><r:li r:resource="http://example.com/1"/></r:Seq></items>
+  </channel>
+  <item>
+    <title>Article</title>
+    <link> http://example.com/1 </link>
+    <prism:volume>12</prism:volume>
+    <dc:date>Summer 2024</dc:date>
+  </item>
+</r:RDF>"#;
+
+  let arena = StringArena::new();
+  let parsed = rss1::parse(input, &arena).unwrap();
+  let channel = parsed.value;
+
+  assert_eq!("", channel.description);
+  assert_eq!(
+    "2024-09-18T23:28:25Z",
+    channel.dublin_core.date.unwrap().to_string()
+  );
+  assert_eq!("http://example.com/1", channel.items[0].link);
+  assert!(channel.items[0].dublin_core.date.is_none());
+
+  let warnings = parsed.warnings;
+  assert_eq!(3, warnings.len(), "{:?}", warnings);
+  assert!(matches!(
+    warnings[0],
+    ParseWarning::MissingElement {
+      name: "description",
+      ..
+    }
+  ));
+  assert!(matches!(
+    warnings[1],
+    ParseWarning::MissingAttribute {
+      name: "rdf:about",
+      ..
+    }
+  ));
+  assert!(matches!(
+    warnings[2],
+    ParseWarning::InvalidTimeStamp {
+      name: "dc:date",
+      ..
+    }
+  ));
+}
+
+#[test]
+fn parse_invalid_documents() {
+  let arena = StringArena::new();
+
+  assert!(matches!(
+    rss1::parse(r#"<rss version="2.0"><channel/></rss>"#, &arena),
+    Err(XmlParseError::UnexpectedElement { .. })
+  ));
+
+  assert!(matches!(
+    rss1::parse(
+      r#"<RDF xmlns="http://www.w3.org/1999/02/22-rdf-syntax-ns#"></RDF>"#,
+      &arena
+    ),
+    Err(XmlParseError::MissingElement { name: "channel" })
+  ));
+}
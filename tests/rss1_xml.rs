@@ -0,0 +1,92 @@
+use core::time::Duration;
+use std::str::from_utf8;
+
+use syndication_format::{
+  common::TimeStamp,
+  rss1::{Channel, Image, Item, TextInput},
+  serializer::{formatter::DefaultWriter, Serializer, XmlSerializer},
+};
+
+fn to_xml(channel: Channel<'_>, namespace: Option<&str>) -> String {
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  let mut xml_serializer = XmlSerializer::new(&mut writer);
+  xml_serializer.serialize(channel, namespace).unwrap();
+
+  from_utf8(&bytes).unwrap().to_owned()
+}
+
+#[test]
+fn simple_rss1_channel() {
+  let expected = concat!(
+    r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/" "#,
+    r#"xmlns:dc="http://purl.org/dc/elements/1.1/">"#,
+    r#"<channel rdf:about="http://www.xml.com/xml/news.rss"><title>XML.com</title>"#,
+    r#"<link>http://xml.com/pub</link><description>XML.com features a rich mix.</description>"#,
+    r#"<dc:publisher>O&apos;Reilly</dc:publisher><dc:date>2024-09-18T23:28:25Z</dc:date>"#,
+    r#"<image rdf:resource="http://xml.com/universal/images/xml_tiny.gif"/>"#,
+    r#"<items><rdf:Seq><rdf:li rdf:resource="http://xml.com/pub/2000/08/09/xslt/xslt.html"/>"#,
+    r#"<rdf:li rdf:resource="http://xml.com/pub/2000/08/09/rdfdb/index.html"/></rdf:Seq></items>"#,
+    r#"<textinput rdf:resource="http://search.xml.com"/></channel>"#,
+    r#"<image rdf:about="http://xml.com/universal/images/xml_tiny.gif"><title>XML.com</title>"#,
+    r#"<url>http://xml.com/universal/images/xml_tiny.gif</url><link>http://www.xml.com</link></image>"#,
+    r#"<item rdf:about="http://xml.com/pub/2000/08/09/xslt/xslt.html"><title>Processing Inclusions with XSLT</title>"#,
+    r#"<link>http://xml.com/pub/2000/08/09/xslt/xslt.html</link><dc:creator>Bob DuCharme</dc:creator></item>"#,
+    r#"<item rdf:about="http://xml.com/pub/2000/08/09/rdfdb/index.html"><title>Putting RDF to Work</title>"#,
+    r#"<link>http://xml.com/pub/2000/08/09/rdfdb/index.html</link>"#,
+    r#"<description>Tool and API support for the Resource Description Framework.</description></item>"#,
+    r#"<textinput rdf:about="http://search.xml.com"><title>Search XML.com</title>"#,
+    r#"<description>Search XML.com&apos;s XML collection</description><name>s</name>"#,
+    r#"<link>http://search.xml.com</link></textinput></rdf:RDF>"#
+  );
+
+  let mut channel = Channel::new(
+    "http://www.xml.com/xml/news.rss",
+    "XML.com",
+    "http://xml.com/pub",
+    "XML.com features a rich mix.",
+  );
+  channel.dublin_core.publisher = Some("O'Reilly");
+  channel.dublin_core.date = Some(TimeStamp::new(Duration::from_secs(1726702105)));
+  channel.image = Some(Image::new(
+    "http://xml.com/universal/images/xml_tiny.gif",
+    "XML.com",
+    "http://www.xml.com",
+  ));
+  channel.text_input = Some(TextInput::new(
+    "Search XML.com",
+    "Search XML.com's XML collection",
+    "s",
+    "http://search.xml.com",
+  ));
+
+  let mut item = Item::new(
+    "Processing Inclusions with XSLT",
+    "http://xml.com/pub/2000/08/09/xslt/xslt.html",
+  );
+  item.dublin_core.creator = Some("Bob DuCharme");
+  channel.items.push(item);
+
+  let mut item = Item::new(
+    "Putting RDF to Work",
+    "http://xml.com/pub/2000/08/09/rdfdb/index.html",
+  );
+  item.description = Some("Tool and API support for the Resource Description Framework.");
+  channel.items.push(item);
+
+  assert_eq!(expected, to_xml(channel, None));
+}
+
+#[test]
+fn prefixed_rss1_channel() {
+  let expected = concat!(
+    r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:rss="http://purl.org/rss/1.0/">"#,
+    r#"<rss:channel rdf:about="urn:feed"><rss:title>Title</rss:title><rss:link>http://example.com</rss:link>"#,
+    r#"<rss:description>Description</rss:description><rss:items><rdf:Seq></rdf:Seq></rss:items></rss:channel>"#,
+    r#"</rdf:RDF>"#
+  );
+
+  let channel = Channel::new("urn:feed", "Title", "http://example.com", "Description");
+
+  assert_eq!(expected, to_xml(channel, Some("rss")));
+}
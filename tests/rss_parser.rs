@@ -0,0 +1,392 @@
+use core::time::Duration;
+use std::str::from_utf8;
+
+use syndication_format::{
+  common::{DateHeuristic, TimeStamp},
+  error::{ParseWarning, XmlParseError},
+  reader::StringArena,
+  rss::{self, Channel, Day, Enclosure, Guid, Item},
+  serializer::{formatter::DefaultWriter, Serializer, XmlSerializer},
+};
+
+fn to_xml(channel: Channel<'_>) -> String {
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  let mut xml_serializer = XmlSerializer::new(&mut writer);
+  xml_serializer.serialize(channel, None).unwrap();
+
+  from_utf8(&bytes).unwrap().to_owned()
+}
+
+#[test]
+fn parse_serialized_channel() {
+  let pub_date = TimeStamp::new(Duration::from_secs(1726702105));
+  let mut channel = Channel::new(
+    "Liftoff News",
+    "http://liftoff.msfc.nasa.gov/",
+    "Liftoff to Space Exploration & more.",
+  );
+  channel.pub_date = Some(pub_date);
+  channel.ttl = Some(60);
+  channel.skip_hours = vec![0, 23];
+  channel.skip_days = vec![Day::Saturday, Day::Sunday];
+
+  let mut guid = Guid::new("urn:starcity");
+  guid.is_permalink = false;
+
+  let mut item = Item::new("Star City");
+  item.description = Some("<p>How do Americans get ready?</p>");
+  item.enclosure = Some(Enclosure::new(
+    "http://www.scripting.com/mp3s/weatherReportSuite.mp3",
+    12216320,
+    "audio/mpeg",
+  ));
+  item.guid = Some(guid);
+  item.pub_date = Some(pub_date);
+  channel.items.push(item);
+
+  let xml = to_xml(channel);
+  let arena = StringArena::new();
+  let parsed = rss::parse(&xml, &arena).unwrap();
+  let channel = parsed.value;
+
+  assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+  assert_eq!("Liftoff News", channel.title);
+  assert_eq!("http://liftoff.msfc.nasa.gov/", channel.link);
+  assert_eq!("Liftoff to Space Exploration & more.", channel.description);
+  assert_eq!(Some("syndication_format"), channel.generator);
+  assert_eq!(
+    "2024-09-18T23:28:25Z",
+    channel.pub_date.unwrap().to_string()
+  );
+  assert_eq!(Some(60), channel.ttl);
+  assert_eq!(vec![0, 23], channel.skip_hours);
+  assert_eq!(vec![Day::Saturday, Day::Sunday], channel.skip_days);
+
+  let item = &channel.items[0];
+  let enclosure = item.enclosure.as_ref().unwrap();
+  assert_eq!(Some("Star City"), item.title);
+  assert_eq!(Some("<p>How do Americans get ready?</p>"), item.description);
+  assert_eq!(12216320, enclosure.length);
+  assert_eq!("audio/mpeg", enclosure.media_type);
+  assert!(!item.guid.as_ref().unwrap().is_permalink);
+  assert_eq!("2024-09-18T23:28:25Z", item.pub_date.unwrap().to_string());
+}
+
+#[test]
+fn parse_real_world_feed() {
+  let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel>
+    <title>Caf&eacute; News</title>
+    <description>News&nbsp;&amp; views</description>
+    <pubDate>18 Sep 2024 23:28 GMT</pubDate>
+    <lastBuildDate>2024-09-19T02:28:25+03:00</lastBuildDate>
+    <ttl>one hour</ttl>
+    <skipDays><day>sunday</day><day>Someday</day></skipDays>
+    <skipHours><hour>24</hour><hour>7</hour></skipHours>
+    <item>
+      <title><![CDATA[Fish & Chips]]></title>
+      <description><p>Unescaped <b>HTML</b></p></description>
+      <dc:creator>John Doe</dc:creator>
+      <pubDate>Yesterday</pubDate>
+      <enclosure url="http://example.com/a.mp3" length="" type="audio/mpeg"/>
+      <guid isPermaLink="no">  urn:fish  </guid>
+    </item>
+    <item><link>http://example.com/empty</link></item>
+  </channel>
+</rss>"#;
+
+  let arena = StringArena::new();
+  let parsed = rss::parse(input, &arena).unwrap();
+  let channel = parsed.value;
+
+  assert_eq!("Caf&eacute; News", channel.title);
+  assert_eq!("", channel.link);
+  assert_eq!("News&nbsp;& views", channel.description);
+  assert_eq!(
+    "2024-09-18T23:28:00Z",
+    channel.pub_date.unwrap().to_string()
+  );
+  assert_eq!(
+    "2024-09-19T02:28:25+03:00",
+    channel.last_build_date.unwrap().to_string()
+  );
+  assert_eq!(None, channel.ttl);
+  assert_eq!(vec![Day::Sunday], channel.skip_days);
+  assert_eq!(vec![7], channel.skip_hours);
+  assert_eq!(None, channel.generator);
+  assert_eq!(None, channel.docs);
+  assert_eq!(2, channel.items.len());
+
+  let item = &channel.items[0];
+  assert_eq!(Some("Fish & Chips"), item.title);
+  assert_eq!(Some("<p>Unescaped <b>HTML</b></p>"), item.description);
+  assert!(item.pub_date.is_none());
+  assert_eq!(0, item.enclosure.as_ref().unwrap().length);
+  assert_eq!("urn:fish", item.guid.as_ref().unwrap().value);
+  assert!(item.guid.as_ref().unwrap().is_permalink);
+
+  let warnings = parsed.warnings;
+  assert_eq!(11, warnings.len(), "{:?}", warnings);
+  assert!(matches!(warnings[0], ParseWarning::InvalidEntity { .. }));
+  assert!(matches!(warnings[1], ParseWarning::InvalidEntity { .. }));
+  assert!(matches!(
+    warnings[2],
+    ParseWarning::InvalidValue { name: "ttl", .. }
+  ));
+  assert!(matches!(
+    warnings[3],
+    ParseWarning::InvalidValue { name: "day", .. }
+  ));
+  assert!(matches!(
+    warnings[4],
+    ParseWarning::InvalidValue { name: "hour", .. }
+  ));
+  assert!(matches!(
+    warnings[5],
+    ParseWarning::MarkupInText {
+      name: "description",
+      ..
+    }
+  ));
+  assert!(matches!(
+    warnings[6],
+    ParseWarning::InvalidTimeStamp {
+      name: "pubDate",
+      ..
+    }
+  ));
+  assert!(matches!(
+    warnings[7],
+    ParseWarning::InvalidValue { name: "length", .. }
+  ));
+  assert!(matches!(
+    warnings[8],
+    ParseWarning::InvalidValue {
+      name: "isPermaLink",
+      ..
+    }
+  ));
+  assert!(matches!(
+    warnings[9],
+    ParseWarning::MissingElement { name: "title", .. }
+  ));
+  assert!(matches!(
+    warnings[10],
+    ParseWarning::MissingElement { name: "link", .. }
+  ));
+}
+
+#[test]
+fn parse_rfc822_dates() {
+  let dates = [
+    ("Wed, 18 Sep 2024 23:28:25 +0000", "2024-09-18T23:28:25Z"),
+    ("18 Sep 2024 23:28:25 GMT", "2024-09-18T23:28:25Z"),
+    ("wed, 8 sep 2024 23:28 UT", "2024-09-08T23:28:00Z"),
+    ("Wed, 18 Sep 24 19:28:25 EDT", "2024-09-18T19:28:25-04:00"),
+    (
+      "Thu, 19 Sep 2024 02:28:25 +0300",
+      "2024-09-19T02:28:25+03:00",
+    ),
+    (
+      "Wed, 18 Sep 2024 18:28:25 -0500",
+      "2024-09-18T18:28:25-05:00",
+    ),
+  ];
+
+  for (value, expected) in dates {
+    let input = format!(
+      "<rss version=\"2.0\"><channel><title>a</title><link>b</link><description>c</description>\
+      <pubDate>{}</pubDate></channel></rss>",
+      value
+    );
+
+    let arena = StringArena::new();
+    let parsed = rss::parse(&input, &arena).unwrap();
+
+    assert!(
+      parsed.warnings.is_empty(),
+      "{}: {:?}",
+      value,
+      parsed.warnings
+    );
+    assert_eq!(expected, parsed.value.pub_date.unwrap().to_string());
+  }
+
+  let invalid = [
+    "Wed, 31 Sep 2024 23:28:25 +0000",
+    "Wed, 18 Sep 2024 25:28:25 +0000",
+    "Wed, 18 Sep 2024 23:28:25 +00",
+    "Wed, 18 Sep 2024 23:28:25 XYZ",
+    "Day, 18 Sep 2024 23:28:25 +0000",
+    "2024-09-18T2a:28:25Z",
+  ];
+
+  for value in invalid {
+    let input = format!(
+      "<rss version=\"2.0\"><channel><title>a</title><link>b</link><description>c</description>\
+      <pubDate>{}</pubDate></channel></rss>",
+      value
+    );
+
+    let arena = StringArena::new();
+    let parsed = rss::parse(&input, &arena).unwrap();
+
+    assert!(parsed.value.pub_date.is_none(), "{}", value);
+    assert!(
+      matches!(parsed.warnings[..], [ParseWarning::InvalidTimeStamp { .. }]),
+      "{}",
+      value
+    );
+  }
+}
+
+#[test]
+fn parse_invalid_documents() {
+  let arena = StringArena::new();
+
+  assert!(matches!(
+    rss::parse("<feed><channel/></feed>", &arena),
+    Err(XmlParseError::UnexpectedElement { .. })
+  ));
+
+  assert!(matches!(
+    rss::parse(r#"<rss version="2.0"></rss>"#, &arena),
+    Err(XmlParseError::MissingElement { name: "channel" })
+  ));
+
+  assert!(matches!(
+    rss::parse(
+      r#"<rss version="2.0"><channel><title>a</channel></rss>"#,
+      &arena
+    ),
+    Err(XmlParseError::MismatchedEndTag { .. })
+  ));
+
+  let parsed = rss::parse("<rss><channel/></rss>", &arena).unwrap();
+  assert!(matches!(
+    parsed.warnings[0],
+    ParseWarning::UnsupportedVersion { .. }
+  ));
+}
+
+#[test]
+fn recover_lenient_dates() {
+  let input =
+    "<rss version=\"2.0\"><channel><title>a</title><link>b</link><description>c</description>\
+    <pubDate>2024-09-18 23:28</pubDate><lastBuildDate>Sept 18, 2024 11:28 PM EDT</lastBuildDate>\
+    </channel></rss>";
+
+  let arena = StringArena::new();
+  let parsed = rss::parse(input, &arena).unwrap();
+
+  assert_eq!(
+    "2024-09-18T23:28:00-00:00",
+    parsed.value.pub_date.unwrap().to_string()
+  );
+  assert_eq!(
+    "2024-09-18T23:28:00-04:00",
+    parsed.value.last_build_date.unwrap().to_string()
+  );
+  assert!(matches!(
+    parsed.warnings[..],
+    [
+      ParseWarning::RecoveredTimeStamp {
+        name: "pubDate",
+        heuristic: DateHeuristic::IsoDateTime,
+        assumed_time_zone: true,
+        ..
+      },
+      ParseWarning::RecoveredTimeStamp {
+        name: "lastBuildDate",
+        heuristic: DateHeuristic::MonthName,
+        assumed_time_zone: false,
+        ..
+      }
+    ]
+  ));
+}
+
+#[test]
+fn recover_unclosed_markup() {
+  let input = "<rss version=\"2.0\"><channel><title>a</title><link>b</link>\
+    <description>c</description>\
+    <item><description><p>foo<br></description><link>https://example.org/1</link></item>\
+    <item><description>1 < 2 <![CDATA[</description>]]></description></item>\
+    <item><title>a <b>b</b></title><description><b/>unclosed <i></item></description></item>\
+    </channel></rss>";
+
+  let arena = StringArena::new();
+  let parsed = rss::parse(input, &arena).unwrap();
+  let items = &parsed.value.items;
+
+  assert_eq!(3, items.len());
+  assert_eq!(Some("<p>foo<br>"), items[0].description);
+  assert_eq!(Some("https://example.org/1"), items[0].link);
+  assert_eq!(Some("1 < 2 </description>"), items[1].description);
+  assert_eq!(Some("a <b>b</b>"), items[2].title);
+  assert_eq!(Some("<b/>unclosed <i></item>"), items[2].description);
+
+  assert!(matches!(
+    parsed.warnings[..],
+    [
+      ParseWarning::MarkupInText {
+        name: "description",
+        ..
+      },
+      ParseWarning::MarkupInText {
+        name: "description",
+        ..
+      },
+      ParseWarning::MarkupInText { name: "title", .. },
+      ParseWarning::MarkupInText {
+        name: "description",
+        ..
+      },
+    ]
+  ));
+
+  assert!(matches!(
+    rss::parse(
+      "<rss version=\"2.0\"><channel><title>a <b></channel></rss>",
+      &arena
+    ),
+    Err(XmlParseError::UnexpectedEof)
+  ));
+}
+
+#[test]
+fn decode_entities_in_markup() {
+  let input = "<rss version=\"2.0\"><channel><title>a</title><link>b</link>\
+    <description>c</description>\
+    <item><description><p>Fish &amp; Chips&nbsp;&#x21;</p><![CDATA[<i>&amp;</i>]]>\
+    <!-- a &amp; b --></description></item>\
+    <item><description><p>Fish &amp; Chips</p></description></item>\
+    </channel></rss>";
+
+  let arena = StringArena::new();
+  let parsed = rss::parse(input, &arena).unwrap();
+  let items = &parsed.value.items;
+
+  assert_eq!(
+    Some("<p>Fish & Chips&nbsp;!</p><i>&amp;</i><!-- a &amp; b -->"),
+    items[0].description
+  );
+  assert_eq!(Some("<p>Fish & Chips</p>"), items[1].description);
+
+  assert!(matches!(
+    parsed.warnings[..],
+    [
+      ParseWarning::MarkupInText {
+        name: "description",
+        ..
+      },
+      ParseWarning::InvalidEntity { .. },
+      ParseWarning::MarkupInText {
+        name: "description",
+        ..
+      },
+    ]
+  ));
+}
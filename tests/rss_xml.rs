@@ -0,0 +1,107 @@
+use core::time::Duration;
+use std::str::from_utf8;
+
+use syndication_format::{
+  common::{AttributeName, OffsetSymbol, TimeStamp, TimeZoneOffset},
+  rss::{Category, Channel, Cloud, Day, Enclosure, Guid, Image, Item, Source, TextInput},
+  serializer::{formatter::DefaultWriter, Serializer, XmlSerializer},
+};
+
+#[test]
+fn simple_rss_channel() {
+  let expected = concat!(
+    r#"<rss xmlns:dc="http://purl.org/dc/elements/1.1/" version="2.0"><channel>"#,
+    r#"<title>Liftoff News</title><link>http://liftoff.msfc.nasa.gov/</link>"#,
+    r#"<description>Liftoff to Space Exploration.</description><language>en-us</language>"#,
+    r#"<pubDate>Wed, 18 Sep 2024 23:28:25 +0000</pubDate>"#,
+    r#"<lastBuildDate>Thu, 19 Sep 2024 02:28:25 +0300</lastBuildDate>"#,
+    r#"<category domain="Syndic8">1765</category><docs>https://www.rssboard.org/rss-specification</docs>"#,
+    r#"<cloud domain="rpc.sys.com" port="80" path="/RPC2" registerProcedure="pingMe" protocol="soap"/>"#,
+    r#"<ttl>60</ttl><image><url>http://liftoff.msfc.nasa.gov/logo.png</url><title>Liftoff News</title>"#,
+    r#"<link>http://liftoff.msfc.nasa.gov/</link><width>88</width></image>"#,
+    r#"<textInput><title>Search</title><description>Search articles</description><name>q</name>"#,
+    r#"<link>http://liftoff.msfc.nasa.gov/search</link></textInput>"#,
+    r#"<skipHours><hour>0</hour><hour>1</hour></skipHours><skipDays><day>Sunday</day></skipDays>"#,
+    r#"<item><title>Star City</title><link>http://liftoff.msfc.nasa.gov/news/2003/news-starcity.asp</link>"#,
+    r#"<description>&lt;p&gt;How do Americans get ready to work with Russians aboard the ISS?&lt;/p&gt;</description>"#,
+    r#"<enclosure url="http://www.scripting.com/mp3s/weatherReportSuite.mp3" length="12216320" type="audio/mpeg"/>"#,
+    r#"<guid isPermaLink="false">urn:starcity</guid><pubDate>Wed, 18 Sep 2024 23:28:25 +0000</pubDate>"#,
+    r#"<source url="http://www.tomalak.org/links2.xml">Tomalak&apos;s Realm</source></item>"#,
+    r#"<item><description>Sky watchers in Europe, Asia, and parts of Alaska and Canada.</description></item>"#,
+    r#"</channel></rss>"#
+  );
+
+  let pub_date = TimeStamp::new(Duration::from_secs(1726702105));
+  let mut last_build_date = pub_date;
+  let mut offset = TimeZoneOffset::new();
+  offset.set_hours(3).set_symbol(OffsetSymbol::Plus);
+  last_build_date.set_tz(offset);
+
+  let mut channel = Channel::new(
+    "Liftoff News",
+    "http://liftoff.msfc.nasa.gov/",
+    "Liftoff to Space Exploration.",
+  );
+
+  channel.set_attribute(
+    AttributeName::new("xmlns:dc").unwrap(),
+    "http://purl.org/dc/elements/1.1/".into(),
+  );
+  channel.generator = None;
+  channel.language = Some("en-us");
+  channel.pub_date = Some(pub_date);
+  channel.last_build_date = Some(last_build_date);
+  channel.ttl = Some(60);
+  channel.skip_hours = vec![0, 1];
+  channel.skip_days = vec![Day::Sunday];
+  channel.cloud = Some(Cloud::new("rpc.sys.com", 80, "/RPC2", "pingMe", "soap"));
+  channel.text_input = Some(TextInput::new(
+    "Search",
+    "Search articles",
+    "q",
+    "http://liftoff.msfc.nasa.gov/search",
+  ));
+
+  let mut category = Category::new("1765");
+  category.domain = Some("Syndic8");
+  channel.categories.push(category);
+
+  let mut image = Image::new(
+    "http://liftoff.msfc.nasa.gov/logo.png",
+    "Liftoff News",
+    "http://liftoff.msfc.nasa.gov/",
+  );
+  image.width = Some(88);
+  channel.image = Some(image);
+
+  let mut guid = Guid::new("urn:starcity");
+  guid.is_permalink = false;
+
+  let mut item = Item::new("Star City");
+  item.link = Some("http://liftoff.msfc.nasa.gov/news/2003/news-starcity.asp");
+  item.description =
+    Some("<p>How do Americans get ready to work with Russians aboard the ISS?</p>");
+  item.enclosure = Some(Enclosure::new(
+    "http://www.scripting.com/mp3s/weatherReportSuite.mp3",
+    12216320,
+    "audio/mpeg",
+  ));
+  item.guid = Some(guid);
+  item.pub_date = Some(pub_date);
+  item.source = Some(Source::new(
+    "http://www.tomalak.org/links2.xml",
+    "Tomalak's Realm",
+  ));
+
+  channel.items.push(item);
+  channel.items.push(Item::with_description(
+    "Sky watchers in Europe, Asia, and parts of Alaska and Canada.",
+  ));
+
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  let mut xml_serializer = XmlSerializer::new(&mut writer);
+  xml_serializer.serialize(channel, None).unwrap();
+
+  assert_eq!(expected, from_utf8(&bytes).unwrap());
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "serde")]
+
+use syndication_format::{
+  atom::{self, owned},
+  common::{LinkRelation, TimeStamp},
+  reader::StringArena,
+};
+
+const FEED: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="en">
+  <title type="html">A &lt;em&gt;feed&lt;/em&gt;</title>
+  <id>urn:feed</id>
+  <updated>2024-09-18T23:28:25+02:00</updated>
+  <link rel="self" href="https://example.org/feed.atom"/>
+  <entry>
+    <title>Entry "one"</title>
+    <id>urn:entry:1</id>
+    <updated>2024-09-18T23:28:25Z</updated>
+    <link href="https://example.org/1" type="text/html" rel="tag:example.org,2024:similar"/>
+    <content type="image/png">iVBORw0KGgoA</content>
+  </entry>
+</feed>"#;
+
+#[test]
+fn json_shape() {
+  let arena = StringArena::new();
+  let feed = atom::parse(FEED, &arena).unwrap();
+  let json = serde_json::to_value(&feed).unwrap();
+
+  assert_eq!("en", json["attributes"]["xml:lang"]);
+  assert_eq!(
+    serde_json::json!({ "type": "html", "value": "A <em>feed</em>" }),
+    json["title"]["value"]
+  );
+  assert_eq!("2024-09-18T23:28:25+02:00", json["updated"]["value"]);
+  assert_eq!("self", json["links"][0]["rel"]);
+  assert!(json["id"].get("attributes").is_none());
+
+  let entry = &json["entries"][0];
+  assert_eq!("text/html", entry["links"][0]["type"]);
+  assert_eq!("tag:example.org,2024:similar", entry["links"][0]["rel"]);
+  assert_eq!(serde_json::Value::Null, entry["published"]);
+  assert_eq!(
+    serde_json::json!({ "kind": "inlined_media", "media_type": "image/png", "data": "iVBORw0KGgoA" }),
+    entry["content"]["value"]
+  );
+}
+
+#[test]
+fn json_round_trip() {
+  let arena = StringArena::new();
+  let feed = atom::parse(FEED, &arena).unwrap();
+  let json = serde_json::to_string(&feed).unwrap();
+
+  let mut stored: owned::Feed = serde_json::from_str(&json).unwrap();
+  assert_eq!(
+    Some("en"),
+    stored
+      .get_mut_attributes()
+      .get("xml:lang")
+      .map(|v| v.as_str())
+  );
+  assert_eq!("urn:feed", stored.id.value);
+
+  let entry = &stored.entries[0];
+  assert!(
+    matches!(&entry.title.value, owned::XmlText::PlainText(title) if title == "Entry \"one\"")
+  );
+  assert_eq!(
+    Some(LinkRelation::Extension(
+      "tag:example.org,2024:similar".into()
+    )),
+    entry.links[0].rel
+  );
+  assert!(matches!(
+    &entry.content.as_ref().unwrap().value,
+    owned::ContentValue::InlinedMedia { data, .. } if data[..4] == [0x89, b'P', b'N', b'G']
+  ));
+  assert_eq!(json, serde_json::to_string(&stored).unwrap());
+}
+
+#[test]
+fn json_invalid_values() {
+  assert!(serde_json::from_str::<TimeStamp>(r#""2024-09-18""#).is_err());
+  assert!(serde_json::from_str::<LinkRelation>(r#""not a relation""#).is_err());
+  assert!(
+    serde_json::from_str::<owned::Id>(r#"{"value":"urn:id","attributes":{"1a":"x"}}"#).is_err()
+  );
+  assert!(serde_json::from_str::<owned::XmlText>(r#"{"type":"markdown","value":"x"}"#).is_err());
+}
+
+#[test]
+fn json_timestamp_fraction() {
+  let timestamp: TimeStamp = serde_json::from_str(r#""2024-09-18T23:28:25.5402+03:00""#).unwrap();
+
+  assert_eq!(540_200_000, timestamp.subsec_nanos());
+  assert_eq!(
+    r#""2024-09-18T23:28:25.540200+03:00""#,
+    serde_json::to_string(&timestamp).unwrap()
+  );
+}
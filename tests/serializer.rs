@@ -1,12 +1,17 @@
 use std::str::from_utf8_unchecked;
 
-use atom_syndication_format::{
-  common::{AttributeMap, AttributeName, AttributeValue},
+use core::time::Duration;
+
+use syndication_format::{
+  atom::Feed,
+  common::{AttributeMap, AttributeName, AttributeValue, TimeStamp},
   error::XmlSerializeError,
+  rss, rss1,
   serializer::{
     formatter::{DefaultWriter, IndentedWriter, SpaceStyle},
-    ElementSerializer, Serialize, Serializer, XmlSerializer,
+    ElementSerializer, NamespaceContext, Serialize, Serializer, XmlSerializer,
   },
+  text,
 };
 
 pub struct Title {
@@ -22,6 +27,10 @@ pub struct List {
   inner: Vec<ListItem>,
 }
 
+pub struct Creator {
+  name: String,
+}
+
 pub struct ListItem {
   value: usize,
 }
@@ -44,6 +53,19 @@ impl Serialize for ListItem {
   }
 }
 
+impl Serialize for Creator {
+  fn serialize<S>(&self, serializer: S, _namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  where
+    S: Serializer,
+  {
+    let prefix = serializer.namespace_prefix("http://purl.org/dc/elements/1.1/");
+    let creator = serializer.serialize_element("creator", prefix, None)?;
+    creator.serialize_escaped_str(&self.name)?;
+
+    Ok(())
+  }
+}
+
 impl Serialize for List {
   fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
   where
@@ -62,7 +84,7 @@ impl Serialize for List {
 }
 
 impl Serialize for Title {
-  fn serialize<S>(&self, serializer: S, namespace: Option<&str>) -> Result<(), XmlSerializeError>
+  fn serialize<S>(&self, serializer: S, _namespace: Option<&str>) -> Result<(), XmlSerializeError>
   where
     S: Serializer,
   {
@@ -171,3 +193,177 @@ fn xml_serializer_tab_formatted_write() {
 
   assert_eq!(expected, serialized_text);
 }
+
+#[test]
+fn xml_serializer_inline_text_write() {
+  let expected = "<root>
+    <xhtml:h1>Hello &amp; welcome!</xhtml:h1>
+    <xhtml:ul>
+        <xhtml:li value=\"12\"/>
+        <xhtml:li value=\"13\"/>
+    </xhtml:ul>
+</root>
+";
+
+  let obj = Root {
+    list: List {
+      inner: vec![ListItem { value: 12 }, ListItem { value: 13 }],
+    },
+    title: Title {
+      text: String::from("Hello & welcome!"),
+    },
+  };
+
+  let mut buffer: Vec<u8> = Vec::new();
+  let mut writer = IndentedWriter::with_inline_text(&mut buffer, SpaceStyle::WhiteSpace, 4);
+  let mut xml_writer = XmlSerializer::new(&mut writer);
+  xml_writer.serialize(obj, None).unwrap();
+  let serialized_text = String::from_utf8(buffer).unwrap();
+
+  assert_eq!(expected, serialized_text);
+
+  // Elements without text or children are kept on a single line.
+  let mut buffer: Vec<u8> = Vec::new();
+  let mut writer = IndentedWriter::with_inline_text(&mut buffer, SpaceStyle::WhiteSpace, 4);
+  let mut xml_writer = XmlSerializer::new(&mut writer);
+  xml_writer
+    .serialize(List { inner: Vec::new() }, Some("xhtml"))
+    .unwrap();
+
+  assert_eq!(
+    "<xhtml:ul></xhtml:ul>\n",
+    String::from_utf8(buffer).unwrap()
+  );
+}
+
+fn serialize_with_namespaces<V>(
+  value: V,
+  namespace: Option<&str>,
+  namespaces: &NamespaceContext,
+) -> Result<String, XmlSerializeError>
+where
+  V: Serialize,
+{
+  let mut bytes: Vec<u8> = Vec::new();
+  let mut writer = DefaultWriter::new(&mut bytes);
+  XmlSerializer::with_namespaces(&mut writer, namespaces).serialize(value, namespace)?;
+
+  Ok(String::from_utf8(bytes).unwrap())
+}
+
+#[test]
+fn namespace_context_bindings() {
+  let mut namespaces = NamespaceContext::new();
+  namespaces
+    .bind("", "https://example.org/default")
+    .unwrap()
+    .bind("ext", "https://example.org/ext")
+    .unwrap()
+    .bind("ext", "https://example.org/ext")
+    .unwrap();
+
+  assert_eq!(Some(""), namespaces.prefix("https://example.org/default"));
+  assert_eq!(Some("https://example.org/ext"), namespaces.uri("ext"));
+  assert_eq!(2, namespaces.iter().count());
+
+  assert!(matches!(
+    namespaces.bind("ext", "https://example.org/other"),
+    Err(XmlSerializeError::ConflictingNamespace { prefix }) if prefix.as_ref() == "ext"
+  ));
+
+  for prefix in ["xmlns", "xml", "a:b", "1abc"] {
+    assert!(matches!(
+      namespaces.bind(prefix, "https://example.org/"),
+      Err(XmlSerializeError::InvalidNamespace)
+    ));
+  }
+}
+
+#[test]
+fn default_namespace_prefix() {
+  let mut namespaces = NamespaceContext::new();
+  namespaces
+    .bind("", "http://purl.org/dc/elements/1.1/")
+    .unwrap();
+
+  let creator = Creator {
+    name: String::from("John Doe"),
+  };
+
+  assert_eq!(
+    r#"<creator xmlns="http://purl.org/dc/elements/1.1/">John Doe</creator>"#,
+    serialize_with_namespaces(&creator, None, &namespaces).unwrap()
+  );
+}
+
+#[test]
+fn declare_namespaces_on_root() {
+  let mut namespaces = NamespaceContext::new();
+  namespaces
+    .bind("atom", "http://www.w3.org/2005/Atom")
+    .unwrap()
+    .bind("media", "http://search.yahoo.com/mrss/")
+    .unwrap();
+
+  let mut feed = Feed::new("urn:feed", text!("Feed"), TimeStamp::default());
+  feed.generator = None;
+
+  assert_eq!(
+    concat!(
+      r#"<atom:feed xmlns:atom="http://www.w3.org/2005/Atom" xmlns:media="http://search.yahoo.com/mrss/">"#,
+      r#"<atom:title type="text">Feed</atom:title><atom:id>urn:feed</atom:id>"#,
+      r#"<atom:updated>1970-01-01T00:00:00Z</atom:updated></atom:feed>"#
+    ),
+    serialize_with_namespaces(feed, Some("atom"), &namespaces).unwrap()
+  );
+
+  let channel = rss::Channel::new("Channel", "https://example.org/", "Description");
+  let output = serialize_with_namespaces(channel, None, &namespaces).unwrap();
+  assert!(output.starts_with(concat!(
+    r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" "#,
+    r#"xmlns:media="http://search.yahoo.com/mrss/"><channel>"#
+  )));
+}
+
+#[test]
+fn conflicting_root_declaration() {
+  let mut namespaces = NamespaceContext::new();
+  namespaces
+    .bind("atom", "https://example.org/not-atom")
+    .unwrap();
+
+  let feed = Feed::new("urn:feed", text!("Feed"), TimeStamp::default());
+
+  assert!(matches!(
+    serialize_with_namespaces(feed, Some("atom"), &namespaces),
+    Err(XmlSerializeError::ConflictingNamespace { prefix }) if prefix.as_ref() == "atom"
+  ));
+}
+
+#[test]
+fn prefix_lookup_by_namespace() {
+  let mut namespaces = NamespaceContext::new();
+  namespaces
+    .bind("purl", "http://purl.org/dc/elements/1.1/")
+    .unwrap();
+
+  let mut channel = rss1::Channel::new(
+    "https://example.org/rss",
+    "Channel",
+    "https://example.org/",
+    "Description",
+  );
+  channel.dublin_core.date = Some(TimeStamp::new(Duration::from_secs(1726702105)));
+
+  assert_eq!(
+    concat!(
+      r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/" "#,
+      r#"xmlns:purl="http://purl.org/dc/elements/1.1/">"#,
+      r#"<channel rdf:about="https://example.org/rss"><title>Channel</title>"#,
+      r#"<link>https://example.org/</link><description>Description</description>"#,
+      r#"<purl:date>2024-09-18T23:28:25Z</purl:date><items><rdf:Seq></rdf:Seq></items></channel>"#,
+      r#"</rdf:RDF>"#
+    ),
+    serialize_with_namespaces(channel, None, &namespaces).unwrap()
+  );
+}
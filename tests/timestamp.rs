@@ -1,10 +1,16 @@
-use atom_syndication_format::common::{OffsetSymbol, TimeStamp, TimeZoneOffset};
 use core::time::Duration;
+use syndication_format::{
+  common::{
+    DateHeuristic, DateNames, LenientDateOptions, OffsetSymbol, SecondsPrecision, TimeStamp,
+    TimeZoneOffset,
+  },
+  error::TimeStampFormatErrors,
+};
 
 #[test]
 fn basic_timestamp() {
   let timestamp = TimeStamp::new(Duration::from_millis(1726702105542));
-  assert_eq!("2024-09-18T23:28:25Z", &timestamp.to_string())
+  assert_eq!("2024-09-18T23:28:25.542Z", &timestamp.to_string())
 }
 
 #[test]
@@ -18,7 +24,7 @@ fn basic_timestamp_with_utc_plus_03() {
 
   timestamp.set_tz(offset);
 
-  assert_eq!("2024-09-19T02:28:25+03:00", &timestamp.to_string())
+  assert_eq!("2024-09-19T02:28:25.542+03:00", &timestamp.to_string())
 }
 
 #[test]
@@ -32,5 +38,539 @@ fn basic_timestamp_with_utc_minus_03() {
 
   timestamp.set_tz(offset);
 
-  assert_eq!("2024-09-18T20:28:25-03:00", &timestamp.to_string())
+  assert_eq!("2024-09-18T20:28:25.542-03:00", &timestamp.to_string())
+}
+
+#[test]
+fn fractional_seconds() {
+  let timestamp: TimeStamp = "2024-09-18T23:28:25.542Z".parse().unwrap();
+  assert_eq!(
+    Some(Duration::from_millis(1726702105542)),
+    timestamp.checked_unix_time()
+  );
+  assert_eq!("2024-09-18T23:28:25.542Z", timestamp.to_string());
+
+  let timestamp: TimeStamp = "2024-09-19T02:28:25.5+03:00".parse().unwrap();
+  assert_eq!(
+    Some(Duration::from_millis(1726702105500)),
+    timestamp.checked_unix_time()
+  );
+  assert_eq!(
+    "2024-09-19T02:28:25.500000+03:00",
+    timestamp.display(SecondsPrecision::Micros).to_string()
+  );
+
+  // Digits beyond nanoseconds are truncated.
+  let timestamp: TimeStamp = "2024-09-18T23:28:25.1234567891Z".parse().unwrap();
+  assert_eq!(123_456_789, timestamp.subsec_nanos());
+  assert_eq!("2024-09-18T23:28:25.123456789Z", timestamp.to_string());
+  assert_eq!(
+    "2024-09-18T23:28:25.123456789Z",
+    timestamp.display(SecondsPrecision::Nanos).to_string()
+  );
+  assert_eq!(
+    "2024-09-18T23:28:25.123Z",
+    timestamp.display(SecondsPrecision::Millis).to_string()
+  );
+  assert_eq!(
+    "2024-09-18T23:28:25Z",
+    timestamp.display(SecondsPrecision::Seconds).to_string()
+  );
+
+  assert!("2024-09-18T23:28:25.Z".parse::<TimeStamp>().is_err());
+  assert!("2024-09-18T23:28:25.12".parse::<TimeStamp>().is_err());
+}
+
+#[test]
+fn dates_before_epoch() {
+  let timestamp: TimeStamp = "1969-12-31T23:00:00-01:00".parse().unwrap();
+  assert_eq!(0, timestamp.unix_seconds());
+  assert_eq!(Some(Duration::ZERO), timestamp.checked_unix_time());
+
+  let timestamp: TimeStamp = "1815-06-18T11:30:00.25+01:00".parse().unwrap();
+  assert_eq!(-4_876_810_200, timestamp.unix_seconds());
+  assert_eq!(250_000_000, timestamp.subsec_nanos());
+  assert_eq!(None, timestamp.checked_unix_time());
+  assert_eq!(Duration::ZERO, timestamp.unix_time());
+  assert_eq!("1815-06-18T11:30:00.250+01:00", timestamp.to_string());
+
+  let timestamp = TimeStamp::from_unix_time(-1, 1_500_000_000).unwrap();
+  assert_eq!(0, timestamp.unix_seconds());
+  assert_eq!(500_000_000, timestamp.subsec_nanos());
+}
+
+#[test]
+fn calendar_range() {
+  let dates = [
+    ("0000-01-01T00:00:00Z", -62_167_219_200),
+    ("0000-02-29T00:00:00Z", -62_162_121_600),
+    ("1600-02-29T12:00:00Z", -11_670_955_200),
+    ("1900-03-01T00:00:00Z", -2_203_891_200),
+    ("2000-02-29T00:00:00Z", 951_782_400),
+    ("2100-03-01T00:00:00Z", 4_107_542_400),
+    ("9999-12-31T23:59:59Z", 253_402_300_799),
+  ];
+
+  for (text, seconds) in dates {
+    let timestamp: TimeStamp = text.parse().unwrap();
+
+    assert_eq!(seconds, timestamp.unix_seconds(), "{}", text);
+    assert_eq!(text, timestamp.to_string());
+    assert_eq!(
+      text,
+      TimeStamp::from_unix_time(seconds, 0).unwrap().to_string()
+    );
+  }
+
+  // Outside of the four digit years.
+  assert!(TimeStamp::from_unix_time(-62_167_219_201, 0).is_none());
+  assert!(TimeStamp::from_unix_time(-62_200_000_000, 0).is_none());
+  assert!(TimeStamp::from_unix_time(253_402_300_800, 0).is_none());
+  assert!(TimeStamp::from_unix_time(253_402_300_799, 1_000_000_000).is_none());
+  assert!(TimeStamp::from_unix_time(300_000_000_000, 0).is_none());
+  assert!(TimeStamp::from_unix_time(i64::MAX, u32::MAX).is_none());
+
+  let timestamp = TimeStamp::new(Duration::from_secs(300_000_000_000));
+  assert_eq!("9999-12-31T23:59:59.999999999Z", timestamp.to_string());
+  let timestamp = TimeStamp::new(Duration::MAX);
+  assert_eq!(253_402_300_799, timestamp.unix_seconds());
+
+  // Not leap years.
+  assert!("1900-02-29T00:00:00Z".parse::<TimeStamp>().is_err());
+  assert!("2100-02-29T00:00:00Z".parse::<TimeStamp>().is_err());
+}
+
+#[test]
+fn compare_instants() {
+  let utc: TimeStamp = "2024-09-18T23:28:25Z".parse().unwrap();
+  let local: TimeStamp = "2024-09-19T02:28:25+03:00".parse().unwrap();
+  let earlier: TimeStamp = "1969-07-20T20:17:40Z".parse().unwrap();
+
+  assert!(utc == local);
+  assert!(earlier < utc);
+  assert!(Some(utc) == [earlier, utc].into_iter().max());
+}
+
+#[test]
+fn rfc3339_grammar() {
+  let valid = [
+    ("1985-04-12T23:20:50.52Z", "1985-04-12T23:20:50.520Z"),
+    ("1996-12-19T16:39:57-08:00", "1996-12-19T16:39:57.000-08:00"),
+    ("1990-12-31T23:59:60Z", "1991-01-01T00:00:00.000Z"),
+    ("1990-12-31T15:59:60-08:00", "1990-12-31T16:00:00.000-08:00"),
+    (
+      "1937-01-01T12:00:27.87+00:20",
+      "1937-01-01T12:00:27.870+00:20",
+    ),
+    ("2024-09-18t23:28:25z", "2024-09-18T23:28:25.000Z"),
+    ("2024-09-18T23:28:25-00:00", "2024-09-18T23:28:25.000-00:00"),
+    (
+      "2024-09-18T23:28:25.000000001+23:59",
+      "2024-09-18T23:28:25.000+23:59",
+    ),
+  ];
+
+  for (text, formatted) in valid {
+    let timestamp: TimeStamp = text.parse().unwrap();
+    assert_eq!(
+      formatted,
+      timestamp.display(SecondsPrecision::Millis).to_string()
+    );
+  }
+
+  let invalid = [
+    ("", TimeStampFormatErrors::InvalidFormat),
+    ("2024", TimeStampFormatErrors::InvalidFormat),
+    ("2024-09-18", TimeStampFormatErrors::InvalidFormat),
+    ("2024-09-18T23:28:2", TimeStampFormatErrors::InvalidFormat),
+    ("2024-09-18 23:28:25Z", TimeStampFormatErrors::InvalidFormat),
+    ("2024/09/18T23:28:25Z", TimeStampFormatErrors::InvalidFormat),
+    ("2024-9-18T23:28:25Z", TimeStampFormatErrors::InvalidFormat),
+    ("20a4-09-18T23:28:25Z", TimeStampFormatErrors::InvalidFormat),
+    ("2024-09-18T23-28-25Z", TimeStampFormatErrors::InvalidFormat),
+    ("2024-09-18T2::28:25Z", TimeStampFormatErrors::InvalidFormat),
+    (
+      "2024-09-18T23:28:25.Z",
+      TimeStampFormatErrors::InvalidFormat,
+    ),
+    (
+      "2024-09-18T23:28:25",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "2024-09-18T23:28:25+0300",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "2024-09-18T23:28:25+03:0a",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "2024-09-18T23:28:25+24:00",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "2024-09-18T23:28:25+03:60",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "2024-09-18T23:28:25Z ",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "2024-09-18T23:28:25.5ZZ",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    ("2024-13-18T23:28:25Z", TimeStampFormatErrors::InvalidDate),
+    ("2024-00-18T23:28:25Z", TimeStampFormatErrors::InvalidDate),
+    ("2024-09-00T23:28:25Z", TimeStampFormatErrors::InvalidDate),
+    ("2024-09-32T23:28:25Z", TimeStampFormatErrors::InvalidDate),
+    (
+      "2024-09-31T23:28:25Z",
+      TimeStampFormatErrors::NonExistingDate,
+    ),
+    (
+      "2023-02-29T23:28:25Z",
+      TimeStampFormatErrors::NonExistingDate,
+    ),
+    ("2024-09-18T24:00:00Z", TimeStampFormatErrors::InvalidTime),
+    ("2024-09-18T23:60:00Z", TimeStampFormatErrors::InvalidTime),
+    ("2024-09-18T23:28:61Z", TimeStampFormatErrors::InvalidTime),
+    (
+      "2024-09-18T23:28:25Zü",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    ("ü024-09-18T23:28:25Z", TimeStampFormatErrors::InvalidFormat),
+  ];
+
+  for (text, expected) in invalid {
+    let error = text.parse::<TimeStamp>().err();
+    assert!(
+      matches!(&error, Some(error) if core::mem::discriminant(error) == core::mem::discriminant(&expected)),
+      "{:?}: {:?}",
+      text,
+      error
+    );
+  }
+}
+
+#[test]
+fn rfc822_dates() {
+  let valid = [
+    ("Wed, 18 Sep 2024 23:28:25 +0000", "2024-09-18T23:28:25Z"),
+    ("18 Sep 2024 23:28:25 gmt", "2024-09-18T23:28:25Z"),
+    ("Wed, 18 Sep 24 23:28 UTC", "2024-09-18T23:28:00Z"),
+    ("Sat, 18 Sep 49 23:28:25 Z", "2049-09-18T23:28:25Z"),
+    ("Sun, 18 Sep 50 23:28:25 EST", "1950-09-18T23:28:25-05:00"),
+    ("Fri, 18 Sep 099 23:28:25 CDT", "1999-09-18T23:28:25-05:00"),
+    ("Wed, 18 Sep 2024 23:28:25 CST", "2024-09-18T23:28:25-06:00"),
+    ("Wed, 18 Sep 2024 23:28:25 MDT", "2024-09-18T23:28:25-06:00"),
+    ("Wed, 18 Sep 2024 23:28:25 MST", "2024-09-18T23:28:25-07:00"),
+    ("Wed, 18 Sep 2024 23:28:25 pdt", "2024-09-18T23:28:25-07:00"),
+    ("Wed, 18 Sep 2024 23:28:25 PST", "2024-09-18T23:28:25-08:00"),
+    ("Wed, 18 Sep 2024 23:28:25 A", "2024-09-18T23:28:25-00:00"),
+    (
+      "Wed, 18 Sep 2024 23:28:25 -0000",
+      "2024-09-18T23:28:25-00:00",
+    ),
+    ("Thu, 31 Dec 1998 23:59:60 +0000", "1999-01-01T00:00:00Z"),
+    (
+      "Thu, 19 Sep 2024 02:28:25 +0530",
+      "2024-09-19T02:28:25+05:30",
+    ),
+  ];
+
+  for (value, expected) in valid {
+    let timestamp = TimeStamp::parse_rfc822(value).unwrap();
+
+    assert_eq!(expected, timestamp.to_string(), "{}", value);
+  }
+
+  let invalid = [
+    ("", TimeStampFormatErrors::InvalidFormat),
+    ("Wed, 18 Sep 2024", TimeStampFormatErrors::InvalidFormat),
+    (
+      "Wed, 18 Sep 2024 23:28:25 +0000 GMT",
+      TimeStampFormatErrors::InvalidFormat,
+    ),
+    (
+      "Day, 18 Sep 2024 23:28:25 +0000",
+      TimeStampFormatErrors::InvalidDate,
+    ),
+    (
+      "Wed, 18 Sept 2024 23:28:25 +0000",
+      TimeStampFormatErrors::InvalidDate,
+    ),
+    (
+      "Wed, 31 Sep 2024 23:28:25 +0000",
+      TimeStampFormatErrors::NonExistingDate,
+    ),
+    (
+      "Wed, 18 Sep 2 23:28:25 +0000",
+      TimeStampFormatErrors::UnsupportedYear,
+    ),
+    (
+      "Wed, 18 Sep 20245 23:28:25 +0000",
+      TimeStampFormatErrors::UnsupportedYear,
+    ),
+    (
+      "Wed, 18 Sep 2024 24:28:25 +0000",
+      TimeStampFormatErrors::InvalidTime,
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:61 +0000",
+      TimeStampFormatErrors::InvalidTime,
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:25:00 +0000",
+      TimeStampFormatErrors::InvalidTime,
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:25 +00",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:25 +2400",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:25 +0060",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:25 J",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:25 CEST",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:25 +ü00",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+  ];
+
+  for (value, expected) in invalid {
+    let error = TimeStamp::parse_rfc822(value).err();
+    assert!(
+      matches!(&error, Some(error) if core::mem::discriminant(error) == core::mem::discriminant(&expected)),
+      "{:?}: {:?}",
+      value,
+      error
+    );
+  }
+
+  let formatted = [
+    ("2024-09-18T23:28:25.75Z", "Wed, 18 Sep 2024 23:28:25 +0000"),
+    (
+      "2024-09-19T02:28:25+03:00",
+      "Thu, 19 Sep 2024 02:28:25 +0300",
+    ),
+    (
+      "1969-12-31T19:00:00-05:00",
+      "Wed, 31 Dec 1969 19:00:00 -0500",
+    ),
+    (
+      "2024-09-18T23:28:25-00:00",
+      "Wed, 18 Sep 2024 23:28:25 -0000",
+    ),
+  ];
+
+  for (value, expected) in formatted {
+    let timestamp: TimeStamp = value.parse().unwrap();
+    let text = timestamp.format_rfc822();
+
+    assert_eq!(expected, text);
+    assert!(TimeStamp::parse_rfc822(&text).unwrap().unix_seconds() == timestamp.unix_seconds());
+  }
+}
+
+#[test]
+fn lenient_dates() {
+  let options = LenientDateOptions::default();
+  let dates = [
+    ("2024-09-18T23:28:25Z", None, false, "2024-09-18T23:28:25Z"),
+    (
+      "Wed, 18 Sep 2024 23:28:25 GMT",
+      None,
+      false,
+      "2024-09-18T23:28:25Z",
+    ),
+    (
+      "2024-09-18 23:28",
+      Some(DateHeuristic::IsoDateTime),
+      true,
+      "2024-09-18T23:28:00-00:00",
+    ),
+    (
+      "2024/9/8 23:28:25.5 +0300",
+      Some(DateHeuristic::IsoDateTime),
+      false,
+      "2024-09-08T23:28:25.500+03:00",
+    ),
+    (
+      "2024-09-18t23:28:25 EDT",
+      Some(DateHeuristic::IsoDateTime),
+      false,
+      "2024-09-18T23:28:25-04:00",
+    ),
+    (
+      "2024-09-18",
+      Some(DateHeuristic::IsoDate),
+      true,
+      "2024-09-18T00:00:00-00:00",
+    ),
+    (
+      "Sept 18, 2024",
+      Some(DateHeuristic::MonthName),
+      true,
+      "2024-09-18T00:00:00-00:00",
+    ),
+    (
+      "Wednesday, September 18th, 2024 11:28 PM GMT",
+      Some(DateHeuristic::MonthName),
+      false,
+      "2024-09-18T23:28:00Z",
+    ),
+    (
+      "18 sep. 24 12:05 am",
+      Some(DateHeuristic::MonthName),
+      true,
+      "2024-09-18T00:05:00-00:00",
+    ),
+    (
+      "Wed Sep 18 23:28:25 UTC 2024",
+      Some(DateHeuristic::MonthName),
+      false,
+      "2024-09-18T23:28:25Z",
+    ),
+    (
+      "Wed, 18 Sep 2024 23:28:25",
+      Some(DateHeuristic::MonthName),
+      true,
+      "2024-09-18T23:28:25-00:00",
+    ),
+    (
+      "Mittwoch, 18. März 2024 23:28 +01:00",
+      Some(DateHeuristic::LocalizedMonthName),
+      false,
+      "2024-03-18T23:28:00+01:00",
+    ),
+    (
+      "18 févr. 2024",
+      Some(DateHeuristic::LocalizedMonthName),
+      true,
+      "2024-02-18T00:00:00-00:00",
+    ),
+    (
+      "quarta-feira, 18 setembro 2024",
+      Some(DateHeuristic::LocalizedMonthName),
+      true,
+      "2024-09-18T00:00:00-00:00",
+    ),
+  ];
+
+  for (value, heuristic, assumed_time_zone, expected) in dates {
+    let parsed = TimeStamp::parse_lenient(value, &options).unwrap();
+
+    assert_eq!(heuristic, parsed.heuristic, "{}", value);
+    assert_eq!(assumed_time_zone, parsed.assumed_time_zone, "{}", value);
+    assert_eq!(expected, parsed.timestamp.to_string(), "{}", value);
+  }
+
+  let invalid = [
+    ("", TimeStampFormatErrors::InvalidFormat),
+    ("yesterday", TimeStampFormatErrors::InvalidFormat),
+    ("2024-09-18 23", TimeStampFormatErrors::InvalidFormat),
+    (
+      "2024-09-18 23:28 tomorrow",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    ("2024-09-31 23:28", TimeStampFormatErrors::NonExistingDate),
+    ("2024-13-18", TimeStampFormatErrors::InvalidDate),
+    ("2024-09-18 24:28", TimeStampFormatErrors::InvalidTime),
+    ("Sept 18, 2024 13:28 PM", TimeStampFormatErrors::InvalidTime),
+    (
+      "Sept 18, 2024 23:28 +2400",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    ("Sept 31, 2024", TimeStampFormatErrors::NonExistingDate),
+    ("Ju 18, 2024", TimeStampFormatErrors::InvalidFormat),
+    (
+      "18 juil 2024 10:00 foo",
+      TimeStampFormatErrors::InvalidTimeOffset,
+    ),
+    ("18 juil 2024 foo", TimeStampFormatErrors::InvalidFormat),
+  ];
+
+  for (value, expected) in invalid {
+    let error = TimeStamp::parse_lenient(value, &options).err();
+    assert!(
+      matches!(&error, Some(error) if core::mem::discriminant(error) == core::mem::discriminant(&expected)),
+      "{:?}: {:?}",
+      value,
+      error
+    );
+  }
+
+  let mut options = LenientDateOptions::new();
+  options
+    .set_heuristics(&[DateHeuristic::IsoDate])
+    .set_default_time_zone(None);
+
+  assert!(matches!(
+    TimeStamp::parse_lenient("2024-09-18", &options),
+    Err(TimeStampFormatErrors::InvalidTimeOffset)
+  ));
+
+  let mut time_zone = TimeZoneOffset::new();
+  time_zone.set_hours(2).set_symbol(OffsetSymbol::Plus);
+  options.set_default_time_zone(Some(time_zone));
+
+  assert_eq!(
+    "2024-09-18T00:00:00+02:00",
+    TimeStamp::parse_lenient("2024-09-18", &options)
+      .unwrap()
+      .timestamp
+      .to_string()
+  );
+  assert!(TimeStamp::parse_lenient("Sept 18, 2024", &options).is_err());
+
+  let finnish = [DateNames::new(
+    [
+      "tammikuu",
+      "helmikuu",
+      "maaliskuu",
+      "huhtikuu",
+      "toukokuu",
+      "kesäkuu",
+      "heinäkuu",
+      "elokuu",
+      "syyskuu",
+      "lokakuu",
+      "marraskuu",
+      "joulukuu",
+    ],
+    [
+      "sunnuntai",
+      "maanantai",
+      "tiistai",
+      "keskiviikko",
+      "torstai",
+      "perjantai",
+      "lauantai",
+    ],
+  )];
+  options
+    .set_heuristics(&[DateHeuristic::LocalizedMonthName])
+    .set_names(&finnish);
+
+  assert_eq!(
+    "2024-09-18T00:00:00+02:00",
+    TimeStamp::parse_lenient("keskiviikko 18. SYYSKUU 2024", &options)
+      .unwrap()
+      .timestamp
+      .to_string()
+  );
 }